@@ -17,3 +17,45 @@ fn e_tag_is_correct() -> PilotResult<()> {
     assert_eq!(OUTPUT.plus.json["ETag"], OUTPUT.aws.json["ETag"]);
     Ok(())
 }
+
+#[test]
+fn accept_ranges_is_correct() -> PilotResult<()> {
+    assert_eq!(
+        OUTPUT.plus.json["AcceptRanges"],
+        OUTPUT.aws.json["AcceptRanges"]
+    );
+    Ok(())
+}
+
+#[test]
+fn last_modified_is_correct() -> PilotResult<()> {
+    assert_eq!(
+        OUTPUT.plus.json["LastModified"],
+        OUTPUT.aws.json["LastModified"]
+    );
+    Ok(())
+}
+
+#[test]
+fn content_length_is_correct() -> PilotResult<()> {
+    assert_eq!(
+        OUTPUT.plus.json["ContentLength"],
+        OUTPUT.aws.json["ContentLength"]
+    );
+    Ok(())
+}
+
+#[test]
+fn content_type_is_correct() -> PilotResult<()> {
+    assert_eq!(
+        OUTPUT.plus.json["ContentType"],
+        OUTPUT.aws.json["ContentType"]
+    );
+    Ok(())
+}
+
+#[test]
+fn metadata_is_correct() -> PilotResult<()> {
+    assert_eq!(OUTPUT.plus.json["Metadata"], OUTPUT.aws.json["Metadata"]);
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use plus_s3::core::checksum::{Checksum, ChecksumAlgorithm};
+
+/// Checksum fields of the output, named like `ChecksumCRC32`.
+#[derive(Default, Serialize, Deserialize)]
+pub struct ChecksumFields {
+    #[serde(rename = "ChecksumCRC32", skip_serializing_if = "Option::is_none")]
+    checksum_crc32: Option<String>,
+
+    #[serde(rename = "ChecksumCRC32C", skip_serializing_if = "Option::is_none")]
+    checksum_crc32c: Option<String>,
+
+    #[serde(rename = "ChecksumSHA1", skip_serializing_if = "Option::is_none")]
+    checksum_sha1: Option<String>,
+
+    #[serde(rename = "ChecksumSHA256", skip_serializing_if = "Option::is_none")]
+    checksum_sha256: Option<String>,
+}
+
+impl From<Option<Checksum>> for ChecksumFields {
+    fn from(checksum: Option<Checksum>) -> Self {
+        let mut fields = ChecksumFields::default();
+        if let Some(checksum) = checksum {
+            let value = Some(checksum.value);
+            match checksum.algorithm {
+                ChecksumAlgorithm::Crc32 => fields.checksum_crc32 = value,
+                ChecksumAlgorithm::Crc32c => fields.checksum_crc32c = value,
+                ChecksumAlgorithm::Sha1 => fields.checksum_sha1 = value,
+                ChecksumAlgorithm::Sha256 => fields.checksum_sha256 = value,
+            }
+        }
+        fields
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{s3_client, CommandOutput, CommandResult};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use clap_task::ClapTask;
+use plus_s3::actions::delete_bucket_ownership_controls;
+
+// see also:
+// https://docs.aws.amazon.com/cli/latest/reference/s3api/delete-bucket-ownership-controls.html
+
+pub fn define() -> Box<dyn ClapTask<CommandResult>> {
+    Box::new(Task)
+}
+
+struct Task;
+
+#[async_trait]
+impl ClapTask<CommandResult> for Task {
+    fn name(&self) -> &str {
+        "delete-bucket-ownership-controls"
+    }
+
+    fn design(&self) -> App {
+        SubCommand::with_name(self.name())
+            .about("Removes OwnershipControls for a bucket.")
+            .arg(
+                Arg::with_name("bucket")
+                    .long("bucket")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The Amazon S3 bucket whose OwnershipControls you want to delete."),
+            )
+    }
+
+    async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
+        let client = s3_client::from_matches(matches)?;
+        let request = delete_bucket_ownership_controls::BucketRequest::new();
+        let _response = client.delete_bucket_ownership_controls(request).await?;
+        Ok(CommandOutput::empty())
+    }
+}
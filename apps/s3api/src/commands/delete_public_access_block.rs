@@ -0,0 +1,39 @@
+use crate::{s3_client, CommandOutput, CommandResult};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use clap_task::ClapTask;
+use plus_s3::actions::delete_public_access_block;
+
+// see also:
+// https://docs.aws.amazon.com/cli/latest/reference/s3api/delete-public-access-block.html
+
+pub fn define() -> Box<dyn ClapTask<CommandResult>> {
+    Box::new(Task)
+}
+
+struct Task;
+
+#[async_trait]
+impl ClapTask<CommandResult> for Task {
+    fn name(&self) -> &str {
+        "delete-public-access-block"
+    }
+
+    fn design(&self) -> App {
+        SubCommand::with_name(self.name())
+            .about("Removes the PublicAccessBlock configuration for a bucket.")
+            .arg(
+                Arg::with_name("bucket")
+                    .long("bucket")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The bucket whose PublicAccessBlock configuration you want to delete."),
+            )
+    }
+
+    async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
+        let client = s3_client::from_matches(matches)?;
+        let request = delete_public_access_block::BucketRequest::new();
+        let _response = client.delete_public_access_block(request).await?;
+        Ok(CommandOutput::empty())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::commands::put_bucket_ownership_controls::OwnershipControlsContent;
+use crate::{s3_client, CommandOutput, CommandResult};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use clap_task::ClapTask;
+use plus_s3::actions::get_bucket_ownership_controls;
+
+// see also:
+// https://docs.aws.amazon.com/cli/latest/reference/s3api/get-bucket-ownership-controls.html
+
+pub fn define() -> Box<dyn ClapTask<CommandResult>> {
+    Box::new(Task)
+}
+
+struct Task;
+
+#[async_trait]
+impl ClapTask<CommandResult> for Task {
+    fn name(&self) -> &str {
+        "get-bucket-ownership-controls"
+    }
+
+    fn design(&self) -> App {
+        SubCommand::with_name(self.name())
+            .about("Retrieves OwnershipControls for a bucket.")
+            .arg(
+                Arg::with_name("bucket")
+                    .long("bucket")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The name of the bucket whose OwnershipControls you want to retrieve."),
+            )
+    }
+
+    async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
+        let client = s3_client::from_matches(matches)?;
+        let request = get_bucket_ownership_controls::BucketRequest::new();
+        let response = client.get_bucket_ownership_controls(request).await?;
+        let content = Content {
+            ownership_controls: response.configuration.into(),
+        };
+        Ok(CommandOutput::json(content)?)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Content {
+    #[serde(rename = "OwnershipControls")]
+    ownership_controls: OwnershipControlsContent,
+}
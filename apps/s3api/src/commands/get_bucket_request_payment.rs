@@ -0,0 +1,39 @@
+use crate::{s3_client, CommandOutput, CommandResult};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use clap_task::ClapTask;
+use plus_s3::actions::get_bucket_request_payment;
+
+// see also:
+// https://docs.aws.amazon.com/cli/latest/reference/s3api/get-bucket-request-payment.html
+
+pub fn define() -> Box<dyn ClapTask<CommandResult>> {
+    Box::new(Task)
+}
+
+struct Task;
+
+#[async_trait]
+impl ClapTask<CommandResult> for Task {
+    fn name(&self) -> &str {
+        "get-bucket-request-payment"
+    }
+
+    fn design(&self) -> App {
+        SubCommand::with_name(self.name())
+            .about("Returns the request payment configuration of a bucket.")
+            .arg(
+                Arg::with_name("bucket")
+                    .long("bucket")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The name of the bucket for which to get the payment request configuration."),
+            )
+    }
+
+    async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
+        let client = s3_client::from_matches(matches)?;
+        let request = get_bucket_request_payment::BucketRequest::new();
+        let response = client.get_bucket_request_payment(request).await?;
+        Ok(CommandOutput::json(response.configuration)?)
+    }
+}
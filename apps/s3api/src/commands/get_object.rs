@@ -1,10 +1,11 @@
-use crate::{CommandOutput, CommandResult};
+use crate::checksum_fields::ChecksumFields;
+use crate::{s3_client, CommandOutput, CommandResult};
 use clap::{App, Arg, ArgMatches, SubCommand};
 use clap_extractor::Matcher;
 use clap_task::ClapTask;
 use plus_s3::actions::get_object;
-use plus_s3::client::S3Client;
-use plus_s3::core::S3Bucket;
+use plus_s3::core::checksum::ChecksumMode;
+use std::collections::BTreeMap;
 
 // see also:
 // https://docs.aws.amazon.com/cli/latest/reference/s3api/get-object.html
@@ -38,32 +39,209 @@ impl ClapTask<CommandResult> for Task {
                     .takes_value(true)
                     .help("Key of the object to get."),
             )
+            .arg(
+                Arg::with_name("checksum-mode")
+                    .long("checksum-mode")
+                    .required(false)
+                    .takes_value(true)
+                    .possible_values(&["ENABLED"])
+                    .help("To retrieve the checksum, this mode must be enabled."),
+            )
             .arg(
                 Arg::with_name("outfile")
                     .required(true)
                     .takes_value(true)
-                    .help("Filename where the content will be saved."),
+                    .help(
+                    "Filename where the content will be saved. Use \"-\" to write it to stdout.",
+                ),
             )
     }
 
     async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
-        let client = S3Client::from_env(S3Bucket::from_string(
-            matches.single("bucket").as_required()?,
-        ))?;
-        let request = get_object::FileRequest::create(
-            matches.single("key").as_required()?,
-            matches.single("outfile").as_required()?,
-        )?;
-        let response = client.get_object(request).await?;
-        let content = Content {
-            e_tag: response.headers.e_tag.into_string(),
+        let client = s3_client::from_matches(matches)?;
+        let object_key: String = matches.single("key").as_required()?;
+        let checksum_mode = matches
+            .value_of("checksum-mode")
+            .map(|_| ChecksumMode::Enabled);
+        let outfile: String = matches.single("outfile").as_required()?;
+        let to_stdout = outfile == "-";
+        let response = if to_stdout {
+            let request = get_object::WriterRequest::new(object_key, tokio::io::stdout());
+            let request = match checksum_mode {
+                Some(mode) => request.with_checksum_mode(mode),
+                None => request,
+            };
+            client.get_object(request).await?
+        } else {
+            let request = get_object::FileRequest::create(object_key, outfile.into())?;
+            let request = match checksum_mode {
+                Some(mode) => request.with_checksum_mode(mode),
+                None => request,
+            };
+            client.get_object(request).await?
         };
-        Ok(CommandOutput::json(content)?)
+        let content = Content::from(response.headers);
+        if to_stdout {
+            // stdout is occupied by the object itself.
+            Ok(CommandOutput::json_to_stderr(content)?)
+        } else {
+            Ok(CommandOutput::json(content)?)
+        }
     }
 }
 
+/// Fields ordered and named like the output of `aws s3api get-object`.
 #[derive(Serialize, Deserialize)]
 struct Content {
+    #[serde(rename = "AcceptRanges", skip_serializing_if = "Option::is_none")]
+    accept_ranges: Option<String>,
+
+    #[serde(rename = "Expiration", skip_serializing_if = "Option::is_none")]
+    expiration: Option<String>,
+
+    #[serde(rename = "Restore", skip_serializing_if = "Option::is_none")]
+    restore: Option<String>,
+
+    #[serde(rename = "LastModified", skip_serializing_if = "Option::is_none")]
+    last_modified: Option<String>,
+
+    #[serde(rename = "ContentLength", skip_serializing_if = "Option::is_none")]
+    content_length: Option<u64>,
+
     #[serde(rename = "ETag")]
     e_tag: String,
+
+    #[serde(flatten)]
+    checksum: ChecksumFields,
+
+    #[serde(rename = "MissingMeta", skip_serializing_if = "Option::is_none")]
+    missing_meta: Option<u32>,
+
+    #[serde(rename = "VersionId", skip_serializing_if = "Option::is_none")]
+    version_id: Option<String>,
+
+    #[serde(rename = "CacheControl", skip_serializing_if = "Option::is_none")]
+    cache_control: Option<String>,
+
+    #[serde(rename = "ContentDisposition", skip_serializing_if = "Option::is_none")]
+    content_disposition: Option<String>,
+
+    #[serde(rename = "ContentEncoding", skip_serializing_if = "Option::is_none")]
+    content_encoding: Option<String>,
+
+    #[serde(rename = "ContentLanguage", skip_serializing_if = "Option::is_none")]
+    content_language: Option<String>,
+
+    #[serde(rename = "ContentRange", skip_serializing_if = "Option::is_none")]
+    content_range: Option<String>,
+
+    #[serde(rename = "ContentType", skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+
+    #[serde(rename = "Expires", skip_serializing_if = "Option::is_none")]
+    expires: Option<String>,
+
+    #[serde(
+        rename = "WebsiteRedirectLocation",
+        skip_serializing_if = "Option::is_none"
+    )]
+    website_redirect_location: Option<String>,
+
+    #[serde(
+        rename = "ServerSideEncryption",
+        skip_serializing_if = "Option::is_none"
+    )]
+    server_side_encryption: Option<String>,
+
+    #[serde(rename = "Metadata")]
+    metadata: BTreeMap<String, String>,
+
+    #[serde(
+        rename = "SSECustomerAlgorithm",
+        skip_serializing_if = "Option::is_none"
+    )]
+    sse_customer_algorithm: Option<String>,
+
+    #[serde(rename = "SSECustomerKeyMD5", skip_serializing_if = "Option::is_none")]
+    sse_customer_key_md5: Option<String>,
+
+    #[serde(rename = "SSEKMSKeyId", skip_serializing_if = "Option::is_none")]
+    sse_kms_key_id: Option<String>,
+
+    #[serde(rename = "BucketKeyEnabled", skip_serializing_if = "Option::is_none")]
+    bucket_key_enabled: Option<bool>,
+
+    #[serde(rename = "StorageClass", skip_serializing_if = "Option::is_none")]
+    storage_class: Option<String>,
+
+    #[serde(rename = "RequestCharged", skip_serializing_if = "Option::is_none")]
+    request_charged: Option<String>,
+
+    #[serde(rename = "ReplicationStatus", skip_serializing_if = "Option::is_none")]
+    replication_status: Option<String>,
+
+    #[serde(rename = "PartsCount", skip_serializing_if = "Option::is_none")]
+    parts_count: Option<u32>,
+
+    #[serde(rename = "TagCount", skip_serializing_if = "Option::is_none")]
+    tag_count: Option<u32>,
+
+    #[serde(rename = "ObjectLockMode", skip_serializing_if = "Option::is_none")]
+    object_lock_mode: Option<String>,
+
+    #[serde(
+        rename = "ObjectLockRetainUntilDate",
+        skip_serializing_if = "Option::is_none"
+    )]
+    object_lock_retain_until_date: Option<String>,
+
+    #[serde(
+        rename = "ObjectLockLegalHoldStatus",
+        skip_serializing_if = "Option::is_none"
+    )]
+    object_lock_legal_hold_status: Option<String>,
+}
+
+impl From<get_object::ResponseHeaders> for Content {
+    fn from(headers: get_object::ResponseHeaders) -> Self {
+        Content {
+            accept_ranges: headers.accept_ranges.map(|x| x.into_string()),
+            expiration: headers.expiration.map(|x| x.into_string()),
+            restore: headers.restore.map(|x| x.into_string()),
+            last_modified: headers.last_modified.map(|x| x.into_string()),
+            content_length: headers.content_length.map(|x| x.value()),
+            e_tag: headers.e_tag.into_string(),
+            checksum: headers.checksum.into(),
+            missing_meta: headers.missing_meta.map(|x| x.value()),
+            version_id: headers.version_id.map(|x| x.into_string()),
+            cache_control: headers.cache_control.map(|x| x.into_string()),
+            content_disposition: headers.content_disposition.map(|x| x.into_string()),
+            content_encoding: headers.content_encoding.map(|x| x.into_string()),
+            content_language: headers.content_language.map(|x| x.into_string()),
+            content_range: headers.content_range.map(|x| x.into_string()),
+            content_type: headers.content_type.map(|x| x.into_string()),
+            expires: headers.expires.map(|x| x.into_string()),
+            website_redirect_location: headers.website_redirect_location.map(|x| x.into_string()),
+            server_side_encryption: headers
+                .server_side_encryption
+                .map(|x| x.as_str().to_string()),
+            metadata: headers.metadata.into_map(),
+            sse_customer_algorithm: headers.sse_customer_algorithm.map(|x| x.into_string()),
+            sse_customer_key_md5: headers.sse_customer_key_md5.map(|x| x.into_string()),
+            sse_kms_key_id: headers.sse_kms_key_id.map(|x| x.into_string()),
+            bucket_key_enabled: headers.bucket_key_enabled.map(|x| x.value()),
+            storage_class: headers.storage_class.map(|x| x.as_str().to_string()),
+            request_charged: headers.request_charged.map(|x| x.into_string()),
+            replication_status: headers.replication_status.map(|x| x.as_str().to_string()),
+            parts_count: headers.parts_count.map(|x| x.value()),
+            tag_count: headers.tag_count.map(|x| x.value()),
+            object_lock_mode: headers.object_lock_mode.map(|x| x.into_string()),
+            object_lock_retain_until_date: headers
+                .object_lock_retain_until_date
+                .map(|x| x.into_string()),
+            object_lock_legal_hold_status: headers
+                .object_lock_legal_hold_status
+                .map(|x| x.into_string()),
+        }
+    }
 }
@@ -0,0 +1,216 @@
+use crate::{s3_client, CommandOutput, CommandResult};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use clap_extractor::Matcher;
+use clap_task::ClapTask;
+use plus_s3::actions::get_object_attributes;
+
+// see also:
+// https://docs.aws.amazon.com/cli/latest/reference/s3api/get-object-attributes.html
+
+pub fn define() -> Box<dyn ClapTask<CommandResult>> {
+    Box::new(Task)
+}
+
+struct Task;
+
+#[async_trait]
+impl ClapTask<CommandResult> for Task {
+    fn name(&self) -> &str {
+        "get-object-attributes"
+    }
+
+    fn design(&self) -> App {
+        SubCommand::with_name(self.name())
+            .about("Retrieves all the metadata from an object without returning the object itself.")
+            .arg(
+                Arg::with_name("bucket")
+                    .long("bucket")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The name of the bucket that contains the object."),
+            )
+            .arg(
+                Arg::with_name("key")
+                    .long("key")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The object key."),
+            )
+            .arg(
+                Arg::with_name("object-attributes")
+                    .long("object-attributes")
+                    .required(true)
+                    .takes_value(true)
+                    .multiple(true)
+                    .possible_values(&[
+                        "ETag",
+                        "Checksum",
+                        "ObjectParts",
+                        "StorageClass",
+                        "ObjectSize",
+                    ])
+                    .help("The fields at the root level that you want returned in the response."),
+            )
+            .arg(
+                Arg::with_name("version-id")
+                    .long("version-id")
+                    .required(false)
+                    .takes_value(true)
+                    .help("The version ID used to reference a specific version of the object."),
+            )
+            .arg(
+                Arg::with_name("max-parts")
+                    .long("max-parts")
+                    .required(false)
+                    .takes_value(true)
+                    .help("Sets the maximum number of parts to return."),
+            )
+            .arg(
+                Arg::with_name("part-number-marker")
+                    .long("part-number-marker")
+                    .required(false)
+                    .takes_value(true)
+                    .help("Specifies the part after which listing should begin."),
+            )
+    }
+
+    async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
+        let client = s3_client::from_matches(matches)?;
+        let request = get_object_attributes::AttributesRequest {
+            object_key: matches.single("key").as_required()?,
+            attributes: matches.multiple("object-attributes").as_optional()?,
+            version_id: matches.single("version-id").as_optional()?,
+            max_parts: matches.single("max-parts").as_optional()?,
+            part_number_marker: matches.single("part-number-marker").as_optional()?,
+        };
+        let response = client.get_object_attributes(request).await?;
+        let attributes = response.attributes;
+        let content = Content {
+            last_modified: response.headers.last_modified.map(|x| x.into_string()),
+            version_id: response.headers.version_id.map(|x| x.into_string()),
+            e_tag: attributes.e_tag,
+            checksum: attributes.checksum.map(Checksum::from),
+            object_parts: attributes.object_parts.map(ObjectParts::from),
+            storage_class: attributes.storage_class.map(String::from),
+            object_size: attributes.object_size,
+        };
+        Ok(CommandOutput::json(content)?)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Content {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version_id: Option<String>,
+
+    #[serde(rename = "ETag", skip_serializing_if = "Option::is_none")]
+    e_tag: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    checksum: Option<Checksum>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    object_parts: Option<ObjectParts>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    storage_class: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    object_size: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct Checksum {
+    #[serde(rename = "ChecksumCRC32", skip_serializing_if = "Option::is_none")]
+    checksum_crc32: Option<String>,
+
+    #[serde(rename = "ChecksumCRC32C", skip_serializing_if = "Option::is_none")]
+    checksum_crc32c: Option<String>,
+
+    #[serde(rename = "ChecksumSHA1", skip_serializing_if = "Option::is_none")]
+    checksum_sha1: Option<String>,
+
+    #[serde(rename = "ChecksumSHA256", skip_serializing_if = "Option::is_none")]
+    checksum_sha256: Option<String>,
+}
+
+impl From<get_object_attributes::Checksum> for Checksum {
+    fn from(x: get_object_attributes::Checksum) -> Self {
+        Checksum {
+            checksum_crc32: x.checksum_crc32,
+            checksum_crc32c: x.checksum_crc32c,
+            checksum_sha1: x.checksum_sha1,
+            checksum_sha256: x.checksum_sha256,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct ObjectParts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_parts_count: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_number_marker: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_part_number_marker: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_parts: Option<u32>,
+
+    is_truncated: bool,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    parts: Vec<Part>,
+}
+
+impl From<get_object_attributes::ObjectParts> for ObjectParts {
+    fn from(x: get_object_attributes::ObjectParts) -> Self {
+        ObjectParts {
+            total_parts_count: x.parts_count,
+            part_number_marker: x.part_number_marker,
+            next_part_number_marker: x.next_part_number_marker,
+            max_parts: x.max_parts,
+            is_truncated: x.is_truncated,
+            parts: x.parts.into_iter().map(Part::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Part {
+    part_number: u32,
+    size: u64,
+
+    #[serde(rename = "ChecksumCRC32", skip_serializing_if = "Option::is_none")]
+    checksum_crc32: Option<String>,
+
+    #[serde(rename = "ChecksumCRC32C", skip_serializing_if = "Option::is_none")]
+    checksum_crc32c: Option<String>,
+
+    #[serde(rename = "ChecksumSHA1", skip_serializing_if = "Option::is_none")]
+    checksum_sha1: Option<String>,
+
+    #[serde(rename = "ChecksumSHA256", skip_serializing_if = "Option::is_none")]
+    checksum_sha256: Option<String>,
+}
+
+impl From<get_object_attributes::ObjectPart> for Part {
+    fn from(x: get_object_attributes::ObjectPart) -> Self {
+        Part {
+            part_number: x.part_number,
+            size: x.size,
+            checksum_crc32: x.checksum_crc32,
+            checksum_crc32c: x.checksum_crc32c,
+            checksum_sha1: x.checksum_sha1,
+            checksum_sha256: x.checksum_sha256,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{s3_client, CommandOutput, CommandResult};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use clap_task::ClapTask;
+use plus_s3::actions::get_public_access_block;
+use plus_s3::core::configuration::public_access_block::PublicAccessBlockConfiguration;
+
+// see also:
+// https://docs.aws.amazon.com/cli/latest/reference/s3api/get-public-access-block.html
+
+pub fn define() -> Box<dyn ClapTask<CommandResult>> {
+    Box::new(Task)
+}
+
+struct Task;
+
+#[async_trait]
+impl ClapTask<CommandResult> for Task {
+    fn name(&self) -> &str {
+        "get-public-access-block"
+    }
+
+    fn design(&self) -> App {
+        SubCommand::with_name(self.name())
+            .about("Retrieves the PublicAccessBlock configuration for a bucket.")
+            .arg(
+                Arg::with_name("bucket")
+                    .long("bucket")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The name of the bucket whose PublicAccessBlock configuration you want to retrieve."),
+            )
+    }
+
+    async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
+        let client = s3_client::from_matches(matches)?;
+        let request = get_public_access_block::BucketRequest::new();
+        let response = client.get_public_access_block(request).await?;
+        let content = Content {
+            public_access_block_configuration: response.configuration,
+        };
+        Ok(CommandOutput::json(content)?)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Content {
+    #[serde(rename = "PublicAccessBlockConfiguration")]
+    public_access_block_configuration: PublicAccessBlockConfiguration,
+}
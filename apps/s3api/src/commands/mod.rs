@@ -4,9 +4,36 @@
 use crate::CommandResult;
 use clap_task::ClapTask;
 
+pub mod delete_bucket_ownership_controls;
+pub mod delete_public_access_block;
+pub mod get_bucket_accelerate_configuration;
+pub mod get_bucket_ownership_controls;
+pub mod get_bucket_request_payment;
 pub mod get_object;
+pub mod get_object_attributes;
+pub mod get_public_access_block;
+pub mod presign;
+pub mod put_bucket_accelerate_configuration;
+pub mod put_bucket_ownership_controls;
+pub mod put_bucket_request_payment;
 pub mod put_object;
+pub mod put_public_access_block;
 
 pub fn define_all() -> Vec<Box<dyn ClapTask<CommandResult>>> {
-    vec![get_object::define(), put_object::define()]
+    vec![
+        delete_bucket_ownership_controls::define(),
+        delete_public_access_block::define(),
+        get_bucket_accelerate_configuration::define(),
+        get_bucket_ownership_controls::define(),
+        get_bucket_request_payment::define(),
+        get_object::define(),
+        get_object_attributes::define(),
+        get_public_access_block::define(),
+        presign::define(),
+        put_bucket_accelerate_configuration::define(),
+        put_bucket_ownership_controls::define(),
+        put_bucket_request_payment::define(),
+        put_object::define(),
+        put_public_access_block::define(),
+    ]
 }
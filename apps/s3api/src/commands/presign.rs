@@ -0,0 +1,93 @@
+use crate::{s3_client, CommandOutput, CommandResult};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use clap_extractor::Matcher;
+use clap_task::ClapTask;
+use plus_s3::core::request::PresignRequest;
+use std::time::Duration;
+
+// see also:
+// https://docs.aws.amazon.com/cli/latest/reference/s3/presign.html
+
+pub fn define() -> Box<dyn ClapTask<CommandResult>> {
+    Box::new(Task)
+}
+
+struct Task;
+
+#[async_trait]
+impl ClapTask<CommandResult> for Task {
+    fn name(&self) -> &str {
+        "presign"
+    }
+
+    fn design(&self) -> App {
+        SubCommand::with_name(self.name())
+            .about("Generates a pre-signed URL to download or upload an object.")
+            .arg(
+                Arg::with_name("bucket")
+                    .long("bucket")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The bucket name containing the object."),
+            )
+            .arg(
+                Arg::with_name("key")
+                    .long("key")
+                    .required(true)
+                    .takes_value(true)
+                    .help("Key of the object."),
+            )
+            .arg(
+                Arg::with_name("expires-in")
+                    .long("expires-in")
+                    .required(false)
+                    .takes_value(true)
+                    .default_value("3600")
+                    .help("Number of seconds until the URL expires, up to 604800 (7 days)."),
+            )
+            .arg(
+                Arg::with_name("http-method")
+                    .long("http-method")
+                    .required(false)
+                    .takes_value(true)
+                    .possible_values(&["GET", "PUT"])
+                    .default_value("GET")
+                    .help("GET to download the object, or PUT to upload it."),
+            )
+            .arg(
+                Arg::with_name("content-type")
+                    .long("content-type")
+                    .required(false)
+                    .takes_value(true)
+                    .help("The Content-Type which the uploader has to send with PUT."),
+            )
+            .arg(
+                Arg::with_name("region")
+                    .long("region")
+                    .required(false)
+                    .takes_value(true),
+            )
+    }
+
+    async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
+        let client = s3_client::from_matches(matches)?;
+        let object_key: String = matches.single("key").as_required()?;
+        let expires_in: u64 = matches.single("expires-in").as_required()?;
+        let request = PresignRequest::new(object_key, Duration::from_secs(expires_in));
+        let request = match matches.single("region").as_optional()? {
+            Some(region) => request.with_region(region),
+            None => request,
+        };
+        let url = match matches.value_of("http-method") {
+            Some("PUT") => {
+                let request = match matches.single("content-type").as_optional()? {
+                    Some(content_type) => request.with_content_type(content_type),
+                    None => request,
+                };
+                client.presign_put_object(&request)?
+            }
+            _ => client.presign_get_object(&request)?,
+        };
+        Ok(CommandOutput::new(url.as_str()))
+    }
+}
@@ -0,0 +1,53 @@
+use crate::json_input;
+use crate::{s3_client, CommandOutput, CommandResult};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use clap_extractor::Matcher;
+use clap_task::ClapTask;
+use plus_s3::actions::put_bucket_accelerate_configuration;
+
+// see also:
+// https://docs.aws.amazon.com/cli/latest/reference/s3api/put-bucket-accelerate-configuration.html
+
+pub fn define() -> Box<dyn ClapTask<CommandResult>> {
+    Box::new(Task)
+}
+
+struct Task;
+
+#[async_trait]
+impl ClapTask<CommandResult> for Task {
+    fn name(&self) -> &str {
+        "put-bucket-accelerate-configuration"
+    }
+
+    fn design(&self) -> App {
+        SubCommand::with_name(self.name())
+            .about("Sets the accelerate configuration of an existing bucket.")
+            .arg(
+                Arg::with_name("bucket")
+                    .long("bucket")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The name of the bucket for which the accelerate configuration is set."),
+            )
+            .arg(
+                Arg::with_name("accelerate-configuration")
+                    .long("accelerate-configuration")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The accelerate configuration in JSON, or file://<path> to it."),
+            )
+    }
+
+    async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
+        let client = s3_client::from_matches(matches)?;
+        let configuration = json_input::parse(
+            &matches
+                .single("accelerate-configuration")
+                .as_required::<String>()?,
+        )?;
+        let request = put_bucket_accelerate_configuration::ConfigurationRequest::new(configuration);
+        let _response = client.put_bucket_accelerate_configuration(request).await?;
+        Ok(CommandOutput::empty())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::json_input;
+use crate::{s3_client, CommandOutput, CommandResult};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use clap_extractor::Matcher;
+use clap_task::ClapTask;
+use plus_s3::actions::put_bucket_ownership_controls;
+use plus_s3::core::configuration::ownership_controls::{OwnershipControls, OwnershipControlsRule};
+
+// see also:
+// https://docs.aws.amazon.com/cli/latest/reference/s3api/put-bucket-ownership-controls.html
+
+pub fn define() -> Box<dyn ClapTask<CommandResult>> {
+    Box::new(Task)
+}
+
+struct Task;
+
+#[async_trait]
+impl ClapTask<CommandResult> for Task {
+    fn name(&self) -> &str {
+        "put-bucket-ownership-controls"
+    }
+
+    fn design(&self) -> App {
+        SubCommand::with_name(self.name())
+            .about("Creates or modifies OwnershipControls for a bucket.")
+            .arg(
+                Arg::with_name("bucket")
+                    .long("bucket")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The name of the bucket whose OwnershipControls you want to set."),
+            )
+            .arg(
+                Arg::with_name("ownership-controls")
+                    .long("ownership-controls")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The OwnershipControls in JSON, or file://<path> to it."),
+            )
+    }
+
+    async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
+        let client = s3_client::from_matches(matches)?;
+        let controls: OwnershipControlsContent = json_input::parse(
+            &matches
+                .single("ownership-controls")
+                .as_required::<String>()?,
+        )?;
+        let request = put_bucket_ownership_controls::ConfigurationRequest::new(controls.into());
+        let _response = client.put_bucket_ownership_controls(request).await?;
+        Ok(CommandOutput::empty())
+    }
+}
+
+/// OwnershipControls in JSON, which has `Rules` instead of `Rule` in XML.
+#[derive(Serialize, Deserialize)]
+pub struct OwnershipControlsContent {
+    #[serde(rename = "Rules")]
+    rules: Vec<OwnershipControlsRule>,
+}
+
+impl From<OwnershipControls> for OwnershipControlsContent {
+    fn from(controls: OwnershipControls) -> Self {
+        OwnershipControlsContent {
+            rules: controls.rules,
+        }
+    }
+}
+
+impl From<OwnershipControlsContent> for OwnershipControls {
+    fn from(content: OwnershipControlsContent) -> Self {
+        OwnershipControls {
+            rules: content.rules,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::json_input;
+use crate::{s3_client, CommandOutput, CommandResult};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use clap_extractor::Matcher;
+use clap_task::ClapTask;
+use plus_s3::actions::put_bucket_request_payment;
+
+// see also:
+// https://docs.aws.amazon.com/cli/latest/reference/s3api/put-bucket-request-payment.html
+
+pub fn define() -> Box<dyn ClapTask<CommandResult>> {
+    Box::new(Task)
+}
+
+struct Task;
+
+#[async_trait]
+impl ClapTask<CommandResult> for Task {
+    fn name(&self) -> &str {
+        "put-bucket-request-payment"
+    }
+
+    fn design(&self) -> App {
+        SubCommand::with_name(self.name())
+            .about("Sets the request payment configuration for a bucket.")
+            .arg(
+                Arg::with_name("bucket")
+                    .long("bucket")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The bucket name."),
+            )
+            .arg(
+                Arg::with_name("request-payment-configuration")
+                    .long("request-payment-configuration")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The request payment configuration in JSON, or file://<path> to it."),
+            )
+    }
+
+    async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
+        let client = s3_client::from_matches(matches)?;
+        let configuration = json_input::parse(
+            &matches
+                .single("request-payment-configuration")
+                .as_required::<String>()?,
+        )?;
+        let request = put_bucket_request_payment::ConfigurationRequest::new(configuration);
+        let _response = client.put_bucket_request_payment(request).await?;
+        Ok(CommandOutput::empty())
+    }
+}
@@ -1,14 +1,18 @@
-use crate::{CommandOutput, CommandResult};
+use crate::checksum_fields::ChecksumFields;
+use crate::error::Error::StdinTooLarge;
+use crate::{s3_client, CommandOutput, CommandResult};
 use clap::{App, Arg, ArgMatches, SubCommand};
 use clap_extractor::Matcher;
 use clap_task::ClapTask;
-use plus_s3::actions::put_object::FileRequest;
-use plus_s3::client::S3Client;
-use plus_s3::core::S3Bucket;
+use plus_s3::actions::put_object::{self, BytesRequest, FileRequest};
+use tokio::io::AsyncReadExt;
 
 // see also:
 // https://docs.aws.amazon.com/cli/latest/reference/s3api/put-object.html
 
+/// Stdin is buffered in memory to be hashed, so the larger data should be given as a file.
+const MAX_STDIN_SIZE: u64 = 64 * 1024 * 1024;
+
 pub fn define() -> Box<dyn ClapTask<CommandResult>> {
     Box::new(Task)
 }
@@ -43,7 +47,7 @@ impl ClapTask<CommandResult> for Task {
                     .long("body")
                     .required(false)
                     .takes_value(true)
-                    .help("Object data."),
+                    .help("Object data. Use \"-\" to read it from stdin, up to 64 MiB."),
             )
             .arg(
                 Arg::with_name("content-type")
@@ -52,6 +56,14 @@ impl ClapTask<CommandResult> for Task {
                     .takes_value(true)
                     .help("A standard MIME type describing the format of the contents."),
             )
+            .arg(
+                Arg::with_name("checksum-algorithm")
+                    .long("checksum-algorithm")
+                    .required(false)
+                    .takes_value(true)
+                    .possible_values(&["CRC32", "CRC32C", "SHA1", "SHA256"])
+                    .help("The algorithm used to create the checksum for the object."),
+            )
             .arg(
                 Arg::with_name("region")
                     .long("region")
@@ -61,25 +73,102 @@ impl ClapTask<CommandResult> for Task {
     }
 
     async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
-        let client = S3Client::from_env(S3Bucket::from_string(
-            matches.single("bucket").as_required()?,
-        ))?;
-        let request = FileRequest {
-            file_path: matches.single("body").as_required()?,
-            object_key: matches.single("key").as_required()?,
-            content_type: matches.single("content_type").as_optional()?,
-            region_code: matches.single("region").as_optional()?,
-        };
-        let response = client.put_object(request).await?;
-        let content = Content {
-            e_tag: response.headers.e_tag.into_string(),
+        let client = s3_client::from_matches(matches)?;
+        let file_path: String = matches.single("body").as_required()?;
+        let response = if file_path == "-" {
+            // the length of stdin is unknown, so it is buffered to be hashed.
+            let mut bytes = vec![];
+            let mut stdin = tokio::io::stdin().take(MAX_STDIN_SIZE + 1);
+            stdin.read_to_end(&mut bytes).await?;
+            if bytes.len() as u64 > MAX_STDIN_SIZE {
+                return Err(StdinTooLarge {
+                    limit: MAX_STDIN_SIZE,
+                });
+            }
+            let request = BytesRequest {
+                content_type: matches.single("content_type").as_optional()?,
+                region_code: matches.single("region").as_optional()?,
+                checksum_algorithm: matches.single("checksum-algorithm").as_optional()?,
+                ..BytesRequest::new(matches.single("key").as_required::<String>()?, bytes)
+            };
+            client.put_object(request).await?
+        } else {
+            let request = FileRequest {
+                file_path,
+                object_key: matches.single("key").as_required()?,
+                content_type: matches.single("content_type").as_optional()?,
+                region_code: matches.single("region").as_optional()?,
+                checksum_algorithm: matches.single("checksum-algorithm").as_optional()?,
+            };
+            client.put_object(request).await?
         };
+        let content = Content::from(response.headers);
         Ok(CommandOutput::json(content)?)
     }
 }
 
+/// Fields ordered and named like the output of `aws s3api put-object`.
 #[derive(Serialize, Deserialize)]
 struct Content {
+    #[serde(rename = "Expiration", skip_serializing_if = "Option::is_none")]
+    expiration: Option<String>,
+
     #[serde(rename = "ETag")]
     e_tag: String,
+
+    #[serde(flatten)]
+    checksum: ChecksumFields,
+
+    #[serde(
+        rename = "ServerSideEncryption",
+        skip_serializing_if = "Option::is_none"
+    )]
+    server_side_encryption: Option<String>,
+
+    #[serde(rename = "VersionId", skip_serializing_if = "Option::is_none")]
+    version_id: Option<String>,
+
+    #[serde(
+        rename = "SSECustomerAlgorithm",
+        skip_serializing_if = "Option::is_none"
+    )]
+    sse_customer_algorithm: Option<String>,
+
+    #[serde(rename = "SSECustomerKeyMD5", skip_serializing_if = "Option::is_none")]
+    sse_customer_key_md5: Option<String>,
+
+    #[serde(rename = "SSEKMSKeyId", skip_serializing_if = "Option::is_none")]
+    sse_kms_key_id: Option<String>,
+
+    #[serde(
+        rename = "SSEKMSEncryptionContext",
+        skip_serializing_if = "Option::is_none"
+    )]
+    sse_kms_encryption_context: Option<String>,
+
+    #[serde(rename = "BucketKeyEnabled", skip_serializing_if = "Option::is_none")]
+    bucket_key_enabled: Option<bool>,
+
+    #[serde(rename = "RequestCharged", skip_serializing_if = "Option::is_none")]
+    request_charged: Option<String>,
+}
+
+impl From<put_object::ResponseHeaders> for Content {
+    fn from(headers: put_object::ResponseHeaders) -> Self {
+        Content {
+            expiration: headers.expiration.map(|x| x.into_string()),
+            e_tag: headers.e_tag.into_string(),
+            checksum: headers.checksum.into(),
+            server_side_encryption: headers
+                .server_side_encryption
+                .map(|x| x.as_str().to_string()),
+            version_id: headers.version_id.map(|x| x.into_string()),
+            sse_customer_algorithm: headers.sse_customer_algorithm.map(|x| x.into_string()),
+            sse_customer_key_md5: headers.sse_customer_key_md5.map(|x| x.into_string()),
+            sse_kms_key_id: headers.sse_kms_key_id.map(|x| x.into_string()),
+            sse_kms_encryption_context: headers.sse_kms_encryption_context.map(|x| x.into_string()),
+            bucket_key_enabled: headers.bucket_key_enabled.map(|x| x.value()),
+            request_charged: headers.request_charged.map(|x| x.into_string()),
+        }
+    }
 }
@@ -0,0 +1,53 @@
+use crate::json_input;
+use crate::{s3_client, CommandOutput, CommandResult};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use clap_extractor::Matcher;
+use clap_task::ClapTask;
+use plus_s3::actions::put_public_access_block;
+
+// see also:
+// https://docs.aws.amazon.com/cli/latest/reference/s3api/put-public-access-block.html
+
+pub fn define() -> Box<dyn ClapTask<CommandResult>> {
+    Box::new(Task)
+}
+
+struct Task;
+
+#[async_trait]
+impl ClapTask<CommandResult> for Task {
+    fn name(&self) -> &str {
+        "put-public-access-block"
+    }
+
+    fn design(&self) -> App {
+        SubCommand::with_name(self.name())
+            .about("Creates or modifies the PublicAccessBlock configuration for a bucket.")
+            .arg(
+                Arg::with_name("bucket")
+                    .long("bucket")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The name of the bucket whose PublicAccessBlock configuration you want to set."),
+            )
+            .arg(
+                Arg::with_name("public-access-block-configuration")
+                    .long("public-access-block-configuration")
+                    .required(true)
+                    .takes_value(true)
+                    .help("The PublicAccessBlock configuration in JSON, or file://<path> to it."),
+            )
+    }
+
+    async fn run<'a>(&'a self, matches: &'a ArgMatches<'a>) -> CommandResult {
+        let client = s3_client::from_matches(matches)?;
+        let configuration = json_input::parse(
+            &matches
+                .single("public-access-block-configuration")
+                .as_required::<String>()?,
+        )?;
+        let request = put_public_access_block::ConfigurationRequest::new(configuration);
+        let _response = client.put_public_access_block(request).await?;
+        Ok(CommandOutput::empty())
+    }
+}
@@ -1,5 +1,6 @@
 extern crate failure;
 
+use plus_s3::internal::impl_async::S3ErrorResponse;
 use std::fmt::Debug;
 use std::string;
 
@@ -22,8 +23,23 @@ pub enum Error {
     #[fail(display = "std::io::Error > {}", 0)]
     StdIoError(std::io::Error),
 
+    #[fail(display = "StdinTooLarge > limit: {}", limit)]
+    StdinTooLarge { limit: u64 },
+
     #[fail(display = "string::FromUtf8Error > {}", 0)]
     StringFromUtf8Error(string::FromUtf8Error),
+
+    #[fail(display = "url::ParseError > {}", 0)]
+    UrlParseError(url::ParseError),
+}
+
+impl Error {
+    pub fn s3_error(&self) -> Option<&S3ErrorResponse> {
+        match self {
+            Error::PlusS3Error(e) => e.s3_error(),
+            _ => None,
+        }
+    }
 }
 
 impl From<clap_task::Error> for Error {
@@ -64,3 +80,9 @@ impl From<string::FromUtf8Error> for Error {
         Error::StringFromUtf8Error(e)
     }
 }
+
+impl From<url::ParseError> for Error {
+    fn from(e: url::ParseError) -> Self {
+        Error::UrlParseError(e)
+    }
+}
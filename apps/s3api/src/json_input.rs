@@ -0,0 +1,11 @@
+use crate::S3ApiResult;
+use serde::de::DeserializeOwned;
+
+/// Parses JSON given inline or by `file://<path>` in the same way as AWS CLI.
+pub fn parse<A: DeserializeOwned>(input: &str) -> S3ApiResult<A> {
+    let json = match input.strip_prefix("file://") {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => input.to_string(),
+    };
+    Ok(serde_json::from_str(&json)?)
+}
@@ -13,7 +13,9 @@ extern crate serde_derive;
 #[macro_use]
 extern crate plus_s3_macros;
 
+mod checksum_fields;
 mod commands;
+mod json_input;
 
 mod error;
 pub use error::Result as S3ApiResult;
@@ -21,6 +23,7 @@ pub use error::Result as S3ApiResult;
 mod output;
 pub use output::{CommandOutput, CommandResult};
 
+mod s3_client;
 mod serialize;
 
 use clap::App;
@@ -31,10 +34,13 @@ use std::process::exit;
 async fn main() {
     match run().await {
         Ok(output) => {
-            println!("{}", output.as_str());
+            output.print();
         }
         Err(e) => {
-            eprintln!("s3api failed: {:#?}", e);
+            match e.s3_error() {
+                Some(response) => eprintln!("s3api failed: {}", response),
+                None => eprintln!("s3api failed: {:#?}", e),
+            }
             exit(1);
         }
     }
@@ -53,4 +59,5 @@ fn init<'a, 'b>() -> App<'a, 'b> {
     App::new(crate_name!())
         .version(crate_version!())
         .author(crate_authors!())
+        .args(&s3_client::global_args())
 }
@@ -4,7 +4,10 @@ use crate::S3ApiResult;
 pub type CommandResult = S3ApiResult<CommandOutput>;
 
 #[derive(Debug)]
-pub struct CommandOutput(String);
+pub struct CommandOutput {
+    text: String,
+    to_stderr: bool,
+}
 
 impl CommandOutput {
     pub fn empty() -> CommandOutput {
@@ -12,7 +15,10 @@ impl CommandOutput {
     }
 
     pub fn new<A: Into<String>>(a: A) -> Self {
-        CommandOutput(a.into())
+        CommandOutput {
+            text: a.into(),
+            to_stderr: false,
+        }
     }
 
     pub fn json<A: AwsJsonSerialize>(a: A) -> S3ApiResult<Self> {
@@ -20,7 +26,24 @@ impl CommandOutput {
         Ok(Self::new(json))
     }
 
+    /// Used when stdout is occupied by the content, such as an object body.
+    pub fn json_to_stderr<A: AwsJsonSerialize>(a: A) -> S3ApiResult<Self> {
+        let output = Self::json(a)?;
+        Ok(CommandOutput {
+            to_stderr: true,
+            ..output
+        })
+    }
+
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.text
+    }
+
+    pub fn print(&self) {
+        if self.to_stderr {
+            eprintln!("{}", self.as_str());
+        } else {
+            println!("{}", self.as_str());
+        }
     }
 }
@@ -0,0 +1,62 @@
+use crate::S3ApiResult;
+use clap::{Arg, ArgMatches};
+use clap_extractor::Matcher;
+use plus_s3::client::{S3Client, S3ClientConfigBuilder};
+use plus_s3::core::S3Bucket;
+use std::time::Duration;
+use url::Url;
+
+/// Global options, which are given before the subcommand like AWS CLI.
+pub fn global_args<'a, 'b>() -> Vec<Arg<'a, 'b>> {
+    vec![
+        Arg::with_name("endpoint-url")
+            .long("endpoint-url")
+            .global(true)
+            .takes_value(true)
+            .help("Override command's default URL with the given URL."),
+        Arg::with_name("cli-connect-timeout")
+            .long("cli-connect-timeout")
+            .global(true)
+            .takes_value(true)
+            .help("The maximum socket connect time in seconds. If 0, waits indefinitely."),
+        Arg::with_name("cli-read-timeout")
+            .long("cli-read-timeout")
+            .global(true)
+            .takes_value(true)
+            .help("The maximum socket read time in seconds. If 0, waits indefinitely."),
+    ]
+}
+
+/// Creates a client for the bucket given by `--bucket`.
+pub fn from_matches(matches: &ArgMatches) -> S3ApiResult<S3Client> {
+    let bucket = S3Bucket::from_string(matches.single("bucket").as_required()?);
+    let builder = S3ClientConfigBuilder::from_env()?.user_agent_suffix(format!(
+        "{}/{}",
+        crate_name!(),
+        crate_version!()
+    ));
+
+    let builder = match matches.value_of("endpoint-url") {
+        Some(url) => builder.endpoint_url(Url::parse(url)?),
+        None => builder,
+    };
+    let builder = match timeout_of(matches, "cli-connect-timeout")? {
+        Some(timeout) => builder.connect_timeout(timeout),
+        None => builder,
+    };
+    let builder = match timeout_of(matches, "cli-read-timeout")? {
+        Some(timeout) => builder.read_timeout(timeout),
+        None => builder,
+    };
+    Ok(S3Client::from_env(bucket)?.with_config(builder.build())?)
+}
+
+/// Returns `Some(None)` for 0, which means no timeout.
+fn timeout_of(matches: &ArgMatches, name: &str) -> S3ApiResult<Option<Option<Duration>>> {
+    let seconds: Option<u64> = matches.single(name).as_optional()?;
+    let timeout = seconds.map(|seconds| match seconds {
+        0 => None,
+        _ => Some(Duration::from_secs(seconds)),
+    });
+    Ok(timeout)
+}
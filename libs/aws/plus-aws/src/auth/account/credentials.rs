@@ -1,6 +1,6 @@
 use crate::auth::{AccessKey, SecretKey};
 use crate::env::aws;
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Credentials {
     pub access_key: AccessKey,
     pub secret_key: SecretKey,
@@ -3,6 +3,7 @@ use characters::{AsStr, MultiByte};
 use std::fmt;
 use std::str::FromStr;
 
+#[derive(Clone)]
 pub struct SecretKey(String);
 
 impl SecretKey {
@@ -20,10 +20,14 @@ impl Signer {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Signature(String);
 
 impl Signature {
+    pub fn new<A: Into<String>>(raw: A) -> Signature {
+        Signature(raw.into())
+    }
+
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
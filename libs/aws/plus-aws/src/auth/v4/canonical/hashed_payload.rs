@@ -1,9 +1,6 @@
-use crate::io::stream::bytes_stream;
-use bytes::Bytes;
-use futures_util::{future, stream::Stream, TryStreamExt};
+use crate::io::stream::{DigestAlgorithm, MultiDigester};
 use hex::ToHex;
 use sha2::{Digest, Sha256};
-use std::io;
 use tokio::fs;
 
 #[derive(Debug)]
@@ -18,31 +15,24 @@ impl HashedPayload {
         Self::new("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
     }
 
+    /// Used when the payload is not known at the time of signing,
+    /// like the requests by presigned URLs.
+    pub fn unsigned() -> Self {
+        Self::new("UNSIGNED-PAYLOAD")
+    }
+
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
 
     pub async fn from_file(file: fs::File) -> crate::Result<Self> {
-        let stream = bytes_stream::from_file(file);
-        let hash = calculate(stream).await?;
-        Ok(hash)
+        let digester = MultiDigester::new(&[DigestAlgorithm::Sha256]);
+        let digests = digester.digest_reader(file).await?;
+        Ok(digests.hashed_payload().expect("SHA-256 is requested"))
     }
-}
 
-async fn calculate<S>(stream: S) -> crate::Result<HashedPayload>
-where
-    S: Stream<Item = io::Result<Bytes>>,
-    S: Unpin,
-{
-    let mut sha = Sha256::default();
-    stream
-        .try_for_each(|item: Bytes| {
-            sha.input(item);
-            future::ok(())
-        })
-        .await?;
-
-    let hex: String = sha.result().as_slice().encode_hex();
-    let hash = HashedPayload::new(hex);
-    Ok(hash)
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let hex: String = Sha256::digest(bytes).as_slice().encode_hex();
+        HashedPayload::new(hex)
+    }
 }
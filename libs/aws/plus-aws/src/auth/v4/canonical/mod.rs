@@ -17,7 +17,7 @@ mod hashed_payload;
 pub use hashed_payload::HashedPayload;
 
 mod query;
-pub use query::CanonicalQueryString;
+pub use query::{uri_encode, CanonicalQueryString};
 
 mod signed_headers;
 pub use signed_headers::SignedHeaders;
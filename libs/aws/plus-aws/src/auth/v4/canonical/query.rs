@@ -30,7 +30,7 @@ impl From<&Url> for CanonicalQueryString {
 }
 
 /// Encode a URI following the specific requirements of the AWS.
-fn uri_encode(string: &str, encode_slash: bool) -> String {
+pub fn uri_encode(string: &str, encode_slash: bool) -> String {
     /*
         rf.
         https://github.com/durch/rust-s3/blob/a2b6879f3be920d61394d2c5ebecdfc779d20713/src/signing.rs#L18-L36
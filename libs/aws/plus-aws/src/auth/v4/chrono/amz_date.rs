@@ -9,7 +9,7 @@ use chrono::{DateTime, Utc};
 /// ## See also
 ///  * [Handling Dates in Signature Version 4 - AWS General Reference](https://docs.aws.amazon.com/general/latest/gr/sigv4-date-handling.html)
 ///
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct AmzDate(String);
 
 impl AmzDate {
@@ -3,3 +3,4 @@ pub mod canonical;
 pub mod chrono;
 pub mod request;
 pub mod sign;
+pub mod streaming;
@@ -4,7 +4,10 @@ use crate::auth::v4::canonical::SignedHeaders;
 use crate::auth::v4::sign::{Algorithm, CredentialScope};
 use http::HeaderValue;
 
-pub struct Authorization(String);
+pub struct Authorization {
+    raw: String,
+    signature: Signature,
+}
 
 impl Authorization {
     pub fn new(
@@ -14,17 +17,25 @@ impl Authorization {
         signed_headers: &SignedHeaders,
         signature: &Signature,
     ) -> Authorization {
-        Authorization(format!(
+        let raw = format!(
             "{algorithm} Credential={access_key}/{scope},SignedHeaders={header},Signature={signature}",
             algorithm = algorithm.as_str(),
             access_key = access_key.as_str(),
             scope = scope.as_str(),
             header = signed_headers.as_str(),
             signature = signature.as_str(),
-        ))
+        );
+        Authorization {
+            raw,
+            signature: signature.clone(),
+        }
     }
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.raw
+    }
+    /// Used as the seed signature of the `aws-chunked` payload.
+    pub fn signature(&self) -> &Signature {
+        &self.signature
     }
     pub fn to_header_value(&self) -> crate::Result<HeaderValue> {
         let value = self.as_str().parse()?;
@@ -1,9 +1,10 @@
 use crate::auth::account::Credentials;
-use crate::auth::v4::calculator::Signer;
+use crate::auth::v4::calculator::{Signature, Signer};
 use crate::auth::v4::canonical::HeadersCapturer;
 use crate::auth::v4::chrono::AmzDate;
 use crate::auth::v4::request::Authorization;
 use crate::auth::v4::sign::{Algorithm, CredentialScope, StringToSign};
+use crate::auth::v4::streaming::ChunkSigner;
 use chrono::{DateTime, Utc};
 use http::HeaderMap;
 
@@ -31,22 +32,28 @@ impl AuthorizationFactory<'_> {
         &self.amz_date
     }
 
-    pub fn create_from(self, headers: &HeaderMap) -> Authorization {
+    pub fn create_from(&self, headers: &HeaderMap) -> Authorization {
         let request = self.capturer.capture(headers);
         let algorithm = Algorithm::HmacSha256;
-        let string_to_sign = StringToSign::from(&algorithm, &self.amz_date, &self.scope, &request);
+        let string_to_sign = StringToSign::from(&algorithm, &self.amz_date, self.scope, &request);
         let signature = {
-            let signer = Signer::new(&self.credentials.secret_key, &self.scope);
+            let signer = Signer::new(&self.credentials.secret_key, self.scope);
             signer.sign(string_to_sign)
         };
         Authorization::new(
             &algorithm,
             &self.credentials.access_key,
-            &self.scope,
+            self.scope,
             &request.signed_headers,
             &signature,
         )
     }
+
+    /// Signs the chunks of the payload following the headers signed by `seed`.
+    pub fn chunk_signer(&self, seed: &Signature) -> ChunkSigner {
+        let signer = Signer::new(&self.credentials.secret_key, self.scope);
+        ChunkSigner::new(signer, self.amz_date.clone(), self.scope, seed)
+    }
 }
 
 pub trait AuthorizationParts {
@@ -4,3 +4,6 @@ pub use authorization::Authorization;
 mod factory;
 pub use factory::AuthorizationFactory;
 pub use factory::AuthorizationParts;
+
+mod presigned_url;
+pub use presigned_url::PresignedUrlFactory;
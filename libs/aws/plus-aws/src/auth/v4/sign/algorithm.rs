@@ -1,12 +1,20 @@
 #[derive(Debug)]
 pub enum Algorithm {
     HmacSha256,
+
+    /// Signs each chunk of the `aws-chunked` payload.
+    HmacSha256Payload,
+
+    /// Signs the trailing headers of the `aws-chunked` payload.
+    HmacSha256Trailer,
 }
 
 impl Algorithm {
     pub fn as_str(&self) -> &str {
         match self {
             Algorithm::HmacSha256 => "AWS4-HMAC-SHA256",
+            Algorithm::HmacSha256Payload => "AWS4-HMAC-SHA256-PAYLOAD",
+            Algorithm::HmacSha256Trailer => "AWS4-HMAC-SHA256-TRAILER",
         }
     }
 }
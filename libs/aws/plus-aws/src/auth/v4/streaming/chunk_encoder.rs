@@ -0,0 +1,186 @@
+use crate::auth::v4::streaming::ChunkSigner;
+use bytes::{BufMut, Bytes, BytesMut};
+
+/// Frames the chunks of `aws-chunked` encoding,
+/// with the signatures if the signer is given.
+///
+/// ## Example
+/// ```text
+/// 10000;chunk-signature=ad80c730a21e5b8d04586a2213dd63b9a0e99e0e2307b0ade35a65485a288648\r\n
+/// <65536-bytes>\r\n
+/// 0;chunk-signature=b6c6ea8a5354eaf15b3cb7646744f4275b71ea724fed81ceb9323e279d449df9\r\n
+/// \r\n
+/// ```
+#[derive(Debug)]
+pub struct ChunkEncoder {
+    signer: Option<ChunkSigner>,
+}
+
+/// Length of `;chunk-signature=` followed by the signature in hex.
+const SIGNATURE_EXTENSION_LENGTH: usize = 17 + 64;
+
+/// Length of `x-amz-trailer-signature:` followed by the signature in hex and CRLF.
+const TRAILER_SIGNATURE_LENGTH: usize = 24 + 64 + 2;
+
+impl ChunkEncoder {
+    pub fn signed(signer: ChunkSigner) -> Self {
+        ChunkEncoder {
+            signer: Some(signer),
+        }
+    }
+
+    pub fn unsigned() -> Self {
+        ChunkEncoder { signer: None }
+    }
+
+    pub fn encode(&mut self, chunk: &[u8]) -> Bytes {
+        let mut buffer = BytesMut::with_capacity(chunk.len() + 128);
+        self.put_header(&mut buffer, chunk);
+        buffer.put_slice(chunk);
+        buffer.put_slice(b"\r\n");
+        buffer.freeze()
+    }
+
+    /// Encodes the last chunk, followed by the trailing header like `x-amz-checksum-crc32`.
+    pub fn encode_last_with_trailer(&mut self, name: &str, value: &str) -> Bytes {
+        let mut buffer = BytesMut::with_capacity(256);
+        self.put_header(&mut buffer, &[]);
+
+        let trailer = format!("{}:{}", name, value);
+        buffer.put_slice(trailer.as_bytes());
+        buffer.put_slice(b"\r\n");
+        if let Some(signer) = self.signer.as_mut() {
+            let signature = signer.sign_trailer(&format!("{}\n", trailer));
+            buffer.put_slice(b"x-amz-trailer-signature:");
+            buffer.put_slice(signature.as_str().as_bytes());
+            buffer.put_slice(b"\r\n");
+        }
+        buffer.put_slice(b"\r\n");
+        buffer.freeze()
+    }
+
+    /// Calculates the `Content-Length` of the encoded payload,
+    /// where the chunks except the last one have the same size.
+    ///
+    /// The `trailer_length` is the length of `{name}:{value}`.
+    pub fn encoded_length(
+        signed: bool,
+        decoded_length: u64,
+        chunk_size: u64,
+        trailer_length: Option<u64>,
+    ) -> u64 {
+        let header_length = |size: u64| {
+            let extension = if signed {
+                SIGNATURE_EXTENSION_LENGTH
+            } else {
+                0
+            };
+            format!("{:x}", size).len() as u64 + extension as u64 + 2
+        };
+        let chunk_length = |size: u64| header_length(size) + size + 2;
+
+        let full_chunks = decoded_length / chunk_size;
+        let rest = decoded_length % chunk_size;
+        let mut length = full_chunks * chunk_length(chunk_size);
+        if rest > 0 {
+            length += chunk_length(rest);
+        }
+        length += header_length(0) + 2;
+        if let Some(trailer_length) = trailer_length {
+            length += trailer_length + 2;
+            if signed {
+                length += TRAILER_SIGNATURE_LENGTH as u64;
+            }
+        }
+        length
+    }
+
+    fn put_header(&mut self, buffer: &mut BytesMut, chunk: &[u8]) {
+        buffer.put_slice(format!("{:x}", chunk.len()).as_bytes());
+        if let Some(signer) = self.signer.as_mut() {
+            let signature = signer.sign_chunk(chunk);
+            buffer.put_slice(b";chunk-signature=");
+            buffer.put_slice(signature.as_str().as_bytes());
+        }
+        buffer.put_slice(b"\r\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChunkEncoder;
+    use crate::auth::v4::calculator::{Signature, Signer};
+    use crate::auth::v4::chrono::{AmzDate, DateStamp};
+    use crate::auth::v4::sign::CredentialScope;
+    use crate::auth::v4::streaming::ChunkSigner;
+    use crate::auth::SecretKey;
+    use crate::index::{RegionCode, ServiceCode};
+
+    /// rf. the example of
+    /// [Signature Calculations for the Authorization Header: Transferring Payload in Multiple Chunks (Chunked Upload)](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming.html)
+    #[test]
+    fn it_can_sign_chunks() {
+        let mut encoder = ChunkEncoder::signed(create_signer());
+        let data = vec![b'a'; 65536 + 1024];
+        let (first, second) = data.split_at(65536);
+
+        let chunk = encoder.encode(first);
+        assert!(chunk.starts_with(
+            b"10000;chunk-signature=ad80c730a21e5b8d04586a2213dd63b9a0e99e0e2307b0ade35a65485a288648\r\naaa"
+        ));
+        let chunk = encoder.encode(second);
+        assert!(chunk.starts_with(
+            b"400;chunk-signature=0055627c9e194cb4542bae2aa5492e3c1575bbb81b612b7d234b86a503ef5497\r\naaa"
+        ));
+        let chunk = encoder.encode(&[]);
+        assert_eq!(
+            chunk,
+            "0;chunk-signature=b6c6ea8a5354eaf15b3cb7646744f4275b71ea724fed81ceb9323e279d449df9\r\n\r\n"
+        );
+        assert_eq!(
+            ChunkEncoder::encoded_length(true, 66560, 65536, None),
+            66824
+        );
+    }
+
+    #[test]
+    fn it_can_encode_trailer() {
+        let mut encoder = ChunkEncoder::unsigned();
+        let mut encoded = encoder.encode(b"Hello, World!").to_vec();
+        encoded.extend(encoder.encode_last_with_trailer("x-amz-checksum-crc32", "7ErD0A=="));
+        assert_eq!(
+            String::from_utf8(encoded.clone()).unwrap(),
+            "d\r\nHello, World!\r\n0\r\nx-amz-checksum-crc32:7ErD0A==\r\n\r\n"
+        );
+        let trailer_length = Some("x-amz-checksum-crc32:7ErD0A==".len() as u64);
+        assert_eq!(
+            ChunkEncoder::encoded_length(false, 13, 65536, trailer_length),
+            encoded.len() as u64
+        );
+
+        let mut encoder = ChunkEncoder::signed(create_signer());
+        let mut encoded = encoder.encode(b"Hello, World!").to_vec();
+        encoded.extend(encoder.encode_last_with_trailer("x-amz-checksum-crc32", "7ErD0A=="));
+        assert_eq!(
+            ChunkEncoder::encoded_length(true, 13, 65536, trailer_length),
+            encoded.len() as u64
+        );
+    }
+
+    fn create_signer() -> ChunkSigner {
+        let secret_key = SecretKey::new("wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY");
+        let scope = CredentialScope::v4(
+            DateStamp::new("20130524"),
+            &RegionCode::UsEast1,
+            &ServiceCode::S3,
+        );
+        let seed =
+            Signature::new("4f232c4386841ef735655705268965c44a0e4690baa4adea153f7db9fa80a0a9");
+        ChunkSigner::new(
+            Signer::new(&secret_key, &scope),
+            AmzDate::new("20130524T000000Z"),
+            &scope,
+            &seed,
+        )
+    }
+}
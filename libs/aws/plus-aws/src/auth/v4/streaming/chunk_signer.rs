@@ -0,0 +1,59 @@
+use crate::auth::v4::calculator::{Signature, Signer};
+use crate::auth::v4::canonical::HashedPayload;
+use crate::auth::v4::chrono::AmzDate;
+use crate::auth::v4::sign::{Algorithm, CredentialScope, StringToSign};
+
+/// Signs the chunks in order, each of which is chained to the previous signature.
+#[derive(Debug)]
+pub struct ChunkSigner {
+    signer: Signer,
+    amz_date: AmzDate,
+    scope: String,
+    previous: Signature,
+}
+
+impl ChunkSigner {
+    pub fn new(
+        signer: Signer,
+        amz_date: AmzDate,
+        scope: &CredentialScope,
+        seed: &Signature,
+    ) -> Self {
+        ChunkSigner {
+            signer,
+            amz_date,
+            scope: scope.as_str().to_string(),
+            previous: seed.clone(),
+        }
+    }
+
+    /// The last chunk is the empty one.
+    pub fn sign_chunk(&mut self, chunk: &[u8]) -> Signature {
+        let hash = format!(
+            "{empty}\n{chunk}",
+            empty = HashedPayload::empty().as_str(),
+            chunk = HashedPayload::from_bytes(chunk).as_str(),
+        );
+        self.sign(Algorithm::HmacSha256Payload, &hash)
+    }
+
+    /// Takes the trailing headers like `x-amz-checksum-crc32:AAAAAA==\n`.
+    pub fn sign_trailer(&mut self, trailer: &str) -> Signature {
+        let hash = HashedPayload::from_bytes(trailer.as_bytes());
+        self.sign(Algorithm::HmacSha256Trailer, hash.as_str())
+    }
+
+    fn sign(&mut self, algorithm: Algorithm, hash: &str) -> Signature {
+        let string_to_sign = StringToSign::new(format!(
+            "{algorithm}\n{amz_date}\n{scope}\n{previous}\n{hash}",
+            algorithm = algorithm.as_str(),
+            amz_date = self.amz_date.as_str(),
+            scope = self.scope,
+            previous = self.previous.as_str(),
+            hash = hash,
+        ));
+        let signature = self.signer.sign(string_to_sign);
+        self.previous = signature.clone();
+        signature
+    }
+}
@@ -0,0 +1,13 @@
+/*
+    see also:
+    https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming.html
+*/
+
+mod chunk_encoder;
+pub use chunk_encoder::ChunkEncoder;
+
+mod chunk_signer;
+pub use chunk_signer::ChunkSigner;
+
+mod streaming_payload;
+pub use streaming_payload::StreamingPayload;
@@ -0,0 +1,36 @@
+use crate::auth::v4::canonical::HashedPayload;
+
+/// Used as `x-amz-content-sha256` of the payload sent by `aws-chunked` encoding.
+///
+/// ## See also
+///  * [Signature Calculations for the Authorization Header: Transferring Payload in Multiple Chunks (Chunked Upload)](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming.html)
+///  * [Signature calculation: Including trailing headers (chunked upload)](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming-trailers.html)
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StreamingPayload {
+    Signed,
+    SignedWithTrailer,
+    UnsignedWithTrailer,
+}
+
+impl StreamingPayload {
+    pub fn as_str(&self) -> &str {
+        match self {
+            StreamingPayload::Signed => "STREAMING-AWS4-HMAC-SHA256-PAYLOAD",
+            StreamingPayload::SignedWithTrailer => "STREAMING-AWS4-HMAC-SHA256-PAYLOAD-TRAILER",
+            StreamingPayload::UnsignedWithTrailer => "STREAMING-UNSIGNED-PAYLOAD-TRAILER",
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        !matches!(self, StreamingPayload::UnsignedWithTrailer)
+    }
+
+    pub fn has_trailer(&self) -> bool {
+        !matches!(self, StreamingPayload::Signed)
+    }
+
+    pub fn to_hashed_payload(&self) -> HashedPayload {
+        HashedPayload::new(self.as_str())
+    }
+}
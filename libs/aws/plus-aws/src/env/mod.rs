@@ -10,4 +10,16 @@ pub mod aws {
     pub fn secret_key() -> EnvVar {
         env_var("AWS_SECRET_ACCESS_KEY")
     }
+    pub fn retry_mode() -> EnvVar {
+        env_var("AWS_RETRY_MODE")
+    }
+    pub fn max_attempts() -> EnvVar {
+        env_var("AWS_MAX_ATTEMPTS")
+    }
+    pub fn use_dualstack_endpoint() -> EnvVar {
+        env_var("AWS_USE_DUALSTACK_ENDPOINT")
+    }
+    pub fn use_fips_endpoint() -> EnvVar {
+        env_var("AWS_USE_FIPS_ENDPOINT")
+    }
 }
@@ -17,12 +17,18 @@ pub enum Error {
     #[fail(display = "HostNotFound > {}", 0)]
     HostNotFound(Url),
 
+    #[fail(display = "InvalidExpiration > {} seconds", 0)]
+    InvalidExpiration(u64),
+
     #[fail(display = "InvalidHeaderName > {}", 0)]
     InvalidHeaderName(header::InvalidHeaderName),
 
     #[fail(display = "InvalidHeaderValue > {}", 0)]
     InvalidHeaderValue(header::InvalidHeaderValue),
 
+    #[fail(display = "InvalidRegion > {}", 0)]
+    InvalidRegion(String),
+
     #[fail(display = "std::io::Error > {}", 0)]
     StdIoError(std::io::Error),
 }
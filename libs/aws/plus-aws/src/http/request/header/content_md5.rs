@@ -0,0 +1,49 @@
+use crate::http::request::HeaderFragment;
+use crate::http::request::ToHeaderFragment;
+use http::header::HeaderName;
+use md5::{Digest, Md5};
+use std::str::FromStr;
+
+/// > The base64-encoded 128-bit MD5 digest of the message (without the headers)
+/// > according to RFC 1864.
+///
+/// ## See also
+///  * [Common Request Headers - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/RESTCommonRequestHeaders.html)
+///
+#[derive(Debug)]
+pub struct ContentMd5(String);
+
+impl ContentMd5 {
+    pub fn new<A: Into<String>>(key: A) -> Self {
+        Self(key.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let digest = Md5::digest(bytes);
+        Self::new(base64::encode(digest.as_slice()))
+    }
+}
+
+impl ToHeaderFragment for ContentMd5 {
+    fn into(self) -> crate::Result<HeaderFragment> {
+        Ok(HeaderFragment {
+            key: HeaderName::from_str("Content-MD5")?,
+            value: self.as_str().parse()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContentMd5;
+
+    #[test]
+    fn it_works() {
+        let md5 = ContentMd5::from_bytes(b"Hello, World!");
+        assert_eq!(md5.as_str(), "ZajifYh5KDgxtmS9i38K1A==");
+    }
+}
@@ -3,7 +3,7 @@ use crate::http::request::ToHeaderFragment;
 use http::header::CONTENT_TYPE;
 use std::str::FromStr;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ContentType(String);
 
 impl ContentType {
@@ -7,6 +7,9 @@ pub use amz_date::AmzDate;
 mod content_length;
 pub use content_length::ContentLength;
 
+mod content_md5;
+pub use content_md5::ContentMd5;
+
 mod content_type;
 pub use content_type::ContentType;
 
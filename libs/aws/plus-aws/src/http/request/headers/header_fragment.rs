@@ -10,6 +10,12 @@ pub trait ToHeaderFragment {
     fn into(self) -> crate::Result<HeaderFragment>;
 }
 
+impl ToHeaderFragment for HeaderFragment {
+    fn into(self) -> crate::Result<HeaderFragment> {
+        Ok(self)
+    }
+}
+
 impl<'a> ToHeaderFragment for (&'static str, &'a str) {
     fn into(self) -> crate::Result<HeaderFragment> {
         let (key, value) = self;
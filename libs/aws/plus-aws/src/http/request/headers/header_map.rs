@@ -14,6 +14,11 @@ pub trait RichHeaderMap: Sized {
     where
         A: ToHeaderFragment;
 
+    fn push_all<A, I>(self, headers: I) -> crate::Result<Self>
+    where
+        A: ToHeaderFragment,
+        I: IntoIterator<Item = A>;
+
     fn host(self, url: &Url) -> crate::Result<Self>;
 
     fn authorize_with(self, factory: AuthorizationFactory) -> crate::Result<Self>;
@@ -40,11 +45,23 @@ impl RichHeaderMap for HeaderMap {
         }
     }
 
+    fn push_all<A, I>(self, headers: I) -> crate::Result<Self>
+    where
+        A: ToHeaderFragment,
+        I: IntoIterator<Item = A>,
+    {
+        headers
+            .into_iter()
+            .try_fold(self, |map, header| map.push(header))
+    }
+
     fn host(mut self, url: &Url) -> crate::Result<Self> {
-        let host = url
-            .host_str()
-            .ok_or_else(|| HostNotFound(url.clone()))?
-            .parse()?;
+        let host = url.host_str().ok_or_else(|| HostNotFound(url.clone()))?;
+        // the port is omitted by `Url` when it is the default of the scheme.
+        let host = match url.port() {
+            Some(port) => format!("{}:{}", host, port).parse()?,
+            None => host.parse()?,
+        };
 
         self.insert(HOST, host);
         Ok(self)
@@ -55,3 +72,23 @@ impl RichHeaderMap for HeaderMap {
         Ok(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RichHeaderMap;
+    use http::header::HOST;
+    use http::HeaderMap;
+    use url::Url;
+
+    #[test]
+    fn it_includes_non_default_port_in_host() -> crate::Result<()> {
+        let url = Url::parse("http://localhost:9000/bucket/key").unwrap();
+        let headers = HeaderMap::new().host(&url)?;
+        assert_eq!(headers[HOST], "localhost:9000");
+
+        let url = Url::parse("https://example.s3.amazonaws.com:443/key").unwrap();
+        let headers = HeaderMap::new().host(&url)?;
+        assert_eq!(headers[HOST], "example.s3.amazonaws.com");
+        Ok(())
+    }
+}
@@ -1,3 +1,6 @@
+mod partition;
+pub use partition::Partition;
+
 mod region;
 pub use region::RegionCode;
 
@@ -0,0 +1,191 @@
+/// A group of regions sharing the DNS suffix and the credentials.
+///
+/// see also:
+///  * [AWS Partitions - AWS Whitepapers](https://docs.aws.amazon.com/whitepapers/latest/aws-fault-isolation-boundaries/partitions.html)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Partition {
+    Aws,
+    AwsCn,
+    AwsUsGov,
+    AwsIso,
+    AwsIsoB,
+    AwsIsoE,
+    AwsIsoF,
+}
+
+/// Partitions sharing a region prefix must come before the general ones,
+/// since `us-gov-west-1` should not be taken as a region of `aws`.
+const PARTITIONS: [Partition; 7] = [
+    Partition::AwsUsGov,
+    Partition::AwsIso,
+    Partition::AwsIsoB,
+    Partition::AwsIsoE,
+    Partition::AwsIsoF,
+    Partition::AwsCn,
+    Partition::Aws,
+];
+
+impl Partition {
+    /// Finds the partition of a known region,
+    /// or of a region following the naming pattern of the partition.
+    pub fn of(region: &str) -> Option<Partition> {
+        let known = PARTITIONS
+            .iter()
+            .find(|partition| partition.regions().contains(&region));
+
+        known
+            .or_else(|| PARTITIONS.iter().find(|p| p.matches_pattern(region)))
+            .copied()
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Partition::Aws => "aws",
+            Partition::AwsCn => "aws-cn",
+            Partition::AwsUsGov => "aws-us-gov",
+            Partition::AwsIso => "aws-iso",
+            Partition::AwsIsoB => "aws-iso-b",
+            Partition::AwsIsoE => "aws-iso-e",
+            Partition::AwsIsoF => "aws-iso-f",
+        }
+    }
+
+    pub fn dns_suffix(&self) -> &str {
+        match self {
+            Partition::Aws | Partition::AwsUsGov => "amazonaws.com",
+            Partition::AwsCn => "amazonaws.com.cn",
+            Partition::AwsIso => "c2s.ic.gov",
+            Partition::AwsIsoB => "sc2s.sgov.gov",
+            Partition::AwsIsoE => "cloud.adc-e.uk",
+            Partition::AwsIsoF => "csp.hci.ic.gov",
+        }
+    }
+
+    pub fn supports_dualstack(&self) -> bool {
+        matches!(
+            self,
+            Partition::Aws | Partition::AwsCn | Partition::AwsUsGov
+        )
+    }
+
+    pub fn supports_fips(&self) -> bool {
+        !matches!(self, Partition::AwsCn)
+    }
+
+    pub fn regions(&self) -> &'static [&'static str] {
+        match self {
+            Partition::Aws => &[
+                "af-south-1",
+                "ap-east-1",
+                "ap-northeast-1",
+                "ap-northeast-2",
+                "ap-northeast-3",
+                "ap-south-1",
+                "ap-south-2",
+                "ap-southeast-1",
+                "ap-southeast-2",
+                "ap-southeast-3",
+                "ap-southeast-4",
+                "ap-southeast-5",
+                "ap-southeast-7",
+                "ca-central-1",
+                "ca-west-1",
+                "eu-central-1",
+                "eu-central-2",
+                "eu-north-1",
+                "eu-south-1",
+                "eu-south-2",
+                "eu-west-1",
+                "eu-west-2",
+                "eu-west-3",
+                "il-central-1",
+                "me-central-1",
+                "me-south-1",
+                "mx-central-1",
+                "sa-east-1",
+                "us-east-1",
+                "us-east-2",
+                "us-west-1",
+                "us-west-2",
+            ],
+            Partition::AwsCn => &["cn-north-1", "cn-northwest-1"],
+            Partition::AwsUsGov => &["us-gov-east-1", "us-gov-west-1"],
+            Partition::AwsIso => &["us-iso-east-1", "us-iso-west-1"],
+            Partition::AwsIsoB => &["us-isob-east-1"],
+            Partition::AwsIsoE => &["eu-isoe-west-1"],
+            Partition::AwsIsoF => &["us-isof-east-1", "us-isof-south-1"],
+        }
+    }
+
+    fn region_prefixes(&self) -> &'static [&'static str] {
+        match self {
+            Partition::Aws => &["af", "ap", "ca", "eu", "il", "me", "mx", "sa", "us"],
+            Partition::AwsCn => &["cn"],
+            Partition::AwsUsGov => &["us-gov"],
+            Partition::AwsIso => &["us-iso"],
+            Partition::AwsIsoB => &["us-isob"],
+            Partition::AwsIsoE => &["eu-isoe"],
+            Partition::AwsIsoF => &["us-isof"],
+        }
+    }
+
+    /// Matches `{prefix}-{word}-{number}` like `ap-northeast-1`.
+    fn matches_pattern(&self, region: &str) -> bool {
+        self.region_prefixes().iter().any(|prefix| {
+            let rest = match region.strip_prefix(prefix) {
+                Some(rest) => rest,
+                None => return false,
+            };
+            let mut labels = rest.splitn(3, '-');
+            let is_word = |x: &str| {
+                !x.is_empty()
+                    && x.chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            };
+            let is_number = |x: &str| !x.is_empty() && x.chars().all(|c| c.is_ascii_digit());
+
+            labels.next() == Some("")
+                && labels.next().is_some_and(is_word)
+                && labels.next().is_some_and(is_number)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Partition;
+
+    #[test]
+    fn it_finds_partition_of_known_regions() {
+        assert_eq!(Partition::of("ap-northeast-1"), Some(Partition::Aws));
+        assert_eq!(Partition::of("cn-north-1"), Some(Partition::AwsCn));
+        assert_eq!(Partition::of("us-gov-west-1"), Some(Partition::AwsUsGov));
+        assert_eq!(Partition::of("us-isob-east-1"), Some(Partition::AwsIsoB));
+    }
+
+    #[test]
+    fn it_finds_partition_of_plausible_regions() {
+        assert_eq!(Partition::of("eu-east-9"), Some(Partition::Aws));
+        assert_eq!(Partition::of("cn-south-2"), Some(Partition::AwsCn));
+        assert_eq!(Partition::of("us-gov-north-1"), Some(Partition::AwsUsGov));
+        assert_eq!(Partition::of("us-iso-north-1"), Some(Partition::AwsIso));
+        assert_eq!(Partition::of("eu-isoe-north-2"), Some(Partition::AwsIsoE));
+    }
+
+    #[test]
+    fn it_rejects_implausible_regions() {
+        let regions = [
+            "",
+            "tokyo",
+            "us-east",
+            "us-east-",
+            "us-east-1a",
+            "US-EAST-1",
+            "xx-east-1",
+            "us-east-1.evil.example.com",
+        ];
+        for region in regions.iter() {
+            assert_eq!(Partition::of(region), None, "{}", region);
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use crate::env::aws;
+use crate::index::Partition;
 use crate::verbs::AsBytes;
 use std::str::FromStr;
 
 /// see also:
 ///  * [AWS Service Endpoints - AWS General Reference](https://docs.aws.amazon.com/general/latest/gr/rande.html)
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum RegionCode {
     ApNorthEast1,
     UsEast1,
@@ -28,6 +29,12 @@ impl RegionCode {
             RegionCode::Any(code) => &code,
         }
     }
+
+    /// Falls back to `aws` for the regions which are not known,
+    /// like the ones given by the `x-amz-bucket-region` header.
+    pub fn partition(&self) -> Partition {
+        Partition::of(self.as_str()).unwrap_or(Partition::Aws)
+    }
 }
 
 impl AsBytes for RegionCode {
@@ -39,7 +46,34 @@ impl AsBytes for RegionCode {
 impl FromStr for RegionCode {
     type Err = crate::Error;
 
+    /// Accepts the known regions and the ones following the naming pattern of a partition.
     fn from_str(s: &str) -> crate::Result<Self> {
-        Ok(Self::any(s))
+        match Partition::of(s) {
+            Some(_) => Ok(Self::any(s)),
+            None => Err(crate::Error::InvalidRegion(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RegionCode;
+    use crate::index::Partition;
+
+    #[test]
+    fn it_parses_plausible_regions() -> crate::Result<()> {
+        let region: RegionCode = "cn-northwest-1".parse()?;
+        assert_eq!(region.as_str(), "cn-northwest-1");
+        assert_eq!(region.partition(), Partition::AwsCn);
+
+        let region: RegionCode = "ap-northeast-9".parse()?;
+        assert_eq!(region.partition(), Partition::Aws);
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_implausible_regions() {
+        assert!("tokyo".parse::<RegionCode>().is_err());
+        assert!("us-east-1/".parse::<RegionCode>().is_err());
     }
 }
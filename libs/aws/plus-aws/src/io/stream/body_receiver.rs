@@ -10,3 +10,17 @@ pub trait BodyReceiver {
         S: Stream<Item = Result<Bytes, Self::Err>>,
         S: Send;
 }
+
+/// Allows the caller to take the received result out of the receiver afterwards.
+#[async_trait]
+impl<A: BodyReceiver + Send> BodyReceiver for &mut A {
+    type Err = A::Err;
+
+    async fn receive_body_from<S>(&mut self, body: S) -> Result<usize, Self::Err>
+    where
+        S: Stream<Item = Result<Bytes, Self::Err>>,
+        S: Send,
+    {
+        (**self).receive_body_from(body).await
+    }
+}
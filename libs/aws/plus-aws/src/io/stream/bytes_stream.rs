@@ -2,10 +2,15 @@ use bytes::{Bytes, BytesMut};
 use futures_util::{stream::Stream, TryStreamExt};
 use std::io;
 use tokio::fs::File;
+use tokio::io::AsyncRead;
 use tokio_util::codec::{BytesCodec, FramedRead};
 
 /// rf. [Turning a file into futures Stream](https://users.rust-lang.org/t/turning-a-file-into-futures-stream/33480/2)
 pub fn from_file(file: File) -> impl Stream<Item = io::Result<Bytes>> {
+    from_reader(file)
+}
+
+pub fn from_reader<R: AsyncRead>(reader: R) -> impl Stream<Item = io::Result<Bytes>> {
     let decoder = BytesCodec::new();
-    FramedRead::new(file, decoder).map_ok(BytesMut::freeze)
+    FramedRead::new(reader, decoder).map_ok(BytesMut::freeze)
 }
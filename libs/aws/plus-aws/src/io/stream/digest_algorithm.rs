@@ -0,0 +1,9 @@
+/// Algorithms which `MultiDigester` can calculate in a single pass.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DigestAlgorithm {
+    Crc32,
+    Crc32c,
+    Md5,
+    Sha1,
+    Sha256,
+}
@@ -0,0 +1,55 @@
+use crate::auth::v4::canonical::HashedPayload;
+use crate::http::request::header::ContentMd5;
+use crate::io::stream::DigestAlgorithm;
+use hex::ToHex;
+
+/// Raw bytes of the digest, encoded as each header requires.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DigestValue {
+    pub algorithm: DigestAlgorithm,
+    bytes: Vec<u8>,
+}
+
+impl DigestValue {
+    pub fn new(algorithm: DigestAlgorithm, bytes: Vec<u8>) -> Self {
+        DigestValue { algorithm, bytes }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.bytes.encode_hex()
+    }
+
+    pub fn to_base64(&self) -> String {
+        base64::encode(&self.bytes)
+    }
+}
+
+/// Digests calculated by `MultiDigester`, one for each requested algorithm.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Digests(Vec<DigestValue>);
+
+impl Digests {
+    pub fn new(values: Vec<DigestValue>) -> Self {
+        Digests(values)
+    }
+
+    pub fn get(&self, algorithm: DigestAlgorithm) -> Option<&DigestValue> {
+        self.0.iter().find(|value| value.algorithm == algorithm)
+    }
+
+    /// Returns `None` unless SHA-256 was requested.
+    pub fn hashed_payload(&self) -> Option<HashedPayload> {
+        let value = self.get(DigestAlgorithm::Sha256)?;
+        Some(HashedPayload::new(value.to_hex()))
+    }
+
+    /// Returns `None` unless MD5 was requested.
+    pub fn content_md5(&self) -> Option<ContentMd5> {
+        let value = self.get(DigestAlgorithm::Md5)?;
+        Some(ContentMd5::new(value.to_base64()))
+    }
+}
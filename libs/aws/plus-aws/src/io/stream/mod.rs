@@ -2,3 +2,12 @@ pub mod bytes_stream;
 
 mod body_receiver;
 pub use body_receiver::BodyReceiver;
+
+mod digest_algorithm;
+pub use digest_algorithm::DigestAlgorithm;
+
+mod digests;
+pub use digests::{DigestValue, Digests};
+
+mod multi_digester;
+pub use multi_digester::MultiDigester;
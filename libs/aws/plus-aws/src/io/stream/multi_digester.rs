@@ -0,0 +1,181 @@
+use crate::io::stream::{DigestAlgorithm, DigestValue, Digests};
+use futures_util::future;
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use std::io;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Feeds every chunk into all the requested hashers,
+/// so that a payload is read only once to calculate all its digests.
+pub struct MultiDigester {
+    hashers: Vec<Hasher>,
+    blocking_pool: bool,
+}
+
+impl MultiDigester {
+    /// Size of the buffer to read the payload by `digest_reader`.
+    pub const BUFFER_SIZE: usize = 1024 * 1024;
+
+    pub fn new(algorithms: &[DigestAlgorithm]) -> Self {
+        MultiDigester {
+            hashers: algorithms.iter().map(|x| Hasher::new(*x)).collect(),
+            blocking_pool: false,
+        }
+    }
+
+    /// Hashes the chunks on the blocking thread pool of tokio,
+    /// while the next chunk is read.
+    pub fn on_blocking_pool(self) -> Self {
+        MultiDigester {
+            blocking_pool: true,
+            ..self
+        }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        for hasher in self.hashers.iter_mut() {
+            hasher.update(bytes);
+        }
+    }
+
+    pub fn finish(self) -> Digests {
+        Digests::new(self.hashers.into_iter().map(Hasher::finish).collect())
+    }
+
+    /// Reads the reader to the end in a single pass.
+    pub async fn digest_reader<R>(mut self, mut reader: R) -> io::Result<Digests>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let mut chunk = read_chunk(&mut reader).await?;
+        while !chunk.is_empty() {
+            if self.blocking_pool {
+                let hashing = tokio::task::spawn_blocking(move || {
+                    self.update(&chunk);
+                    self
+                });
+                let (hashed, next) = future::join(hashing, read_chunk(&mut reader)).await;
+                self = hashed.map_err(io::Error::other)?;
+                chunk = next?;
+            } else {
+                self.update(&chunk);
+                chunk = read_chunk(&mut reader).await?;
+            }
+        }
+        Ok(self.finish())
+    }
+}
+
+/// Fills the buffer unless the reader reaches the end,
+/// since the reader may return much less bytes at once.
+async fn read_chunk<R>(reader: &mut R) -> io::Result<Vec<u8>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buffer = Vec::with_capacity(MultiDigester::BUFFER_SIZE);
+    reader
+        .take(MultiDigester::BUFFER_SIZE as u64)
+        .read_to_end(&mut buffer)
+        .await?;
+    Ok(buffer)
+}
+
+enum Hasher {
+    Crc32(crc32fast::Hasher),
+    Crc32c(u32),
+    Md5(Md5),
+    Sha1(Sha1),
+    Sha256(Sha256),
+}
+
+impl Hasher {
+    fn new(algorithm: DigestAlgorithm) -> Self {
+        match algorithm {
+            DigestAlgorithm::Crc32 => Hasher::Crc32(crc32fast::Hasher::new()),
+            DigestAlgorithm::Crc32c => Hasher::Crc32c(0),
+            DigestAlgorithm::Md5 => Hasher::Md5(Md5::default()),
+            DigestAlgorithm::Sha1 => Hasher::Sha1(Sha1::default()),
+            DigestAlgorithm::Sha256 => Hasher::Sha256(Sha256::default()),
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Hasher::Crc32(hasher) => hasher.update(bytes),
+            Hasher::Crc32c(crc) => *crc = crc32c::crc32c_append(*crc, bytes),
+            Hasher::Md5(hasher) => hasher.input(bytes),
+            Hasher::Sha1(hasher) => hasher.input(bytes),
+            Hasher::Sha256(hasher) => hasher.input(bytes),
+        }
+    }
+
+    fn finish(self) -> DigestValue {
+        let (algorithm, bytes) = match self {
+            Hasher::Crc32(hasher) => (
+                DigestAlgorithm::Crc32,
+                hasher.finalize().to_be_bytes().to_vec(),
+            ),
+            Hasher::Crc32c(crc) => (DigestAlgorithm::Crc32c, crc.to_be_bytes().to_vec()),
+            Hasher::Md5(hasher) => (DigestAlgorithm::Md5, hasher.result().to_vec()),
+            Hasher::Sha1(hasher) => (DigestAlgorithm::Sha1, hasher.result().to_vec()),
+            Hasher::Sha256(hasher) => (DigestAlgorithm::Sha256, hasher.result().to_vec()),
+        };
+        DigestValue::new(algorithm, bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultiDigester;
+    use crate::io::stream::DigestAlgorithm::{Crc32, Crc32c, Md5, Sha1, Sha256};
+    use crate::io::stream::Digests;
+    use std::io;
+    use tokio::runtime::{Builder, Runtime};
+
+    fn create_runtime() -> io::Result<Runtime> {
+        Builder::new().basic_scheduler().build()
+    }
+
+    fn assert_digests(digests: &Digests) {
+        let base64 = |algorithm| digests.get(algorithm).unwrap().to_base64();
+        assert_eq!(base64(Crc32), "7ErD0A==");
+        assert_eq!(base64(Crc32c), "TVUQaA==");
+        assert_eq!(base64(Sha1), "CgqfKmdylCVXq1NV12r0Qvj2XgE=");
+        assert_eq!(
+            digests.hashed_payload().unwrap().as_str(),
+            "dffd6021bb2bd5b0af676290809ec3a53191dd81c7f70a4b28688a362182986f"
+        );
+        assert_eq!(
+            digests.content_md5().unwrap().as_str(),
+            "ZajifYh5KDgxtmS9i38K1A=="
+        );
+    }
+
+    #[test]
+    fn it_calculates_all_digests_in_single_pass() -> io::Result<()> {
+        let digester = MultiDigester::new(&[Crc32, Crc32c, Md5, Sha1, Sha256]);
+        let digests = create_runtime()?.block_on(digester.digest_reader(&b"Hello, World!"[..]))?;
+        assert_digests(&digests);
+
+        let mut digester = MultiDigester::new(&[Crc32, Crc32c, Md5, Sha1, Sha256]);
+        digester.update(b"Hello, ");
+        digester.update(b"World!");
+        assert_digests(&digester.finish());
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_hash_on_blocking_pool() -> io::Result<()> {
+        let mut runtime = create_runtime()?;
+
+        let bytes = vec![b'a'; MultiDigester::BUFFER_SIZE * 2 + 1];
+        let digester = MultiDigester::new(&[Sha256]).on_blocking_pool();
+        let digests = runtime.block_on(digester.digest_reader(&bytes[..]))?;
+
+        let mut expected = MultiDigester::new(&[Sha256]);
+        expected.update(&bytes);
+        assert_eq!(digests, expected.finish());
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::actions::abort_multipart_upload;
+use crate::core;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use plus_aws::index::RegionCode;
+
+#[derive(Debug)]
+pub struct UploadRequest {
+    pub object_key: String,
+    pub upload_id: String,
+    pub region_code: Option<RegionCode>,
+}
+
+impl UploadRequest {
+    pub fn new<A: Into<String>, B: Into<String>>(object_key: A, upload_id: B) -> Self {
+        UploadRequest {
+            object_key: object_key.into(),
+            upload_id: upload_id.into(),
+            region_code: None,
+        }
+    }
+}
+
+impl HasObjectKey for UploadRequest {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl HasQueryParams for UploadRequest {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        vec![("uploadId", self.upload_id.clone())]
+    }
+}
+
+#[async_trait]
+impl ResourceLoader for UploadRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        Ok(RequestResource {
+            region: self.region_code.as_ref(),
+            ..RequestResource::empty()
+        })
+    }
+}
+
+impl abort_multipart_upload::Request for UploadRequest {}
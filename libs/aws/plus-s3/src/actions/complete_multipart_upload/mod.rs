@@ -0,0 +1,65 @@
+mod error;
+pub use error::Error;
+
+mod request;
+pub use request::CompletionRequest;
+
+mod response;
+pub use response::Response;
+
+use crate::actions;
+use crate::actions::complete_multipart_upload;
+use crate::client::S3Client;
+use crate::core::request::{ResourceLoader, WithOptions};
+use crate::core::response::S3ErrorBody;
+use crate::core::verbs::{HasObjectKey, HasQueryParams, IsPost};
+use crate::core::xml;
+use crate::internal;
+use crate::internal::impl_async::{read_text, RequestProvider, S3ErrorResponse};
+
+/// rf.
+/// [CompleteMultipartUpload - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_CompleteMultipartUpload.html)
+pub trait Request: HasObjectKey + HasQueryParams + ResourceLoader + Send + Sync {}
+
+impl<A: Request> IsPost<Response> for A {}
+
+impl<A: Request> Request for WithOptions<A> {}
+
+type Result<A> = std::result::Result<A, complete_multipart_upload::Error>;
+
+#[async_trait]
+pub trait Requester {
+    async fn complete_multipart_upload<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync;
+}
+
+#[async_trait]
+impl Requester for S3Client {
+    async fn complete_multipart_upload<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync,
+    {
+        let client = &self.internal_client;
+        let response: complete_multipart_upload::Result<Response> = async {
+            let provider = RequestProvider::new(&self, &request)?;
+            let response = client.request_by(provider).await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let text = read_text(response, self.config().read_timeout).await?;
+
+            // S3 may fail after it has returned 200 OK, since the assembling takes a while.
+            if S3ErrorBody::from_xml(&text).is_ok() {
+                let error = S3ErrorResponse::new(status, headers, text);
+                return Err(internal::Error::S3Error(Box::new(error)).into());
+            }
+            Ok(xml::from_str(&text)?)
+        }
+        .await;
+        Ok(response?)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::actions::complete_multipart_upload;
+use crate::core;
+use crate::core::multipart::CompleteMultipartUpload;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use crate::core::xml;
+use plus_aws::index::RegionCode;
+
+#[derive(Debug)]
+pub struct CompletionRequest {
+    pub object_key: String,
+    pub upload_id: String,
+    pub upload: CompleteMultipartUpload,
+    pub region_code: Option<RegionCode>,
+}
+
+impl CompletionRequest {
+    pub fn new<A, B>(object_key: A, upload_id: B, upload: CompleteMultipartUpload) -> Self
+    where
+        A: Into<String>,
+        B: Into<String>,
+    {
+        CompletionRequest {
+            object_key: object_key.into(),
+            upload_id: upload_id.into(),
+            upload,
+            region_code: None,
+        }
+    }
+}
+
+impl HasObjectKey for CompletionRequest {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl HasQueryParams for CompletionRequest {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        vec![("uploadId", self.upload_id.clone())]
+    }
+}
+
+#[async_trait]
+impl ResourceLoader for CompletionRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let document = xml::to_string(&self.upload)?;
+        Ok(RequestResource {
+            region: self.region_code.as_ref(),
+            ..RequestResource::xml(document)
+        })
+    }
+}
+
+impl complete_multipart_upload::Request for CompletionRequest {}
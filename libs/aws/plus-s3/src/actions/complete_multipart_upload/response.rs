@@ -0,0 +1,51 @@
+/// rf.
+/// [CompleteMultipartUpload - Response Syntax](https://docs.aws.amazon.com/AmazonS3/latest/API/API_CompleteMultipartUpload.html#API_CompleteMultipartUpload_ResponseSyntax)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    pub location: Option<String>,
+    pub bucket: String,
+    pub key: String,
+
+    #[serde(rename = "ETag")]
+    pub e_tag: String,
+
+    #[serde(rename = "ChecksumCRC32")]
+    pub checksum_crc32: Option<String>,
+
+    #[serde(rename = "ChecksumCRC32C")]
+    pub checksum_crc32c: Option<String>,
+
+    #[serde(rename = "ChecksumSHA1")]
+    pub checksum_sha1: Option<String>,
+
+    #[serde(rename = "ChecksumSHA256")]
+    pub checksum_sha256: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Response;
+    use crate::core;
+    use crate::core::xml;
+
+    #[test]
+    fn it_can_parse_location_and_etag() -> core::Result<()> {
+        let response: Response = xml::from_str(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <CompleteMultipartUploadResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <Location>https://example-bucket.s3.amazonaws.com/example-object</Location>
+              <Bucket>example-bucket</Bucket>
+              <Key>example-object</Key>
+              <ETag>"3858f62230ac3c915f300c664312c11f-9"</ETag>
+            </CompleteMultipartUploadResult>"#,
+        )?;
+        assert_eq!(
+            response.location.as_deref(),
+            Some("https://example-bucket.s3.amazonaws.com/example-object")
+        );
+        assert_eq!(response.e_tag, "\"3858f62230ac3c915f300c664312c11f-9\"");
+        assert_eq!(response.checksum_crc32, None);
+        Ok(())
+    }
+}
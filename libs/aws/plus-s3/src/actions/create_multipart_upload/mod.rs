@@ -0,0 +1,55 @@
+mod error;
+pub use error::Error;
+
+mod request;
+pub use request::ObjectRequest;
+
+mod response;
+pub use response::Response;
+
+use crate::actions;
+use crate::actions::create_multipart_upload;
+use crate::client::S3Client;
+use crate::core::request::{ResourceLoader, WithOptions};
+use crate::core::verbs::{HasObjectKey, HasQueryParams, IsPost};
+use crate::core::xml;
+use crate::internal::impl_async::{read_text, RequestProvider};
+
+/// rf.
+/// [CreateMultipartUpload - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_CreateMultipartUpload.html)
+pub trait Request: HasObjectKey + HasQueryParams + ResourceLoader + Send + Sync {}
+
+impl<A: Request> IsPost<Response> for A {}
+
+impl<A: Request> Request for WithOptions<A> {}
+
+type Result<A> = std::result::Result<A, create_multipart_upload::Error>;
+
+#[async_trait]
+pub trait Requester {
+    async fn create_multipart_upload<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync;
+}
+
+#[async_trait]
+impl Requester for S3Client {
+    async fn create_multipart_upload<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync,
+    {
+        let client = &self.internal_client;
+        let response: create_multipart_upload::Result<Response> = async {
+            let provider = RequestProvider::new(&self, &request)?;
+            let response = client.request_by(provider).await?;
+            let text = read_text(response, self.config().read_timeout).await?;
+            Ok(xml::from_str(&text)?)
+        }
+        .await;
+        Ok(response?)
+    }
+}
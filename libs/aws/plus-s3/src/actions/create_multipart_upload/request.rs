@@ -0,0 +1,61 @@
+use crate::actions::create_multipart_upload;
+use crate::core;
+use crate::core::checksum::ChecksumAlgorithm;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use plus_aws::http::request::header::ContentType;
+use plus_aws::http::request::ToHeaderFragment;
+use plus_aws::index::RegionCode;
+
+#[derive(Debug)]
+pub struct ObjectRequest {
+    pub object_key: String,
+    pub content_type: Option<ContentType>,
+    pub region_code: Option<RegionCode>,
+    /// Requires each part to be uploaded with the checksum of the algorithm.
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+}
+
+impl ObjectRequest {
+    pub fn new<A: Into<String>>(object_key: A) -> Self {
+        ObjectRequest {
+            object_key: object_key.into(),
+            content_type: None,
+            region_code: None,
+            checksum_algorithm: None,
+        }
+    }
+}
+
+impl HasObjectKey for ObjectRequest {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl HasQueryParams for ObjectRequest {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        vec![("uploads", "".to_string())]
+    }
+}
+
+#[async_trait]
+impl ResourceLoader for ObjectRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let mut headers = vec![];
+        if let Some(algorithm) = &self.checksum_algorithm {
+            headers.push(ToHeaderFragment::into((
+                "x-amz-checksum-algorithm",
+                algorithm.as_str(),
+            ))?);
+        }
+        Ok(RequestResource {
+            region: self.region_code.as_ref(),
+            content_type: self.content_type.as_ref(),
+            headers,
+            ..RequestResource::empty()
+        })
+    }
+}
+
+impl create_multipart_upload::Request for ObjectRequest {}
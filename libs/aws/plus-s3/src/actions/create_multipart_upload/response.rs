@@ -0,0 +1,32 @@
+/// rf.
+/// [CreateMultipartUpload - Response Syntax](https://docs.aws.amazon.com/AmazonS3/latest/API/API_CreateMultipartUpload.html#API_CreateMultipartUpload_ResponseSyntax)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    pub bucket: String,
+    pub key: String,
+    pub upload_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Response;
+    use crate::core;
+    use crate::core::xml;
+
+    #[test]
+    fn it_can_parse_upload_id() -> core::Result<()> {
+        let response: Response = xml::from_str(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <InitiateMultipartUploadResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <Bucket>example-bucket</Bucket>
+              <Key>example-object</Key>
+              <UploadId>VXBsb2FkIElE</UploadId>
+            </InitiateMultipartUploadResult>"#,
+        )?;
+        assert_eq!(response.bucket, "example-bucket");
+        assert_eq!(response.key, "example-object");
+        assert_eq!(response.upload_id, "VXBsb2FkIElE");
+        Ok(())
+    }
+}
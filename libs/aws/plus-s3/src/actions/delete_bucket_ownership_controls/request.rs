@@ -0,0 +1,35 @@
+use crate::actions::delete_bucket_ownership_controls;
+use crate::core;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+
+#[derive(Debug, Default)]
+pub struct BucketRequest {}
+
+impl BucketRequest {
+    pub fn new() -> Self {
+        BucketRequest {}
+    }
+}
+
+impl HasObjectKey for BucketRequest {
+    /// Bucket subresources are located at the root of the bucket.
+    fn get_object_key(&self) -> &str {
+        ""
+    }
+}
+
+impl HasQueryParams for BucketRequest {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        vec![("ownershipControls", "".to_string())]
+    }
+}
+
+#[async_trait]
+impl ResourceLoader for BucketRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        Ok(RequestResource::empty())
+    }
+}
+
+impl delete_bucket_ownership_controls::Request for BucketRequest {}
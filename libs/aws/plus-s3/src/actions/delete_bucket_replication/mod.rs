@@ -0,0 +1,53 @@
+mod error;
+pub use error::Error;
+
+mod request;
+pub use request::BucketRequest;
+
+mod response;
+pub use response::Response;
+
+use crate::actions;
+use crate::actions::delete_bucket_replication;
+use crate::client::S3Client;
+use crate::core::request::{ResourceLoader, WithOptions};
+use crate::core::verbs::{HasObjectKey, HasQueryParams, IsDelete};
+use crate::internal::impl_async::RequestProvider;
+
+/// rf.
+/// [DeleteBucketReplication - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_DeleteBucketReplication.html)
+pub trait Request: HasObjectKey + HasQueryParams + ResourceLoader + Send + Sync {}
+
+impl<A: Request> IsDelete<Response> for A {}
+
+impl<A: Request> Request for WithOptions<A> {}
+
+type Result<A> = std::result::Result<A, delete_bucket_replication::Error>;
+
+#[async_trait]
+pub trait Requester {
+    async fn delete_bucket_replication<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync;
+}
+
+#[async_trait]
+impl Requester for S3Client {
+    async fn delete_bucket_replication<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync,
+    {
+        let client = &self.internal_client;
+        let response: delete_bucket_replication::Result<Response> = async {
+            let provider = RequestProvider::new(&self, &request)?;
+            let _response = client.request_by(provider).await?;
+            Ok(Response {})
+        }
+        .await;
+        Ok(response?)
+    }
+}
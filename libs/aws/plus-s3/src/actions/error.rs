@@ -1,16 +1,204 @@
 extern crate failure;
 
 use crate::actions;
+use crate::internal::impl_async::S3ErrorResponse;
 
 pub type Result<T> = ::std::result::Result<T, Error>;
 
 #[derive(Fail, Debug)]
 pub enum Error {
+    #[fail(display = "actions::abort_multipart_upload::Error > {}", 0)]
+    AbortMultipartUploadError(actions::abort_multipart_upload::Error),
+
+    #[fail(display = "actions::complete_multipart_upload::Error > {}", 0)]
+    CompleteMultipartUploadError(actions::complete_multipart_upload::Error),
+
+    #[fail(display = "actions::create_multipart_upload::Error > {}", 0)]
+    CreateMultipartUploadError(actions::create_multipart_upload::Error),
+
+    #[fail(display = "actions::delete_bucket_ownership_controls::Error > {}", 0)]
+    DeleteBucketOwnershipControlsError(actions::delete_bucket_ownership_controls::Error),
+
+    #[fail(display = "actions::delete_bucket_replication::Error > {}", 0)]
+    DeleteBucketReplicationError(actions::delete_bucket_replication::Error),
+
+    #[fail(display = "actions::delete_public_access_block::Error > {}", 0)]
+    DeletePublicAccessBlockError(actions::delete_public_access_block::Error),
+
+    #[fail(
+        display = "actions::get_bucket_accelerate_configuration::Error > {}",
+        0
+    )]
+    GetBucketAccelerateConfigurationError(actions::get_bucket_accelerate_configuration::Error),
+
+    #[fail(display = "actions::get_bucket_ownership_controls::Error > {}", 0)]
+    GetBucketOwnershipControlsError(actions::get_bucket_ownership_controls::Error),
+
+    #[fail(display = "actions::get_bucket_replication::Error > {}", 0)]
+    GetBucketReplicationError(actions::get_bucket_replication::Error),
+
+    #[fail(display = "actions::get_bucket_request_payment::Error > {}", 0)]
+    GetBucketRequestPaymentError(actions::get_bucket_request_payment::Error),
+
     #[fail(display = "actions::get_object::Error > {}", 0)]
     GetObjectError(actions::get_object::Error),
 
+    #[fail(display = "actions::get_object_attributes::Error > {}", 0)]
+    GetObjectAttributesError(actions::get_object_attributes::Error),
+
+    #[fail(display = "actions::get_public_access_block::Error > {}", 0)]
+    GetPublicAccessBlockError(actions::get_public_access_block::Error),
+
+    #[fail(display = "actions::head_object::Error > {}", 0)]
+    HeadObjectError(actions::head_object::Error),
+
+    #[fail(
+        display = "actions::put_bucket_accelerate_configuration::Error > {}",
+        0
+    )]
+    PutBucketAccelerateConfigurationError(actions::put_bucket_accelerate_configuration::Error),
+
+    #[fail(display = "actions::put_bucket_ownership_controls::Error > {}", 0)]
+    PutBucketOwnershipControlsError(actions::put_bucket_ownership_controls::Error),
+
+    #[fail(display = "actions::put_bucket_replication::Error > {}", 0)]
+    PutBucketReplicationError(actions::put_bucket_replication::Error),
+
+    #[fail(display = "actions::put_bucket_request_payment::Error > {}", 0)]
+    PutBucketRequestPaymentError(actions::put_bucket_request_payment::Error),
+
     #[fail(display = "actions::put_object::Error > {}", 0)]
     PutObjectError(actions::put_object::Error),
+
+    #[fail(display = "actions::put_public_access_block::Error > {}", 0)]
+    PutPublicAccessBlockError(actions::put_public_access_block::Error),
+
+    #[fail(display = "actions::upload_part::Error > {}", 0)]
+    UploadPartError(actions::upload_part::Error),
+}
+
+impl Error {
+    /// Returns the response when S3 has rejected the request.
+    pub fn s3_error(&self) -> Option<&S3ErrorResponse> {
+        match self {
+            Error::AbortMultipartUploadError(
+                actions::abort_multipart_upload::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::CompleteMultipartUploadError(
+                actions::complete_multipart_upload::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::CreateMultipartUploadError(
+                actions::create_multipart_upload::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::DeleteBucketOwnershipControlsError(
+                actions::delete_bucket_ownership_controls::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::DeleteBucketReplicationError(
+                actions::delete_bucket_replication::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::DeletePublicAccessBlockError(
+                actions::delete_public_access_block::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::GetBucketAccelerateConfigurationError(
+                actions::get_bucket_accelerate_configuration::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::GetBucketOwnershipControlsError(
+                actions::get_bucket_ownership_controls::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::GetBucketReplicationError(
+                actions::get_bucket_replication::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::GetBucketRequestPaymentError(
+                actions::get_bucket_request_payment::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::GetObjectError(actions::get_object::Error::InternalError(e)) => e.s3_error(),
+            Error::GetObjectAttributesError(
+                actions::get_object_attributes::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::GetPublicAccessBlockError(
+                actions::get_public_access_block::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::HeadObjectError(actions::head_object::Error::InternalError(e)) => e.s3_error(),
+            Error::PutBucketAccelerateConfigurationError(
+                actions::put_bucket_accelerate_configuration::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::PutBucketOwnershipControlsError(
+                actions::put_bucket_ownership_controls::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::PutBucketReplicationError(
+                actions::put_bucket_replication::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::PutBucketRequestPaymentError(
+                actions::put_bucket_request_payment::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::PutObjectError(actions::put_object::Error::InternalError(e)) => e.s3_error(),
+            Error::PutPublicAccessBlockError(
+                actions::put_public_access_block::Error::InternalError(e),
+            ) => e.s3_error(),
+            Error::UploadPartError(actions::upload_part::Error::InternalError(e)) => e.s3_error(),
+            _ => None,
+        }
+    }
+}
+
+impl From<actions::abort_multipart_upload::Error> for Error {
+    fn from(e: actions::abort_multipart_upload::Error) -> Self {
+        Error::AbortMultipartUploadError(e)
+    }
+}
+
+impl From<actions::complete_multipart_upload::Error> for Error {
+    fn from(e: actions::complete_multipart_upload::Error) -> Self {
+        Error::CompleteMultipartUploadError(e)
+    }
+}
+
+impl From<actions::create_multipart_upload::Error> for Error {
+    fn from(e: actions::create_multipart_upload::Error) -> Self {
+        Error::CreateMultipartUploadError(e)
+    }
+}
+
+impl From<actions::delete_bucket_ownership_controls::Error> for Error {
+    fn from(e: actions::delete_bucket_ownership_controls::Error) -> Self {
+        Error::DeleteBucketOwnershipControlsError(e)
+    }
+}
+
+impl From<actions::delete_bucket_replication::Error> for Error {
+    fn from(e: actions::delete_bucket_replication::Error) -> Self {
+        Error::DeleteBucketReplicationError(e)
+    }
+}
+
+impl From<actions::delete_public_access_block::Error> for Error {
+    fn from(e: actions::delete_public_access_block::Error) -> Self {
+        Error::DeletePublicAccessBlockError(e)
+    }
+}
+
+impl From<actions::get_bucket_accelerate_configuration::Error> for Error {
+    fn from(e: actions::get_bucket_accelerate_configuration::Error) -> Self {
+        Error::GetBucketAccelerateConfigurationError(e)
+    }
+}
+
+impl From<actions::get_bucket_ownership_controls::Error> for Error {
+    fn from(e: actions::get_bucket_ownership_controls::Error) -> Self {
+        Error::GetBucketOwnershipControlsError(e)
+    }
+}
+
+impl From<actions::get_bucket_replication::Error> for Error {
+    fn from(e: actions::get_bucket_replication::Error) -> Self {
+        Error::GetBucketReplicationError(e)
+    }
+}
+
+impl From<actions::get_bucket_request_payment::Error> for Error {
+    fn from(e: actions::get_bucket_request_payment::Error) -> Self {
+        Error::GetBucketRequestPaymentError(e)
+    }
 }
 
 impl From<actions::get_object::Error> for Error {
@@ -19,8 +207,62 @@ impl From<actions::get_object::Error> for Error {
     }
 }
 
+impl From<actions::get_object_attributes::Error> for Error {
+    fn from(e: actions::get_object_attributes::Error) -> Self {
+        Error::GetObjectAttributesError(e)
+    }
+}
+
+impl From<actions::get_public_access_block::Error> for Error {
+    fn from(e: actions::get_public_access_block::Error) -> Self {
+        Error::GetPublicAccessBlockError(e)
+    }
+}
+
+impl From<actions::head_object::Error> for Error {
+    fn from(e: actions::head_object::Error) -> Self {
+        Error::HeadObjectError(e)
+    }
+}
+
+impl From<actions::put_bucket_accelerate_configuration::Error> for Error {
+    fn from(e: actions::put_bucket_accelerate_configuration::Error) -> Self {
+        Error::PutBucketAccelerateConfigurationError(e)
+    }
+}
+
+impl From<actions::put_bucket_ownership_controls::Error> for Error {
+    fn from(e: actions::put_bucket_ownership_controls::Error) -> Self {
+        Error::PutBucketOwnershipControlsError(e)
+    }
+}
+
+impl From<actions::put_bucket_replication::Error> for Error {
+    fn from(e: actions::put_bucket_replication::Error) -> Self {
+        Error::PutBucketReplicationError(e)
+    }
+}
+
+impl From<actions::put_bucket_request_payment::Error> for Error {
+    fn from(e: actions::put_bucket_request_payment::Error) -> Self {
+        Error::PutBucketRequestPaymentError(e)
+    }
+}
+
 impl From<actions::put_object::Error> for Error {
     fn from(e: actions::put_object::Error) -> Self {
         Error::PutObjectError(e)
     }
 }
+
+impl From<actions::put_public_access_block::Error> for Error {
+    fn from(e: actions::put_public_access_block::Error) -> Self {
+        Error::PutPublicAccessBlockError(e)
+    }
+}
+
+impl From<actions::upload_part::Error> for Error {
+    fn from(e: actions::upload_part::Error) -> Self {
+        Error::UploadPartError(e)
+    }
+}
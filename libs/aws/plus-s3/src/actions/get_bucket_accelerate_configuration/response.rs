@@ -0,0 +1,6 @@
+use crate::core::configuration::accelerate::AccelerateConfiguration;
+
+#[derive(Debug)]
+pub struct Response {
+    pub configuration: AccelerateConfiguration,
+}
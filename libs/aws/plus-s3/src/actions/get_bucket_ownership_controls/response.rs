@@ -0,0 +1,6 @@
+use crate::core::configuration::ownership_controls::OwnershipControls;
+
+#[derive(Debug)]
+pub struct Response {
+    pub configuration: OwnershipControls,
+}
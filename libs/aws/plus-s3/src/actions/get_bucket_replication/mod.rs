@@ -0,0 +1,57 @@
+mod error;
+pub use error::Error;
+
+mod request;
+pub use request::BucketRequest;
+
+mod response;
+pub use response::Response;
+
+use crate::actions;
+use crate::actions::get_bucket_replication;
+use crate::client::S3Client;
+use crate::core::request::{ResourceLoader, WithOptions};
+use crate::core::verbs::{HasObjectKey, HasQueryParams, IsGet};
+use crate::core::xml;
+use crate::internal::impl_async::{read_text, RequestProvider};
+
+/// rf.
+/// [GetBucketReplication - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetBucketReplication.html)
+pub trait Request: HasObjectKey + HasQueryParams + ResourceLoader + Send + Sync {}
+
+impl<A: Request> IsGet<Response> for A {}
+
+impl<A: Request> Request for WithOptions<A> {}
+
+type Result<A> = std::result::Result<A, get_bucket_replication::Error>;
+
+#[async_trait]
+pub trait Requester {
+    async fn get_bucket_replication<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync;
+}
+
+#[async_trait]
+impl Requester for S3Client {
+    async fn get_bucket_replication<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync,
+    {
+        let client = &self.internal_client;
+        let response: get_bucket_replication::Result<Response> = async {
+            let provider = RequestProvider::new(&self, &request)?;
+            let response = client.request_by(provider).await?;
+            let text = read_text(response, self.config().read_timeout).await?;
+            Ok(Response {
+                configuration: xml::from_str(&text)?,
+            })
+        }
+        .await;
+        Ok(response?)
+    }
+}
@@ -0,0 +1,6 @@
+use crate::core::configuration::replication::ReplicationConfiguration;
+
+#[derive(Debug)]
+pub struct Response {
+    pub configuration: ReplicationConfiguration,
+}
@@ -0,0 +1,6 @@
+use crate::core::configuration::request_payment::RequestPaymentConfiguration;
+
+#[derive(Debug)]
+pub struct Response {
+    pub configuration: RequestPaymentConfiguration,
+}
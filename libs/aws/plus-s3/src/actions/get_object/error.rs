@@ -2,12 +2,28 @@ use crate::internal;
 
 #[derive(Fail, Debug)]
 pub enum Error {
+    /// The cause has been yielded by the `BodyStream`.
+    #[fail(display = "BodyStreamAborted")]
+    BodyStreamAborted,
+
+    #[fail(display = "BodyStreamClosed")]
+    BodyStreamClosed,
+
+    #[fail(display = "BodyTooLarge > limit: {}", limit)]
+    BodyTooLarge { limit: usize },
+
     #[fail(display = "internal::Error > {}", 0)]
     InternalError(internal::Error),
 
     #[fail(display = "file::OutfileError > {}", 0)]
     OutFileError(super::request::OutfileError),
 
+    #[fail(
+        display = "RangeLengthMismatch > expected: {}, actual: {}",
+        expected, actual
+    )]
+    RangeLengthMismatch { expected: u64, actual: u64 },
+
     #[fail(display = "reqwest::Error > {}", 0)]
     ReqwestError(reqwest::Error),
 
@@ -2,7 +2,9 @@ mod error;
 pub use error::Error;
 
 mod request;
-pub use request::FileRequest;
+pub use request::{
+    BodyStream, BytesRequest, FileRequest, RangeRequest, StreamRequest, WriterRequest,
+};
 pub use request::{Outfile, OutfileError};
 
 mod response;
@@ -11,21 +13,36 @@ pub use response::{Response, ResponseHeaders};
 use crate::actions;
 use crate::actions::get_object;
 use crate::client::S3Client;
-use crate::core::request::ResourceLoader;
-use crate::core::verbs::{HasObjectKey, IsGet};
-use crate::internal::impl_async::{InternalClient, RequestProvider};
+use crate::core::checksum;
+use crate::core::request::{ResourceLoader, WithOptions};
+use crate::core::verbs::{HasObjectKey, HasQueryParams, IsGet};
+use crate::internal::impl_async::{with_idle_timeout, RequestProvider};
 use futures_util::TryStreamExt;
 use plus_aws::io::stream::BodyReceiver;
 
 /// rf.
 /// [GetObject - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObject.html)
 pub trait Request:
-    HasObjectKey + ResourceLoader + BodyReceiver<Err = get_object::Error> + Send + Sync
+    HasObjectKey + HasQueryParams + ResourceLoader + BodyReceiver<Err = get_object::Error> + Send + Sync
 {
+    /// Called when the request has finished, even if it has failed before receiving the body.
+    fn finish_body(&mut self) {}
 }
 
 impl<A: Request> IsGet<Response> for A {}
 
+impl<A: Request> Request for WithOptions<A> {
+    fn finish_body(&mut self) {
+        self.request.finish_body()
+    }
+}
+
+impl<A: Request> Request for &mut A {
+    fn finish_body(&mut self) {
+        (**self).finish_body()
+    }
+}
+
 type Result<A> = std::result::Result<A, get_object::Error>;
 
 #[async_trait]
@@ -43,17 +60,21 @@ impl Requester for S3Client {
         A: Request,
         A: Send,
     {
-        let client = InternalClient::default();
+        let client = &self.internal_client;
         let headers: get_object::Result<ResponseHeaders> = async {
             let provider = RequestProvider::new(&self, &request)?;
             let response = client.request_by(provider).await?;
             let headers = ResponseHeaders::from(response.headers())?;
             let stream = response.bytes_stream().map_err(get_object::Error::from);
+            let stream = with_idle_timeout(stream, self.config().read_timeout);
+
+            let stream = checksum::verify(stream, headers.verifiers());
 
             request.receive_body_from(stream).await?;
             Ok(headers)
         }
         .await;
+        request.finish_body();
         Ok(Response { headers: headers? })
     }
 }
@@ -0,0 +1,121 @@
+use crate::actions::get_object;
+use crate::actions::get_object::request::to_resource;
+use crate::core;
+use crate::core::checksum::ChecksumMode;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use bytes::{Bytes, BytesMut};
+use futures_util::stream::Stream;
+use futures_util::TryStreamExt;
+use plus_aws::io::stream::BodyReceiver;
+
+/// Collects the body into memory, failing if it exceeds the limit.
+///
+/// Pass it by `&mut` to take the bytes after the request has finished.
+#[derive(Debug)]
+pub struct BytesRequest {
+    object_key: String,
+    limit: usize,
+    checksum_mode: Option<ChecksumMode>,
+    bytes: Bytes,
+}
+
+impl BytesRequest {
+    pub fn new<A: Into<String>>(object_key: A, limit: usize) -> Self {
+        BytesRequest {
+            object_key: object_key.into(),
+            limit,
+            checksum_mode: None,
+            bytes: Bytes::new(),
+        }
+    }
+
+    /// Requests the checksum of the object to verify the received body.
+    pub fn with_checksum_mode(self, checksum_mode: ChecksumMode) -> Self {
+        BytesRequest {
+            checksum_mode: Some(checksum_mode),
+            ..self
+        }
+    }
+
+    pub fn bytes(&self) -> &Bytes {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl HasObjectKey for BytesRequest {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl HasQueryParams for BytesRequest {}
+
+#[async_trait]
+impl ResourceLoader for BytesRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        to_resource(self.checksum_mode.as_ref())
+    }
+}
+
+#[async_trait]
+impl BodyReceiver for BytesRequest {
+    type Err = get_object::Error;
+
+    async fn receive_body_from<S>(&mut self, body: S) -> Result<usize, Self::Err>
+    where
+        S: Stream<Item = Result<Bytes, Self::Err>>,
+        S: Send,
+    {
+        let limit = self.limit;
+        let buffer = body
+            .try_fold(BytesMut::new(), |mut buffer, item: Bytes| async move {
+                if buffer.len() + item.len() > limit {
+                    return Err(get_object::Error::BodyTooLarge { limit });
+                }
+                buffer.extend_from_slice(&item);
+                Ok(buffer)
+            })
+            .await?;
+
+        self.bytes = buffer.freeze();
+        Ok(self.bytes.len())
+    }
+}
+
+impl get_object::Request for BytesRequest {}
+
+#[cfg(test)]
+mod tests {
+    use super::BytesRequest;
+    use crate::actions::get_object;
+    use bytes::Bytes;
+    use futures::executor::block_on;
+    use futures_util::stream::iter;
+    use plus_aws::io::stream::BodyReceiver;
+
+    fn chunks() -> Vec<Result<Bytes, get_object::Error>> {
+        vec![Ok(Bytes::from("Hello, ")), Ok(Bytes::from("World!"))]
+    }
+
+    #[test]
+    fn it_collects_body() {
+        let mut request = BytesRequest::new("sample.txt", 13);
+        let size = block_on(request.receive_body_from(iter(chunks()))).unwrap();
+        assert_eq!(size, 13);
+        assert_eq!(request.into_bytes(), "Hello, World!");
+    }
+
+    #[test]
+    fn it_fails_if_body_exceeds_limit() {
+        let mut request = BytesRequest::new("sample.txt", 12);
+        match block_on(request.receive_body_from(iter(chunks()))) {
+            Err(get_object::Error::BodyTooLarge { limit }) => assert_eq!(limit, 12),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
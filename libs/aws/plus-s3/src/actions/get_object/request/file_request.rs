@@ -1,13 +1,13 @@
 use crate::actions;
 use crate::actions::get_object;
+use crate::actions::get_object::request::to_resource;
 use crate::actions::get_object::Outfile;
 use crate::core;
+use crate::core::checksum::ChecksumMode;
 use crate::core::request::{RequestResource, ResourceLoader};
-use crate::core::verbs::HasObjectKey;
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
 use bytes::Bytes;
 use futures_util::stream::Stream;
-use plus_aws::auth::v4::canonical::HashedPayload;
-use plus_aws::auth::v4::chrono::now;
 use plus_aws::io::stream::BodyReceiver;
 use std::path::PathBuf;
 
@@ -15,6 +15,7 @@ use std::path::PathBuf;
 pub struct FileRequest {
     object_key: String,
     outfile: Outfile,
+    checksum_mode: Option<ChecksumMode>,
 }
 
 impl FileRequest {
@@ -22,8 +23,17 @@ impl FileRequest {
         Ok(FileRequest {
             object_key,
             outfile: Outfile::create(file_path).map_err(get_object::Error::from)?,
+            checksum_mode: None,
         })
     }
+
+    /// Requests the checksum of the object to verify the received body.
+    pub fn with_checksum_mode(self, checksum_mode: ChecksumMode) -> Self {
+        FileRequest {
+            checksum_mode: Some(checksum_mode),
+            ..self
+        }
+    }
 }
 
 impl HasObjectKey for FileRequest {
@@ -32,18 +42,12 @@ impl HasObjectKey for FileRequest {
     }
 }
 
+impl HasQueryParams for FileRequest {}
+
 #[async_trait]
 impl ResourceLoader for FileRequest {
     async fn load(&self) -> core::Result<RequestResource<'_>> {
-        let resource = RequestResource {
-            body: None,
-            hash: HashedPayload::empty(),
-            region: None,
-            content_type: None,
-            content_length: 0,
-            requested_at: now(),
-        };
-        Ok(resource)
+        to_resource(self.checksum_mode.as_ref())
     }
 }
 
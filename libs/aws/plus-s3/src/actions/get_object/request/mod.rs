@@ -1,6 +1,34 @@
+mod bytes_request;
+pub use bytes_request::BytesRequest;
+
 mod file_request;
 pub use file_request::FileRequest;
 
 mod outfile;
 pub use outfile::Error as OutfileError;
 pub use outfile::Outfile;
+
+mod range_request;
+pub use range_request::RangeRequest;
+
+mod stream_request;
+pub use stream_request::{BodyStream, StreamRequest};
+
+mod writer_request;
+pub use writer_request::WriterRequest;
+
+use crate::core;
+use crate::core::checksum::ChecksumMode;
+use crate::core::request::RequestResource;
+use plus_aws::http::request::ToHeaderFragment;
+
+fn to_resource<'a>(checksum_mode: Option<&ChecksumMode>) -> core::Result<RequestResource<'a>> {
+    let headers = match checksum_mode {
+        Some(mode) => vec![ToHeaderFragment::into(mode)?],
+        None => vec![],
+    };
+    Ok(RequestResource {
+        headers,
+        ..RequestResource::empty()
+    })
+}
@@ -22,6 +22,9 @@ impl Outfile {
         &self.0.parent().expect("must have parent")
     }
 
+    /// Writes the stream to a temporary file, and moves it to the path only if
+    /// all the items are received successfully, so that the existing file is left untouched
+    /// when the stream fails on its way (e.g. by checksum mismatch).
     pub async fn write<S, E>(&self, stream: S) -> Result<usize, E>
     where
         S: Stream<Item = Result<Bytes, E>>,
@@ -0,0 +1,150 @@
+use crate::actions::get_object;
+use crate::core;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use bytes::Bytes;
+use futures_util::stream::{Stream, StreamExt};
+use plus_aws::http::request::ToHeaderFragment;
+use plus_aws::index::RegionCode;
+use plus_aws::io::stream::BodyReceiver;
+use std::io::SeekFrom;
+use std::path::PathBuf;
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+
+/// Writes the range of the object at the same offset of the existing file,
+/// which is expected to be preallocated and shared by the other ranges.
+#[derive(Debug)]
+pub struct RangeRequest {
+    object_key: String,
+    file_path: PathBuf,
+    offset: u64,
+    length: u64,
+    if_match: Option<String>,
+    region_code: Option<RegionCode>,
+}
+
+impl RangeRequest {
+    pub fn new<A: Into<String>>(
+        object_key: A,
+        file_path: PathBuf,
+        offset: u64,
+        length: u64,
+    ) -> Self {
+        RangeRequest {
+            object_key: object_key.into(),
+            file_path,
+            offset,
+            length,
+            if_match: None,
+            region_code: None,
+        }
+    }
+
+    /// Fails with `412 Precondition Failed` if the object has been replaced.
+    pub fn with_if_match<A: Into<String>>(self, e_tag: A) -> Self {
+        RangeRequest {
+            if_match: Some(e_tag.into()),
+            ..self
+        }
+    }
+
+    pub fn with_region(self, region_code: RegionCode) -> Self {
+        RangeRequest {
+            region_code: Some(region_code),
+            ..self
+        }
+    }
+
+    fn to_range(&self) -> String {
+        let last = (self.offset + self.length).saturating_sub(1);
+        format!("bytes={}-{}", self.offset, last)
+    }
+}
+
+impl HasObjectKey for RangeRequest {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl HasQueryParams for RangeRequest {}
+
+#[async_trait]
+impl ResourceLoader for RangeRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let mut headers = vec![ToHeaderFragment::into(("range", self.to_range().as_str()))?];
+        if let Some(e_tag) = &self.if_match {
+            headers.push(ToHeaderFragment::into(("if-match", e_tag.as_str()))?);
+        }
+        Ok(RequestResource {
+            headers,
+            region: self.region_code.as_ref(),
+            ..RequestResource::empty()
+        })
+    }
+}
+
+#[async_trait]
+impl BodyReceiver for RangeRequest {
+    type Err = get_object::Error;
+
+    async fn receive_body_from<S>(&mut self, body: S) -> Result<usize, Self::Err>
+    where
+        S: Stream<Item = Result<Bytes, Self::Err>>,
+        S: Send,
+    {
+        let mut file = OpenOptions::new().write(true).open(&self.file_path).await?;
+        file.seek(SeekFrom::Start(self.offset)).await?;
+
+        let mut body = Box::pin(body);
+        let mut sum = 0;
+        while let Some(item) = body.next().await {
+            let item = item?;
+            file.write_all(&item).await?;
+            sum += item.len();
+        }
+        file.flush().await?;
+
+        if sum as u64 != self.length {
+            return Err(get_object::Error::RangeLengthMismatch {
+                expected: self.length,
+                actual: sum as u64,
+            });
+        }
+        Ok(sum)
+    }
+}
+
+impl get_object::Request for RangeRequest {}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeRequest;
+    use crate::core;
+    use crate::core::request::ResourceLoader;
+    use futures::executor::block_on;
+    use std::path::PathBuf;
+
+    #[test]
+    fn it_requests_range_pinned_to_etag() -> core::Result<()> {
+        let request = RangeRequest::new("sample.txt", PathBuf::from("sample.txt"), 8, 4)
+            .with_if_match("\"65a8e27d8879283831b664bd8b7f0ad4\"");
+
+        let resource = block_on(request.load())?;
+        let headers: Vec<(&str, &str)> = resource
+            .headers
+            .iter()
+            .map(|header| (header.key.as_str(), header.value.to_str().unwrap()))
+            .collect();
+
+        assert_eq!(
+            headers,
+            vec![
+                ("range", "bytes=8-11"),
+                ("if-match", "\"65a8e27d8879283831b664bd8b7f0ad4\""),
+            ]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use crate::actions::get_object;
+use crate::actions::get_object::request::to_resource;
+use crate::core;
+use crate::core::checksum::ChecksumMode;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use bytes::Bytes;
+use futures_channel::mpsc;
+use futures_util::stream::{Stream, StreamExt};
+use futures_util::SinkExt;
+use plus_aws::io::stream::BodyReceiver;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type Item = Result<Bytes, get_object::Error>;
+
+/// Hands the raw chunks of the body to the paired `BodyStream`.
+///
+/// The `BodyStream` has to be consumed concurrently with the request,
+/// since the request waits until the buffered chunks are taken.
+///
+/// The `BodyStream` ends when the request has finished, even if it is passed by `&mut`.
+/// If the request fails before any body arrives, like 403 or 404,
+/// the `BodyStream` ends cleanly without any item, exactly like an empty object,
+/// so the result of the request has to be checked to tell them apart.
+#[derive(Debug)]
+pub struct StreamRequest {
+    object_key: String,
+    checksum_mode: Option<ChecksumMode>,
+    sender: mpsc::Sender<Item>,
+}
+
+impl StreamRequest {
+    /// `buffer` is the number of chunks which can be held without being taken.
+    pub fn new<A: Into<String>>(object_key: A, buffer: usize) -> (Self, BodyStream) {
+        let (sender, receiver) = mpsc::channel(buffer);
+        let request = StreamRequest {
+            object_key: object_key.into(),
+            checksum_mode: None,
+            sender,
+        };
+        (request, BodyStream(receiver))
+    }
+
+    /// Requests the checksum of the object to verify the received body.
+    pub fn with_checksum_mode(self, checksum_mode: ChecksumMode) -> Self {
+        StreamRequest {
+            checksum_mode: Some(checksum_mode),
+            ..self
+        }
+    }
+}
+
+impl HasObjectKey for StreamRequest {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl HasQueryParams for StreamRequest {}
+
+#[async_trait]
+impl ResourceLoader for StreamRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        to_resource(self.checksum_mode.as_ref())
+    }
+}
+
+#[async_trait]
+impl BodyReceiver for StreamRequest {
+    type Err = get_object::Error;
+
+    async fn receive_body_from<S>(&mut self, body: S) -> Result<usize, Self::Err>
+    where
+        S: Stream<Item = Result<Bytes, Self::Err>>,
+        S: Send,
+    {
+        let sender = &mut self.sender;
+        let result = async {
+            let mut body = Box::pin(body);
+            let mut sum = 0;
+            while let Some(item) = body.next().await {
+                let failed = item.is_err();
+                sum += item.as_ref().map_or(0, Bytes::len);
+                sender
+                    .send(item)
+                    .await
+                    .map_err(|_| get_object::Error::BodyStreamClosed)?;
+
+                if failed {
+                    return Err(get_object::Error::BodyStreamAborted);
+                }
+            }
+            Ok(sum)
+        }
+        .await;
+        self.sender.close_channel();
+        result
+    }
+}
+
+impl get_object::Request for StreamRequest {
+    fn finish_body(&mut self) {
+        self.sender.close_channel();
+    }
+}
+
+/// Stream of the body chunks received by `StreamRequest`.
+///
+/// Errors occurred while receiving, including integrity errors, are yielded as items.
+#[derive(Debug)]
+pub struct BodyStream(mpsc::Receiver<Item>);
+
+impl Stream for BodyStream {
+    type Item = Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.0).poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamRequest;
+    use crate::actions::get_object;
+    use crate::client::test_server::{create_client_at, serve};
+    use bytes::Bytes;
+    use futures::executor::block_on;
+    use futures::future::join;
+    use futures_util::stream::{iter, StreamExt, TryStreamExt};
+    use plus_aws::index::RegionCode;
+    use plus_aws::io::stream::BodyReceiver;
+    use tokio::runtime::Builder;
+
+    #[test]
+    fn it_hands_chunks_to_body_stream() {
+        let chunks: Vec<Result<Bytes, get_object::Error>> =
+            vec![Ok(Bytes::from("Hello, ")), Ok(Bytes::from("World!"))];
+
+        let (mut request, stream) = StreamRequest::new("sample.txt", 1);
+        let (sent, received) = block_on(join(
+            request.receive_body_from(iter(chunks)),
+            stream.try_collect::<Vec<Bytes>>(),
+        ));
+        assert_eq!(sent.unwrap(), 13);
+        assert_eq!(received.unwrap().concat(), b"Hello, World!");
+    }
+
+    #[test]
+    fn it_ends_body_stream_on_failure() {
+        let chunks: Vec<Result<Bytes, get_object::Error>> = vec![
+            Ok(Bytes::from("Hello, ")),
+            Err(get_object::Error::BodyStreamClosed),
+        ];
+        let (mut request, stream) = StreamRequest::new("sample.txt", 1);
+        let (sent, received) = block_on(join(
+            request.receive_body_from(iter(chunks)),
+            stream.collect::<Vec<_>>(),
+        ));
+        assert!(sent.is_err());
+        assert_eq!(received.len(), 2);
+    }
+
+    #[test]
+    fn it_ends_body_stream_of_failed_request() {
+        let (url, _) = serve("404 Not Found", NO_SUCH_KEY);
+        let client = create_client_at(url, Some(RegionCode::any("us-east-1")));
+        let (mut request, stream) = StreamRequest::new("sample.txt", 1);
+
+        let mut runtime = Builder::new()
+            .basic_scheduler()
+            .enable_all()
+            .build()
+            .unwrap();
+        let (result, received) = runtime.block_on(join(
+            client.get_object(&mut request),
+            stream.collect::<Vec<_>>(),
+        ));
+        assert!(result.is_err());
+        assert!(received.is_empty());
+    }
+
+    const NO_SUCH_KEY: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+        <Error><Code>NoSuchKey</Code><Message>The specified key does not exist.</Message></Error>";
+}
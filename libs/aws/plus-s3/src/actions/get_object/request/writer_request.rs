@@ -0,0 +1,119 @@
+use crate::actions::get_object;
+use crate::actions::get_object::request::to_resource;
+use crate::core;
+use crate::core::checksum::ChecksumMode;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use bytes::Bytes;
+use futures_util::stream::{Stream, StreamExt};
+use plus_aws::io::stream::BodyReceiver;
+use std::fmt;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Forwards each chunk of the body to the writer as soon as it arrives.
+///
+/// Note that the chunks already written cannot be taken back
+/// even if the request fails on its way, e.g. by checksum mismatch.
+pub struct WriterRequest<W> {
+    object_key: String,
+    checksum_mode: Option<ChecksumMode>,
+    writer: W,
+}
+
+impl<W> WriterRequest<W>
+where
+    W: AsyncWrite + Unpin + Send + Sync,
+{
+    pub fn new<A: Into<String>>(object_key: A, writer: W) -> Self {
+        WriterRequest {
+            object_key: object_key.into(),
+            checksum_mode: None,
+            writer,
+        }
+    }
+
+    /// Requests the checksum of the object to verify the received body.
+    pub fn with_checksum_mode(self, checksum_mode: ChecksumMode) -> Self {
+        WriterRequest {
+            checksum_mode: Some(checksum_mode),
+            ..self
+        }
+    }
+
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+}
+
+impl<W> fmt::Debug for WriterRequest<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WriterRequest")
+            .field("object_key", &self.object_key)
+            .field("checksum_mode", &self.checksum_mode)
+            .finish()
+    }
+}
+
+impl<W> HasObjectKey for WriterRequest<W> {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl<W> HasQueryParams for WriterRequest<W> {}
+
+#[async_trait]
+impl<W> ResourceLoader for WriterRequest<W>
+where
+    W: Sync,
+{
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        to_resource(self.checksum_mode.as_ref())
+    }
+}
+
+#[async_trait]
+impl<W> BodyReceiver for WriterRequest<W>
+where
+    W: AsyncWrite + Unpin + Send,
+{
+    type Err = get_object::Error;
+
+    async fn receive_body_from<S>(&mut self, body: S) -> Result<usize, Self::Err>
+    where
+        S: Stream<Item = Result<Bytes, Self::Err>>,
+        S: Send,
+    {
+        let mut body = Box::pin(body);
+        let mut sum = 0;
+        while let Some(item) = body.next().await {
+            let item = item?;
+            self.writer.write_all(&item).await?;
+            sum += item.len();
+        }
+        self.writer.flush().await?;
+        Ok(sum)
+    }
+}
+
+impl<W> get_object::Request for WriterRequest<W> where W: AsyncWrite + Unpin + Send + Sync {}
+
+#[cfg(test)]
+mod tests {
+    use super::WriterRequest;
+    use crate::actions::get_object;
+    use bytes::Bytes;
+    use futures::executor::block_on;
+    use futures_util::stream::iter;
+    use plus_aws::io::stream::BodyReceiver;
+
+    #[test]
+    fn it_forwards_body_to_writer() {
+        let chunks: Vec<Result<Bytes, get_object::Error>> =
+            vec![Ok(Bytes::from("Hello, ")), Ok(Bytes::from("World!"))];
+
+        let mut request = WriterRequest::new("sample.txt", vec![]);
+        block_on(request.receive_body_from(iter(chunks))).unwrap();
+        assert_eq!(request.into_writer(), b"Hello, World!");
+    }
+}
@@ -1,5 +1,14 @@
 use crate::core;
-use crate::core::response::headers::{AwsHeaderMap, ETag};
+use crate::core::checksum::{Checksum, ChecksumVerifier, Md5Verifier, Verifier};
+use crate::core::response::headers::{
+    AcceptRanges, AwsHeaderMap, BucketKeyEnabled, CacheControl, ContentDisposition,
+    ContentEncoding, ContentLanguage, ContentLength, ContentRange, ContentType, ETag, Expiration,
+    Expires, LastModified, Metadata, MissingMeta, ObjectLockLegalHoldStatus, ObjectLockMode,
+    ObjectLockRetainUntilDate, PartsCount, ReplicationStatus, RequestCharged, Restore,
+    ServerSideEncryption, SseCustomerAlgorithm, SseCustomerKeyMd5, SseKmsKeyId, TagCount,
+    VersionId, WebsiteRedirectLocation,
+};
+use crate::core::StorageClass;
 use reqwest::header::HeaderMap;
 
 #[derive(Debug)]
@@ -9,13 +18,193 @@ pub struct Response {
 
 #[derive(Debug)]
 pub struct ResponseHeaders {
+    pub accept_ranges: Option<AcceptRanges>,
+    pub expiration: Option<Expiration>,
+    pub restore: Option<Restore>,
+    pub last_modified: Option<LastModified>,
+    pub content_length: Option<ContentLength>,
     pub e_tag: ETag,
+    pub checksum: Option<Checksum>,
+    pub missing_meta: Option<MissingMeta>,
+    pub version_id: Option<VersionId>,
+    pub cache_control: Option<CacheControl>,
+    pub content_disposition: Option<ContentDisposition>,
+    pub content_encoding: Option<ContentEncoding>,
+    pub content_language: Option<ContentLanguage>,
+    pub content_range: Option<ContentRange>,
+    pub content_type: Option<ContentType>,
+    pub expires: Option<Expires>,
+    pub website_redirect_location: Option<WebsiteRedirectLocation>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
+    pub metadata: Metadata,
+    pub sse_customer_algorithm: Option<SseCustomerAlgorithm>,
+    pub sse_customer_key_md5: Option<SseCustomerKeyMd5>,
+    pub sse_kms_key_id: Option<SseKmsKeyId>,
+    pub bucket_key_enabled: Option<BucketKeyEnabled>,
+    pub storage_class: Option<StorageClass>,
+    pub request_charged: Option<RequestCharged>,
+    pub replication_status: Option<ReplicationStatus>,
+    pub parts_count: Option<PartsCount>,
+    pub tag_count: Option<TagCount>,
+    pub object_lock_mode: Option<ObjectLockMode>,
+    pub object_lock_retain_until_date: Option<ObjectLockRetainUntilDate>,
+    pub object_lock_legal_hold_status: Option<ObjectLockLegalHoldStatus>,
 }
 
 impl ResponseHeaders {
     pub fn from(map: &HeaderMap) -> core::Result<ResponseHeaders> {
         Ok(ResponseHeaders {
+            accept_ranges: map.as_optional()?,
+            expiration: map.as_optional()?,
+            restore: map.as_optional()?,
+            last_modified: map.as_optional()?,
+            content_length: map.as_optional()?,
             e_tag: map.as_required()?,
+            checksum: Checksum::find_in(map)?,
+            missing_meta: map.as_optional()?,
+            version_id: map.as_optional()?,
+            cache_control: map.as_optional()?,
+            content_disposition: map.as_optional()?,
+            content_encoding: map.as_optional()?,
+            content_language: map.as_optional()?,
+            content_range: map.as_optional()?,
+            content_type: map.as_optional()?,
+            expires: map.as_optional()?,
+            website_redirect_location: map.as_optional()?,
+            server_side_encryption: map.as_optional()?,
+            metadata: Metadata::find_in(map)?,
+            sse_customer_algorithm: map.as_optional()?,
+            sse_customer_key_md5: map.as_optional()?,
+            sse_kms_key_id: map.as_optional()?,
+            bucket_key_enabled: map.as_optional()?,
+            storage_class: map.as_optional()?,
+            request_charged: map.as_optional()?,
+            replication_status: map.as_optional()?,
+            parts_count: map.as_optional()?,
+            tag_count: map.as_optional()?,
+            object_lock_mode: map.as_optional()?,
+            object_lock_retain_until_date: map.as_optional()?,
+            object_lock_legal_hold_status: map.as_optional()?,
         })
     }
+
+    /// Verifiers to check the integrity of the received body.
+    pub fn verifiers(&self) -> Vec<Box<dyn Verifier>> {
+        let mut verifiers: Vec<Box<dyn Verifier>> = vec![];
+        if self.content_range.is_some() {
+            // the ETag and the checksum are of the whole object, not of the range.
+            return verifiers;
+        }
+        if let Some(checksum) = &self.checksum {
+            // composite checksums cannot be calculated from the whole body.
+            if !checksum.is_composite() {
+                verifiers.push(Box::new(ChecksumVerifier::new(checksum.clone())));
+            }
+        }
+        if let Some(md5) = self.e_tag_as_md5() {
+            verifiers.push(Box::new(Md5Verifier::new(md5)));
+        }
+        verifiers
+    }
+
+    fn e_tag_as_md5(&self) -> Option<&str> {
+        let is_kms = matches!(&self.server_side_encryption, Some(sse) if sse.is_kms());
+
+        if is_kms || self.sse_customer_algorithm.is_some() {
+            None
+        } else {
+            self.e_tag.as_md5()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponseHeaders;
+    use crate::core;
+    use crate::core::response::headers::ServerSideEncryption;
+    use crate::core::StorageClass;
+    use reqwest::header::HeaderMap;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> core::Result<ResponseHeaders> {
+        let mut map = HeaderMap::new();
+        for (key, value) in pairs {
+            map.insert(*key, value.parse().unwrap());
+        }
+        ResponseHeaders::from(&map)
+    }
+
+    #[test]
+    fn it_parses_standard_headers() -> core::Result<()> {
+        let headers = headers(&[
+            ("Accept-Ranges", "bytes"),
+            ("Content-Length", "8"),
+            ("Content-Type", "binary/octet-stream"),
+            ("ETag", "\"090a4e14a392f707cf164a20cee76c18\""),
+            ("Last-Modified", "Thu, 20 Feb 2020 13:28:58 GMT"),
+            ("x-amz-meta-author", "alice"),
+            ("x-amz-server-side-encryption", "AES256"),
+            ("x-amz-server-side-encryption-bucket-key-enabled", "true"),
+            ("x-amz-storage-class", "STANDARD_IA"),
+            ("x-amz-version-id", "3HL4kqtJlcpXroDTDmJ"),
+        ])?;
+        assert_eq!(headers.accept_ranges.unwrap().as_str(), "bytes");
+        assert_eq!(headers.content_length.unwrap().value(), 8);
+        assert_eq!(
+            headers.content_type.unwrap().as_str(),
+            "binary/octet-stream"
+        );
+        assert_eq!(headers.metadata.get("author"), Some("alice"));
+        assert_eq!(
+            headers.server_side_encryption,
+            Some(ServerSideEncryption::Aes256)
+        );
+        assert!(headers.bucket_key_enabled.unwrap().value());
+        assert_eq!(headers.storage_class, Some(StorageClass::StandardIa));
+        assert_eq!(headers.version_id.unwrap().as_str(), "3HL4kqtJlcpXroDTDmJ");
+        Ok(())
+    }
+
+    #[test]
+    fn it_fails_on_invalid_number() {
+        let result = headers(&[("ETag", "\"abc\""), ("Content-Length", "eight")]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_verifies_plain_etag() -> core::Result<()> {
+        let headers = headers(&[("ETag", "\"65a8e27d8879283831b664bd8b7f0ad4\"")])?;
+        assert_eq!(headers.verifiers().len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn it_skips_etag_of_partial_content() -> core::Result<()> {
+        let headers = headers(&[
+            ("ETag", "\"65a8e27d8879283831b664bd8b7f0ad4\""),
+            ("Content-Range", "bytes 0-7/100"),
+        ])?;
+        assert_eq!(headers.verifiers().len(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn it_skips_etag_of_kms_encrypted_object() -> core::Result<()> {
+        let headers = headers(&[
+            ("ETag", "\"65a8e27d8879283831b664bd8b7f0ad4\""),
+            ("x-amz-server-side-encryption", "aws:kms"),
+        ])?;
+        assert_eq!(headers.verifiers().len(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn it_skips_composite_checksum() -> core::Result<()> {
+        let headers = headers(&[
+            ("ETag", "\"d41d8cd98f00b204e9800998ecf8427e-2\""),
+            ("x-amz-checksum-crc32", "7ErD0A==-2"),
+        ])?;
+        assert_eq!(headers.verifiers().len(), 0);
+        Ok(())
+    }
 }
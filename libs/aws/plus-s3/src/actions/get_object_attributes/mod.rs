@@ -0,0 +1,61 @@
+mod error;
+pub use error::Error;
+
+mod request;
+pub use request::{AttributesRequest, ObjectAttribute};
+
+mod response;
+pub use response::{
+    Checksum, ObjectAttributes, ObjectPart, ObjectParts, Response, ResponseHeaders,
+};
+
+use crate::actions;
+use crate::actions::get_object_attributes;
+use crate::client::S3Client;
+use crate::core::request::{ResourceLoader, WithOptions};
+use crate::core::verbs::{HasObjectKey, HasQueryParams, IsGet};
+use crate::core::xml;
+use crate::internal::impl_async::{read_text, RequestProvider};
+
+/// rf.
+/// [GetObjectAttributes - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObjectAttributes.html)
+pub trait Request: HasObjectKey + HasQueryParams + ResourceLoader + Send + Sync {}
+
+impl<A: Request> IsGet<Response> for A {}
+
+impl<A: Request> Request for WithOptions<A> {}
+
+type Result<A> = std::result::Result<A, get_object_attributes::Error>;
+
+#[async_trait]
+pub trait Requester {
+    async fn get_object_attributes<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync;
+}
+
+#[async_trait]
+impl Requester for S3Client {
+    async fn get_object_attributes<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync,
+    {
+        let client = &self.internal_client;
+        let response: get_object_attributes::Result<Response> = async {
+            let provider = RequestProvider::new(&self, &request)?;
+            let response = client.request_by(provider).await?;
+            let headers = ResponseHeaders::from(response.headers())?;
+            let text = read_text(response, self.config().read_timeout).await?;
+            Ok(Response {
+                headers,
+                attributes: xml::from_str(&text)?,
+            })
+        }
+        .await;
+        Ok(response?)
+    }
+}
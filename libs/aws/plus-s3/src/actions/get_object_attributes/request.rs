@@ -0,0 +1,124 @@
+use crate::actions::get_object_attributes;
+use crate::core;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use plus_aws::http::request::ToHeaderFragment;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct AttributesRequest {
+    pub object_key: String,
+    pub attributes: Vec<ObjectAttribute>,
+    pub version_id: Option<String>,
+    /// Maximum number of parts to be listed in `ObjectParts`.
+    pub max_parts: Option<u32>,
+    /// Parts after this number are listed in `ObjectParts`.
+    pub part_number_marker: Option<u32>,
+}
+
+impl AttributesRequest {
+    pub fn new<A: Into<String>>(object_key: A, attributes: Vec<ObjectAttribute>) -> Self {
+        AttributesRequest {
+            object_key: object_key.into(),
+            attributes,
+            version_id: None,
+            max_parts: None,
+            part_number_marker: None,
+        }
+    }
+}
+
+impl HasObjectKey for AttributesRequest {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl HasQueryParams for AttributesRequest {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = vec![("attributes", "".to_string())];
+        if let Some(version_id) = &self.version_id {
+            params.push(("versionId", version_id.to_string()));
+        }
+        params
+    }
+}
+
+#[async_trait]
+impl ResourceLoader for AttributesRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let attributes = self
+            .attributes
+            .iter()
+            .map(ObjectAttribute::as_str)
+            .collect::<Vec<&str>>()
+            .join(",");
+
+        let mut headers = vec![ToHeaderFragment::into((
+            "x-amz-object-attributes",
+            attributes.as_str(),
+        ))?];
+        if let Some(max_parts) = self.max_parts {
+            headers.push(ToHeaderFragment::into((
+                "x-amz-max-parts",
+                u64::from(max_parts),
+            ))?);
+        }
+        if let Some(marker) = self.part_number_marker {
+            headers.push(ToHeaderFragment::into((
+                "x-amz-part-number-marker",
+                u64::from(marker),
+            ))?);
+        }
+        Ok(RequestResource {
+            headers,
+            ..RequestResource::empty()
+        })
+    }
+}
+
+impl get_object_attributes::Request for AttributesRequest {}
+
+/// rf.
+/// [GetObjectAttributes - Request Syntax](https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObjectAttributes.html#API_GetObjectAttributes_RequestSyntax)
+#[derive(Clone, Debug, PartialEq)]
+pub enum ObjectAttribute {
+    ETag,
+    Checksum,
+    ObjectParts,
+    StorageClass,
+    ObjectSize,
+}
+
+impl ObjectAttribute {
+    pub fn all() -> Vec<ObjectAttribute> {
+        vec![
+            ObjectAttribute::ETag,
+            ObjectAttribute::Checksum,
+            ObjectAttribute::ObjectParts,
+            ObjectAttribute::StorageClass,
+            ObjectAttribute::ObjectSize,
+        ]
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectAttribute::ETag => "ETag",
+            ObjectAttribute::Checksum => "Checksum",
+            ObjectAttribute::ObjectParts => "ObjectParts",
+            ObjectAttribute::StorageClass => "StorageClass",
+            ObjectAttribute::ObjectSize => "ObjectSize",
+        }
+    }
+}
+
+impl FromStr for ObjectAttribute {
+    type Err = get_object_attributes::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ObjectAttribute::all()
+            .into_iter()
+            .find(|attribute| attribute.as_str() == s)
+            .ok_or_else(|| get_object_attributes::Error::UnknownObjectAttribute(s.to_string()))
+    }
+}
@@ -0,0 +1,141 @@
+use crate::core;
+use crate::core::response::headers::{AwsHeaderMap, LastModified, VersionId};
+use crate::core::StorageClass;
+use reqwest::header::HeaderMap;
+
+#[derive(Debug)]
+pub struct Response {
+    pub headers: ResponseHeaders,
+    pub attributes: ObjectAttributes,
+}
+
+#[derive(Debug)]
+pub struct ResponseHeaders {
+    pub last_modified: Option<LastModified>,
+    pub version_id: Option<VersionId>,
+}
+
+impl ResponseHeaders {
+    pub fn from(map: &HeaderMap) -> core::Result<ResponseHeaders> {
+        Ok(ResponseHeaders {
+            last_modified: map.as_optional()?,
+            version_id: map.as_optional()?,
+        })
+    }
+}
+
+/// Only the attributes specified by the request are returned.
+///
+/// rf.
+/// [GetObjectAttributes - Response Syntax](https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObjectAttributes.html#API_GetObjectAttributes_ResponseSyntax)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectAttributes {
+    #[serde(rename = "ETag")]
+    pub e_tag: Option<String>,
+    pub checksum: Option<Checksum>,
+    pub object_parts: Option<ObjectParts>,
+    pub storage_class: Option<StorageClass>,
+    pub object_size: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Checksum {
+    #[serde(rename = "ChecksumCRC32")]
+    pub checksum_crc32: Option<String>,
+
+    #[serde(rename = "ChecksumCRC32C")]
+    pub checksum_crc32c: Option<String>,
+
+    #[serde(rename = "ChecksumSHA1")]
+    pub checksum_sha1: Option<String>,
+
+    #[serde(rename = "ChecksumSHA256")]
+    pub checksum_sha256: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectParts {
+    pub parts_count: Option<u32>,
+    pub part_number_marker: Option<u32>,
+    pub next_part_number_marker: Option<u32>,
+    pub max_parts: Option<u32>,
+    #[serde(default)]
+    pub is_truncated: bool,
+    #[serde(rename = "Part", default)]
+    pub parts: Vec<ObjectPart>,
+}
+
+impl ObjectParts {
+    /// Returns the marker to request the next page of parts, if any.
+    pub fn next_marker(&self) -> Option<u32> {
+        if self.is_truncated {
+            self.next_part_number_marker
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectPart {
+    pub part_number: u32,
+    pub size: u64,
+
+    #[serde(rename = "ChecksumCRC32")]
+    pub checksum_crc32: Option<String>,
+
+    #[serde(rename = "ChecksumCRC32C")]
+    pub checksum_crc32c: Option<String>,
+
+    #[serde(rename = "ChecksumSHA1")]
+    pub checksum_sha1: Option<String>,
+
+    #[serde(rename = "ChecksumSHA256")]
+    pub checksum_sha256: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ObjectAttributes;
+    use crate::core::{xml, StorageClass};
+
+    #[test]
+    fn it_can_be_deserialized() -> crate::core::Result<()> {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <GetObjectAttributesResponse xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <ETag>d41d8cd98f00b204e9800998ecf8427e-2</ETag>
+              <Checksum><ChecksumSHA256>47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=-2</ChecksumSHA256></Checksum>
+              <ObjectParts>
+                <PartNumberMarker>0</PartNumberMarker>
+                <NextPartNumberMarker>1</NextPartNumberMarker>
+                <MaxParts>1</MaxParts>
+                <IsTruncated>true</IsTruncated>
+                <Part>
+                  <PartNumber>1</PartNumber>
+                  <Size>5242880</Size>
+                  <ChecksumSHA256>ZnJvbSBwYXJ0IG9uZQ==</ChecksumSHA256>
+                </Part>
+                <PartsCount>2</PartsCount>
+              </ObjectParts>
+              <StorageClass>STANDARD</StorageClass>
+              <ObjectSize>5242890</ObjectSize>
+            </GetObjectAttributesResponse>"#;
+
+        let attributes: ObjectAttributes = xml::from_str(text)?;
+        assert_eq!(
+            attributes.e_tag.as_deref(),
+            Some("d41d8cd98f00b204e9800998ecf8427e-2")
+        );
+        assert_eq!(attributes.storage_class, Some(StorageClass::Standard));
+        assert_eq!(attributes.object_size, Some(5242890));
+
+        let parts = attributes.object_parts.unwrap();
+        assert_eq!(parts.parts_count, Some(2));
+        assert_eq!(parts.next_marker(), Some(1));
+        assert_eq!(parts.parts[0].size, 5242880);
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::internal;
+
+#[derive(Fail, Debug)]
+pub enum Error {
+    #[fail(display = "internal::Error > {}", 0)]
+    InternalError(internal::Error),
+
+    #[fail(display = "reqwest::Error > {}", 0)]
+    ReqwestError(reqwest::Error),
+
+    #[fail(display = "crate::core::Error > {}", 0)]
+    S3CoreError(crate::core::Error),
+}
+
+impl From<internal::Error> for Error {
+    fn from(e: internal::Error) -> Self {
+        Error::InternalError(e)
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::ReqwestError(e)
+    }
+}
+
+impl From<crate::core::Error> for Error {
+    fn from(e: crate::core::Error) -> Self {
+        Error::S3CoreError(e)
+    }
+}
@@ -0,0 +1,6 @@
+use crate::core::configuration::public_access_block::PublicAccessBlockConfiguration;
+
+#[derive(Debug)]
+pub struct Response {
+    pub configuration: PublicAccessBlockConfiguration,
+}
@@ -0,0 +1,53 @@
+mod error;
+pub use error::Error;
+
+mod request;
+pub use request::ObjectRequest;
+
+mod response;
+pub use response::{Response, ResponseHeaders};
+
+use crate::actions;
+use crate::actions::head_object;
+use crate::client::S3Client;
+use crate::core::request::{ResourceLoader, WithOptions};
+use crate::core::verbs::{HasObjectKey, HasQueryParams, IsHead};
+use crate::internal::impl_async::RequestProvider;
+
+/// rf.
+/// [HeadObject - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_HeadObject.html)
+pub trait Request: HasObjectKey + HasQueryParams + ResourceLoader + Send + Sync {}
+
+impl<A: Request> IsHead<Response> for A {}
+
+impl<A: Request> Request for WithOptions<A> {}
+
+type Result<A> = std::result::Result<A, head_object::Error>;
+
+#[async_trait]
+pub trait Requester {
+    async fn head_object<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync;
+}
+
+#[async_trait]
+impl Requester for S3Client {
+    async fn head_object<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync,
+    {
+        let client = &self.internal_client;
+        let headers: head_object::Result<ResponseHeaders> = async {
+            let provider = RequestProvider::new(&self, &request)?;
+            let response = client.request_by(provider).await?;
+            Ok(ResponseHeaders::from(response.headers())?)
+        }
+        .await;
+        Ok(Response { headers: headers? })
+    }
+}
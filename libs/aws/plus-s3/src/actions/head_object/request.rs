@@ -0,0 +1,40 @@
+use crate::actions::head_object;
+use crate::core;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use plus_aws::index::RegionCode;
+
+#[derive(Debug)]
+pub struct ObjectRequest {
+    pub object_key: String,
+    pub region_code: Option<RegionCode>,
+}
+
+impl ObjectRequest {
+    pub fn new<A: Into<String>>(object_key: A) -> Self {
+        ObjectRequest {
+            object_key: object_key.into(),
+            region_code: None,
+        }
+    }
+}
+
+impl HasObjectKey for ObjectRequest {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl HasQueryParams for ObjectRequest {}
+
+#[async_trait]
+impl ResourceLoader for ObjectRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        Ok(RequestResource {
+            region: self.region_code.as_ref(),
+            ..RequestResource::empty()
+        })
+    }
+}
+
+impl head_object::Request for ObjectRequest {}
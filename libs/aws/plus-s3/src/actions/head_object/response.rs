@@ -0,0 +1,25 @@
+use crate::core;
+use crate::core::response::headers::{AwsHeaderMap, ContentLength, ETag, ReplicationStatus};
+use reqwest::header::HeaderMap;
+
+#[derive(Debug)]
+pub struct Response {
+    pub headers: ResponseHeaders,
+}
+
+#[derive(Debug)]
+pub struct ResponseHeaders {
+    pub content_length: Option<ContentLength>,
+    pub e_tag: ETag,
+    pub replication_status: Option<ReplicationStatus>,
+}
+
+impl ResponseHeaders {
+    pub fn from(map: &HeaderMap) -> core::Result<ResponseHeaders> {
+        Ok(ResponseHeaders {
+            content_length: map.as_optional()?,
+            e_tag: map.as_required()?,
+            replication_status: map.as_optional()?,
+        })
+    }
+}
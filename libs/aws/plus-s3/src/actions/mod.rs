@@ -1,5 +1,24 @@
+pub mod abort_multipart_upload;
+pub mod complete_multipart_upload;
+pub mod create_multipart_upload;
+pub mod delete_bucket_ownership_controls;
+pub mod delete_bucket_replication;
+pub mod delete_public_access_block;
+pub mod get_bucket_accelerate_configuration;
+pub mod get_bucket_ownership_controls;
+pub mod get_bucket_replication;
+pub mod get_bucket_request_payment;
 pub mod get_object;
+pub mod get_object_attributes;
+pub mod get_public_access_block;
+pub mod head_object;
+pub mod put_bucket_accelerate_configuration;
+pub mod put_bucket_ownership_controls;
+pub mod put_bucket_replication;
+pub mod put_bucket_request_payment;
 pub mod put_object;
+pub mod put_public_access_block;
+pub mod upload_part;
 
 mod error;
 pub use error::Error;
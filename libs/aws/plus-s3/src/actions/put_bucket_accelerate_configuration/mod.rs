@@ -0,0 +1,53 @@
+mod error;
+pub use error::Error;
+
+mod request;
+pub use request::ConfigurationRequest;
+
+mod response;
+pub use response::Response;
+
+use crate::actions;
+use crate::actions::put_bucket_accelerate_configuration;
+use crate::client::S3Client;
+use crate::core::request::{ResourceLoader, WithOptions};
+use crate::core::verbs::{HasObjectKey, HasQueryParams, IsPut};
+use crate::internal::impl_async::RequestProvider;
+
+/// rf.
+/// [PutBucketAccelerateConfiguration - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_PutBucketAccelerateConfiguration.html)
+pub trait Request: HasObjectKey + HasQueryParams + ResourceLoader + Send + Sync {}
+
+impl<A: Request> IsPut<Response> for A {}
+
+impl<A: Request> Request for WithOptions<A> {}
+
+type Result<A> = std::result::Result<A, put_bucket_accelerate_configuration::Error>;
+
+#[async_trait]
+pub trait Requester {
+    async fn put_bucket_accelerate_configuration<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync;
+}
+
+#[async_trait]
+impl Requester for S3Client {
+    async fn put_bucket_accelerate_configuration<A>(&self, request: A) -> actions::Result<Response>
+    where
+        A: Request,
+        A: Send,
+        A: Sync,
+    {
+        let client = &self.internal_client;
+        let response: put_bucket_accelerate_configuration::Result<Response> = async {
+            let provider = RequestProvider::new(&self, &request)?;
+            let _response = client.request_by(provider).await?;
+            Ok(Response {})
+        }
+        .await;
+        Ok(response?)
+    }
+}
@@ -0,0 +1,40 @@
+use crate::actions::put_bucket_ownership_controls;
+use crate::core;
+use crate::core::configuration::ownership_controls::OwnershipControls;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use crate::core::xml;
+
+#[derive(Debug)]
+pub struct ConfigurationRequest {
+    pub configuration: OwnershipControls,
+}
+
+impl ConfigurationRequest {
+    pub fn new(configuration: OwnershipControls) -> Self {
+        ConfigurationRequest { configuration }
+    }
+}
+
+impl HasObjectKey for ConfigurationRequest {
+    /// Bucket subresources are located at the root of the bucket.
+    fn get_object_key(&self) -> &str {
+        ""
+    }
+}
+
+impl HasQueryParams for ConfigurationRequest {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        vec![("ownershipControls", "".to_string())]
+    }
+}
+
+#[async_trait]
+impl ResourceLoader for ConfigurationRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let document = xml::to_string(&self.configuration)?;
+        Ok(RequestResource::xml(document))
+    }
+}
+
+impl put_bucket_ownership_controls::Request for ConfigurationRequest {}
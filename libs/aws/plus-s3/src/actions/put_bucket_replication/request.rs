@@ -0,0 +1,40 @@
+use crate::actions::put_bucket_replication;
+use crate::core;
+use crate::core::configuration::replication::ReplicationConfiguration;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use crate::core::xml;
+
+#[derive(Debug)]
+pub struct ConfigurationRequest {
+    pub configuration: ReplicationConfiguration,
+}
+
+impl ConfigurationRequest {
+    pub fn new(configuration: ReplicationConfiguration) -> Self {
+        ConfigurationRequest { configuration }
+    }
+}
+
+impl HasObjectKey for ConfigurationRequest {
+    /// Bucket subresources are located at the root of the bucket.
+    fn get_object_key(&self) -> &str {
+        ""
+    }
+}
+
+impl HasQueryParams for ConfigurationRequest {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        vec![("replication", "".to_string())]
+    }
+}
+
+#[async_trait]
+impl ResourceLoader for ConfigurationRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let document = xml::to_string(&self.configuration)?;
+        Ok(RequestResource::xml(document))
+    }
+}
+
+impl put_bucket_replication::Request for ConfigurationRequest {}
@@ -2,7 +2,7 @@ mod error;
 pub use error::Error;
 
 mod request;
-pub use request::FileRequest;
+pub use request::{BytesRequest, FileRequest, ReaderRequest, StreamRequest};
 
 mod response;
 pub use response::{Response, ResponseHeaders};
@@ -10,16 +10,18 @@ pub use response::{Response, ResponseHeaders};
 use crate::actions;
 use crate::actions::put_object;
 use crate::client::S3Client;
-use crate::core::request::ResourceLoader;
-use crate::core::verbs::{HasObjectKey, IsPut};
-use crate::internal::impl_async::{InternalClient, RequestProvider};
+use crate::core::request::{ResourceLoader, WithOptions};
+use crate::core::verbs::{HasObjectKey, HasQueryParams, IsPut};
+use crate::internal::impl_async::RequestProvider;
 
 /// rf.
 /// [PutObject - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_PutObject.html)
-pub trait Request: HasObjectKey + ResourceLoader + Send + Sync {}
+pub trait Request: HasObjectKey + HasQueryParams + ResourceLoader + Send + Sync {}
 
 impl<A: Request> IsPut<Response> for A {}
 
+impl<A: Request> Request for WithOptions<A> {}
+
 type Result<A> = std::result::Result<A, put_object::Error>;
 
 #[async_trait]
@@ -39,7 +41,7 @@ impl Requester for S3Client {
         A: Send,
         A: Sync,
     {
-        let client = InternalClient::default();
+        let client = &self.internal_client;
         let headers: put_object::Result<ResponseHeaders> = async {
             let provider = RequestProvider::new(&self, &request)?;
             let response = client.request_by(provider).await?;
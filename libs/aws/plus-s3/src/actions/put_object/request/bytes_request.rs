@@ -0,0 +1,57 @@
+use crate::actions::put_object;
+use crate::core;
+use crate::core::checksum::ChecksumAlgorithm;
+use crate::core::request::{BodySource, RequestPayload, RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use bytes::Bytes;
+use plus_aws::http::request::header::ContentType;
+use plus_aws::index::RegionCode;
+
+/// Uploads the bytes in memory, such as generated data.
+#[derive(Debug)]
+pub struct BytesRequest {
+    pub bytes: Bytes,
+    pub object_key: String,
+    pub content_type: Option<ContentType>,
+    pub region_code: Option<RegionCode>,
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+}
+
+impl BytesRequest {
+    pub fn new<A: Into<String>, B: Into<Bytes>>(object_key: A, bytes: B) -> Self {
+        BytesRequest {
+            bytes: bytes.into(),
+            object_key: object_key.into(),
+            content_type: None,
+            region_code: None,
+            checksum_algorithm: None,
+        }
+    }
+}
+
+impl HasObjectKey for BytesRequest {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl HasQueryParams for BytesRequest {}
+
+#[async_trait]
+impl ResourceLoader for BytesRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let payload = RequestPayload {
+            source: BodySource::Bytes(self.bytes.clone()),
+            checksum_algorithm: self.checksum_algorithm,
+        };
+        let resource = RequestResource {
+            payload: Some(payload),
+            region: self.region_code.as_ref(),
+            content_type: self.content_type.as_ref(),
+            ..RequestResource::empty()
+        };
+        Ok(resource)
+    }
+}
+
+impl put_object::Request for BytesRequest {}
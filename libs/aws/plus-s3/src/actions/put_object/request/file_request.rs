@@ -1,14 +1,11 @@
 use crate::actions::put_object;
 use crate::core;
-use crate::core::request::{RequestResource, ResourceLoader};
-use crate::core::verbs::HasObjectKey;
-use plus_aws::auth::v4::canonical::HashedPayload;
-use plus_aws::auth::v4::chrono::now;
+use crate::core::checksum::ChecksumAlgorithm;
+use crate::core::request::{BodySource, RequestPayload, RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
 use plus_aws::http::request::header::ContentType;
 use plus_aws::index::RegionCode;
-use plus_aws::io::stream::bytes_stream;
-use std::io::ErrorKind::NotFound;
-use tokio::fs::File;
+use std::path::Path;
 
 #[derive(Debug)]
 pub struct FileRequest {
@@ -16,25 +13,7 @@ pub struct FileRequest {
     pub object_key: String,
     pub content_type: Option<ContentType>,
     pub region_code: Option<RegionCode>,
-}
-
-impl FileRequest {
-    async fn open_file(&self) -> core::Result<File> {
-        File::open(&self.file_path).await.map_err(|e| match e {
-            _ if e.kind() == NotFound => core::Error::FileNotFound {
-                path: self.file_path.to_string(),
-                description: e.to_string(),
-            },
-            _ => core::Error::StdIoError(e),
-        })
-    }
-
-    async fn to_stream_body(&self) -> core::Result<reqwest::Body> {
-        let file: File = self.open_file().await?;
-        let stream = bytes_stream::from_file(file);
-        let body = reqwest::Body::wrap_stream(stream);
-        Ok(body)
-    }
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
 }
 
 impl HasObjectKey for FileRequest {
@@ -43,20 +22,20 @@ impl HasObjectKey for FileRequest {
     }
 }
 
+impl HasQueryParams for FileRequest {}
+
 #[async_trait]
 impl ResourceLoader for FileRequest {
     async fn load(&self) -> core::Result<RequestResource<'_>> {
-        let file: File = self.open_file().await?;
-        let content_length = file.metadata().await?.len();
-        let hash = HashedPayload::from_file(file).await?;
-
+        let payload = RequestPayload {
+            source: BodySource::File(Path::new(&self.file_path)),
+            checksum_algorithm: self.checksum_algorithm,
+        };
         let resource = RequestResource {
-            body: Some(self.to_stream_body().await?),
-            hash,
+            payload: Some(payload),
             region: self.region_code.as_ref(),
             content_type: self.content_type.as_ref(),
-            content_length,
-            requested_at: now(),
+            ..RequestResource::empty()
         };
         Ok(resource)
     }
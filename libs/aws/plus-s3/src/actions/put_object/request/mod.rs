@@ -1,2 +1,11 @@
+mod bytes_request;
+pub use bytes_request::BytesRequest;
+
 mod file_request;
 pub use file_request::FileRequest;
+
+mod reader_request;
+pub use reader_request::ReaderRequest;
+
+mod stream_request;
+pub use stream_request::StreamRequest;
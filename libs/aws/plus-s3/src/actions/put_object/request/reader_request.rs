@@ -0,0 +1,144 @@
+use crate::actions::put_object;
+use crate::core;
+use crate::core::checksum::ChecksumAlgorithm;
+use crate::core::request::{
+    buffer_capacity, BodySource, RequestPayload, RequestResource, ResourceLoader,
+};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use bytes::Bytes;
+use plus_aws::http::request::header::ContentType;
+use plus_aws::index::RegionCode;
+use std::fmt;
+use std::sync::Mutex;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Uploads the content of the reader which has the known length.
+///
+/// The content is buffered in memory at the first load,
+/// because the payload must be hashed before it is sent.
+/// `StreamRequest` sends it without buffering instead, which cannot be retried.
+pub struct ReaderRequest<R> {
+    pub object_key: String,
+    pub content_length: u64,
+    pub content_type: Option<ContentType>,
+    pub region_code: Option<RegionCode>,
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+    reader: Mutex<Option<R>>,
+    buffer: Mutex<Option<Bytes>>,
+}
+
+impl<R> ReaderRequest<R>
+where
+    R: AsyncRead + Unpin + Send,
+{
+    pub fn new<A: Into<String>>(object_key: A, reader: R, content_length: u64) -> Self {
+        ReaderRequest {
+            object_key: object_key.into(),
+            content_length,
+            content_type: None,
+            region_code: None,
+            checksum_algorithm: None,
+            reader: Mutex::new(Some(reader)),
+            buffer: Mutex::new(None),
+        }
+    }
+
+    async fn read_all(&self) -> core::Result<Bytes> {
+        if let Some(bytes) = self.buffer.lock().expect("poisoned").as_ref() {
+            return Ok(bytes.clone());
+        }
+        let reader = self.reader.lock().expect("poisoned").take();
+        let mut reader = reader.ok_or(core::Error::ReaderAlreadyConsumed)?;
+
+        let mut buffer = Vec::with_capacity(buffer_capacity(self.content_length)?);
+        (&mut reader)
+            .take(self.content_length)
+            .read_to_end(&mut buffer)
+            .await?;
+
+        if buffer.len() as u64 != self.content_length {
+            return Err(core::Error::ContentLengthMismatch {
+                expected: self.content_length,
+                actual: buffer.len() as u64,
+            });
+        }
+        let bytes = Bytes::from(buffer);
+        *self.buffer.lock().expect("poisoned") = Some(bytes.clone());
+        Ok(bytes)
+    }
+}
+
+impl<R> fmt::Debug for ReaderRequest<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReaderRequest")
+            .field("object_key", &self.object_key)
+            .field("content_length", &self.content_length)
+            .field("content_type", &self.content_type)
+            .field("region_code", &self.region_code)
+            .field("checksum_algorithm", &self.checksum_algorithm)
+            .finish()
+    }
+}
+
+impl<R> HasObjectKey for ReaderRequest<R> {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl<R> HasQueryParams for ReaderRequest<R> {}
+
+#[async_trait]
+impl<R> ResourceLoader for ReaderRequest<R>
+where
+    R: AsyncRead + Unpin + Send,
+{
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let payload = RequestPayload {
+            source: BodySource::Bytes(self.read_all().await?),
+            checksum_algorithm: self.checksum_algorithm,
+        };
+        let resource = RequestResource {
+            payload: Some(payload),
+            region: self.region_code.as_ref(),
+            content_type: self.content_type.as_ref(),
+            ..RequestResource::empty()
+        };
+        Ok(resource)
+    }
+}
+
+impl<R> put_object::Request for ReaderRequest<R> where R: AsyncRead + Unpin + Send {}
+
+#[cfg(test)]
+mod tests {
+    use super::ReaderRequest;
+    use crate::core;
+    use futures::executor::block_on;
+
+    #[test]
+    fn it_buffers_content_to_load_again() -> core::Result<()> {
+        let request = ReaderRequest::new("sample.txt", &b"Hello, World!"[..], 13);
+        assert_eq!(block_on(request.read_all())?, "Hello, World!");
+        assert_eq!(block_on(request.read_all())?, "Hello, World!");
+        Ok(())
+    }
+
+    #[test]
+    fn it_fails_if_reader_is_shorter_than_length() {
+        let request = ReaderRequest::new("sample.txt", &b"Hello"[..], 13);
+        match block_on(request.read_all()) {
+            Err(core::Error::ContentLengthMismatch { actual, .. }) => assert_eq!(actual, 5),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_does_not_allocate_declared_length_in_advance() {
+        let request = ReaderRequest::new("sample.txt", &b"Hello"[..], u64::MAX);
+        match block_on(request.read_all()) {
+            Err(core::Error::ContentLengthMismatch { actual, .. }) => assert_eq!(actual, 5),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
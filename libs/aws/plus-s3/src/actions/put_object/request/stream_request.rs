@@ -0,0 +1,126 @@
+use crate::actions::put_object;
+use crate::core;
+use crate::core::checksum::ChecksumAlgorithm;
+use crate::core::request::{
+    BodySource, PayloadSigning, RequestOptions, RequestPayload, RequestResource, ResourceLoader,
+};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use plus_aws::http::request::header::ContentType;
+use plus_aws::index::RegionCode;
+use plus_aws::io::stream::bytes_stream;
+use std::fmt;
+use std::sync::Mutex;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Uploads the content of the reader which has the known length,
+/// reading it only once by `aws-chunked` encoding.
+///
+/// The reader cannot be rewound, so the request is neither retried nor redirected,
+/// and fails with `ReaderAlreadyConsumed` when it is sent again.
+pub struct StreamRequest<R> {
+    pub object_key: String,
+    pub content_length: u64,
+    pub content_type: Option<ContentType>,
+    pub region_code: Option<RegionCode>,
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+    /// `Signed` buffers the content in memory to hash it.
+    pub payload_signing: PayloadSigning,
+    reader: Mutex<Option<R>>,
+}
+
+impl<R> StreamRequest<R>
+where
+    R: AsyncRead + Unpin + Send + Sync + 'static,
+{
+    pub fn new<A: Into<String>>(object_key: A, reader: R, content_length: u64) -> Self {
+        StreamRequest {
+            object_key: object_key.into(),
+            content_length,
+            content_type: None,
+            region_code: None,
+            checksum_algorithm: None,
+            payload_signing: PayloadSigning::Streaming,
+            reader: Mutex::new(Some(reader)),
+        }
+    }
+
+    fn take_source(&self) -> core::Result<BodySource<'static>> {
+        let reader = self.reader.lock().expect("poisoned").take();
+        let reader = reader.ok_or(core::Error::ReaderAlreadyConsumed)?;
+        let stream = bytes_stream::from_reader(reader.take(self.content_length));
+        Ok(BodySource::Stream {
+            stream: Box::pin(stream),
+            content_length: self.content_length,
+        })
+    }
+}
+
+impl<R> fmt::Debug for StreamRequest<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamRequest")
+            .field("object_key", &self.object_key)
+            .field("content_length", &self.content_length)
+            .field("content_type", &self.content_type)
+            .field("region_code", &self.region_code)
+            .field("checksum_algorithm", &self.checksum_algorithm)
+            .field("payload_signing", &self.payload_signing)
+            .finish()
+    }
+}
+
+impl<R> HasObjectKey for StreamRequest<R> {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl<R> HasQueryParams for StreamRequest<R> {}
+
+#[async_trait]
+impl<R> ResourceLoader for StreamRequest<R>
+where
+    R: AsyncRead + Unpin + Send + Sync + 'static,
+{
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let payload = RequestPayload {
+            source: self.take_source()?,
+            checksum_algorithm: self.checksum_algorithm,
+        };
+        let resource = RequestResource {
+            payload: Some(payload),
+            region: self.region_code.as_ref(),
+            content_type: self.content_type.as_ref(),
+            options: RequestOptions {
+                payload_signing: Some(self.payload_signing),
+                ..Default::default()
+            },
+            ..RequestResource::empty()
+        };
+        Ok(resource)
+    }
+
+    fn is_replayable(&self) -> bool {
+        false
+    }
+}
+
+impl<R> put_object::Request for StreamRequest<R> where R: AsyncRead + Unpin + Send + Sync + 'static {}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamRequest;
+    use crate::core;
+    use crate::core::request::ResourceLoader;
+    use futures::executor::block_on;
+
+    #[test]
+    fn it_cannot_load_reader_again() {
+        let request = StreamRequest::new("sample.txt", &b"Hello, World!"[..], 13);
+        assert!(!request.is_replayable());
+        assert!(block_on(request.load()).is_ok());
+        match block_on(request.load()) {
+            Err(core::Error::ReaderAlreadyConsumed) => (),
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+}
@@ -1,5 +1,9 @@
 use crate::core;
-use crate::core::response::headers::{AwsHeaderMap, ETag};
+use crate::core::checksum::Checksum;
+use crate::core::response::headers::{
+    AwsHeaderMap, BucketKeyEnabled, ETag, Expiration, RequestCharged, ServerSideEncryption,
+    SseCustomerAlgorithm, SseCustomerKeyMd5, SseKmsEncryptionContext, SseKmsKeyId, VersionId,
+};
 use reqwest::header::HeaderMap;
 
 #[derive(Debug)]
@@ -9,13 +13,33 @@ pub struct Response {
 
 #[derive(Debug)]
 pub struct ResponseHeaders {
+    pub expiration: Option<Expiration>,
     pub e_tag: ETag,
+    pub checksum: Option<Checksum>,
+    pub server_side_encryption: Option<ServerSideEncryption>,
+    pub version_id: Option<VersionId>,
+    pub sse_customer_algorithm: Option<SseCustomerAlgorithm>,
+    pub sse_customer_key_md5: Option<SseCustomerKeyMd5>,
+    pub sse_kms_key_id: Option<SseKmsKeyId>,
+    pub sse_kms_encryption_context: Option<SseKmsEncryptionContext>,
+    pub bucket_key_enabled: Option<BucketKeyEnabled>,
+    pub request_charged: Option<RequestCharged>,
 }
 
 impl ResponseHeaders {
     pub fn from(map: &HeaderMap) -> core::Result<ResponseHeaders> {
         Ok(ResponseHeaders {
+            expiration: map.as_optional()?,
             e_tag: map.as_required()?,
+            checksum: Checksum::find_in(map)?,
+            server_side_encryption: map.as_optional()?,
+            version_id: map.as_optional()?,
+            sse_customer_algorithm: map.as_optional()?,
+            sse_customer_key_md5: map.as_optional()?,
+            sse_kms_key_id: map.as_optional()?,
+            sse_kms_encryption_context: map.as_optional()?,
+            bucket_key_enabled: map.as_optional()?,
+            request_charged: map.as_optional()?,
         })
     }
 }
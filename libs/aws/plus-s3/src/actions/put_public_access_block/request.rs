@@ -0,0 +1,40 @@
+use crate::actions::put_public_access_block;
+use crate::core;
+use crate::core::configuration::public_access_block::PublicAccessBlockConfiguration;
+use crate::core::request::{RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use crate::core::xml;
+
+#[derive(Debug)]
+pub struct ConfigurationRequest {
+    pub configuration: PublicAccessBlockConfiguration,
+}
+
+impl ConfigurationRequest {
+    pub fn new(configuration: PublicAccessBlockConfiguration) -> Self {
+        ConfigurationRequest { configuration }
+    }
+}
+
+impl HasObjectKey for ConfigurationRequest {
+    /// Bucket subresources are located at the root of the bucket.
+    fn get_object_key(&self) -> &str {
+        ""
+    }
+}
+
+impl HasQueryParams for ConfigurationRequest {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        vec![("publicAccessBlock", "".to_string())]
+    }
+}
+
+#[async_trait]
+impl ResourceLoader for ConfigurationRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let document = xml::to_string(&self.configuration)?;
+        Ok(RequestResource::xml(document))
+    }
+}
+
+impl put_public_access_block::Request for ConfigurationRequest {}
@@ -0,0 +1,60 @@
+use crate::actions::upload_part;
+use crate::core;
+use crate::core::checksum::ChecksumAlgorithm;
+use crate::core::request::{
+    BodySource, FileRange, RequestPayload, RequestResource, ResourceLoader,
+};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use plus_aws::index::RegionCode;
+use std::path::Path;
+
+/// Uploads the range of the file as a part, which is read again on retry.
+#[derive(Debug)]
+pub struct FileRequest {
+    pub file_path: String,
+    pub offset: u64,
+    pub length: u64,
+    pub object_key: String,
+    pub upload_id: String,
+    /// From 1 to 10,000.
+    pub part_number: u32,
+    pub region_code: Option<RegionCode>,
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+}
+
+impl HasObjectKey for FileRequest {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl HasQueryParams for FileRequest {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        vec![
+            ("partNumber", self.part_number.to_string()),
+            ("uploadId", self.upload_id.clone()),
+        ]
+    }
+}
+
+#[async_trait]
+impl ResourceLoader for FileRequest {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let range = FileRange {
+            path: Path::new(&self.file_path),
+            offset: self.offset,
+            length: self.length,
+        };
+        let payload = RequestPayload {
+            source: BodySource::FileRange(range),
+            checksum_algorithm: self.checksum_algorithm,
+        };
+        Ok(RequestResource {
+            payload: Some(payload),
+            region: self.region_code.as_ref(),
+            ..RequestResource::empty()
+        })
+    }
+}
+
+impl upload_part::Request for FileRequest {}
@@ -0,0 +1,19 @@
+use crate::core;
+use crate::core::checksum::Checksum;
+use crate::core::response::headers::{AwsHeaderMap, ETag};
+use reqwest::header::HeaderMap;
+
+#[derive(Debug)]
+pub struct Response {
+    pub e_tag: ETag,
+    pub checksum: Option<Checksum>,
+}
+
+impl Response {
+    pub fn from(map: &HeaderMap) -> core::Result<Response> {
+        Ok(Response {
+            e_tag: map.as_required()?,
+            checksum: Checksum::find_in(map)?,
+        })
+    }
+}
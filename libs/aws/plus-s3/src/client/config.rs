@@ -0,0 +1,78 @@
+use crate::client::{S3ClientConfigBuilder, TlsConfig};
+use crate::core::endpoint::{AddressingStyle, EndpointVariants, S3Endpoint};
+use std::time::Duration;
+
+/// Settings of the HTTP connections used by `S3Client`.
+#[derive(Clone, Debug)]
+pub struct S3ClientConfig {
+    pub endpoint: S3Endpoint,
+    pub endpoint_variants: EndpointVariants,
+    pub addressing_style: AddressingStyle,
+
+    /// Limits the time to establish a connection.
+    pub connect_timeout: Option<Duration>,
+
+    /// Limits the time to wait for the response headers of a request without body,
+    /// and the time to wait for each chunk of the response body.
+    pub read_timeout: Option<Duration>,
+
+    /// Limits the whole operation, including the retries and the response body.
+    pub operation_timeout: Option<Duration>,
+
+    /// Appended to the `User-Agent` header.
+    pub user_agent_suffix: Option<String>,
+
+    pub tls: TlsConfig,
+}
+
+impl Default for S3ClientConfig {
+    /// The same timeouts as AWS CLI.
+    fn default() -> Self {
+        S3ClientConfig {
+            endpoint: S3Endpoint::default(),
+            endpoint_variants: EndpointVariants::default(),
+            addressing_style: AddressingStyle::default(),
+            connect_timeout: Some(Duration::from_secs(60)),
+            read_timeout: Some(Duration::from_secs(60)),
+            operation_timeout: None,
+            user_agent_suffix: None,
+            tls: TlsConfig::default(),
+        }
+    }
+}
+
+impl S3ClientConfig {
+    pub fn builder() -> S3ClientConfigBuilder {
+        S3ClientConfigBuilder::default()
+    }
+
+    pub fn user_agent(&self) -> String {
+        let agent = format!("plus-s3/{}", env!("CARGO_PKG_VERSION"));
+        match &self.user_agent_suffix {
+            Some(suffix) => format!("{} {}", agent, suffix),
+            None => agent,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::S3ClientConfig;
+    use std::time::Duration;
+
+    #[test]
+    fn it_builds_config() {
+        let config = S3ClientConfig::builder()
+            .connect_timeout(Duration::from_secs(3))
+            .read_timeout(None)
+            .user_agent_suffix("s3api/0.1.0")
+            .build();
+
+        assert_eq!(config.connect_timeout, Some(Duration::from_secs(3)));
+        assert_eq!(config.read_timeout, None);
+        assert_eq!(config.operation_timeout, None);
+        let expected = format!("plus-s3/{} s3api/0.1.0", env!("CARGO_PKG_VERSION"));
+        assert_eq!(config.user_agent(), expected);
+        assert!(config.tls.verify);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::client::S3ClientConfig;
+use crate::core;
+use crate::core::endpoint::{AddressingStyle, S3Endpoint};
+use plus_aws::env::aws;
+use reqwest::Url;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Builds `S3ClientConfig` from the default values.
+///
+/// The timeouts are removed by passing `None`.
+#[derive(Debug, Default)]
+pub struct S3ClientConfigBuilder {
+    config: S3ClientConfig,
+}
+
+impl S3ClientConfigBuilder {
+    /// Reads `AWS_USE_DUALSTACK_ENDPOINT` and `AWS_USE_FIPS_ENDPOINT` like AWS CLI.
+    pub fn from_env() -> core::Result<Self> {
+        let builder = S3ClientConfigBuilder::default()
+            .use_dualstack_endpoint(
+                aws::use_dualstack_endpoint()
+                    .as_optional()?
+                    .unwrap_or(false),
+            )
+            .use_fips_endpoint(aws::use_fips_endpoint().as_optional()?.unwrap_or(false));
+        Ok(builder)
+    }
+
+    /// Sends the requests to S3-compatible stores, like `http://localhost:9000`.
+    pub fn endpoint_url(mut self, url: Url) -> Self {
+        self.config.endpoint = S3Endpoint::Custom(url);
+        self
+    }
+
+    /// Sends the requests to `s3.{region}.amazonaws.com` instead of `s3.amazonaws.com`.
+    pub fn regional_endpoint(mut self) -> Self {
+        self.config.endpoint = S3Endpoint::Regional;
+        self
+    }
+
+    pub fn use_dualstack_endpoint(mut self, enabled: bool) -> Self {
+        self.config.endpoint_variants.dualstack = enabled;
+        self
+    }
+
+    pub fn use_fips_endpoint(mut self, enabled: bool) -> Self {
+        self.config.endpoint_variants.fips = enabled;
+        self
+    }
+
+    /// Sends the requests to `{bucket}.s3-accelerate.amazonaws.com`,
+    /// which needs the acceleration enabled on the bucket.
+    pub fn use_accelerate_endpoint(mut self, enabled: bool) -> Self {
+        self.config.endpoint_variants.accelerate = enabled;
+        self
+    }
+
+    pub fn addressing_style(mut self, style: AddressingStyle) -> Self {
+        self.config.addressing_style = style;
+        self
+    }
+
+    pub fn connect_timeout<A: Into<Option<Duration>>>(mut self, timeout: A) -> Self {
+        self.config.connect_timeout = timeout.into();
+        self
+    }
+
+    pub fn read_timeout<A: Into<Option<Duration>>>(mut self, timeout: A) -> Self {
+        self.config.read_timeout = timeout.into();
+        self
+    }
+
+    pub fn operation_timeout<A: Into<Option<Duration>>>(mut self, timeout: A) -> Self {
+        self.config.operation_timeout = timeout.into();
+        self
+    }
+
+    pub fn user_agent_suffix<A: Into<String>>(mut self, suffix: A) -> Self {
+        self.config.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Trusts the certificates in the PEM file in addition to the built-in roots.
+    pub fn ca_bundle<A: Into<PathBuf>>(mut self, path: A) -> Self {
+        self.config.tls.ca_bundle = Some(path.into());
+        self
+    }
+
+    /// Disables the verification of the server certificates, like `--no-verify-ssl`.
+    pub fn verify_ssl(mut self, verify: bool) -> Self {
+        self.config.tls.verify = verify;
+        self
+    }
+
+    pub fn build(self) -> S3ClientConfig {
+        self.config
+    }
+}
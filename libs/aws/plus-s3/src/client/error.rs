@@ -2,9 +2,18 @@ pub type Result<T> = ::std::result::Result<T, Error>;
 
 #[derive(Fail, Debug)]
 pub enum Error {
+    #[fail(display = "ConfigError > {}", 0)]
+    ConfigError(crate::core::Error),
+
     #[fail(display = "CredentialsError > {}", 0)]
     CredentialsError(plus_aws::Error),
 
     #[fail(display = "RegionCodeError > {}", 0)]
     RegionCodeError(plus_aws::Error),
+
+    #[fail(display = "RetryConfigError > {}", 0)]
+    RetryConfigError(crate::core::Error),
+
+    #[fail(display = "TransportError > {}", 0)]
+    TransportError(crate::internal::Error),
 }
@@ -1,20 +1,59 @@
+mod config;
+pub use config::S3ClientConfig;
+
+mod config_builder;
+pub use config_builder::S3ClientConfigBuilder;
+
 mod error;
 pub use error::Error;
 pub use error::Result;
 
+#[cfg(test)]
+pub(crate) mod test_server;
+
+mod tls_config;
+pub use tls_config::TlsConfig;
+
 use crate::actions;
-use crate::actions::{get_object, put_object};
+use crate::actions::{
+    abort_multipart_upload, complete_multipart_upload, create_multipart_upload,
+    delete_bucket_ownership_controls, delete_bucket_replication, delete_public_access_block,
+    get_bucket_accelerate_configuration, get_bucket_ownership_controls, get_bucket_replication,
+    get_bucket_request_payment, get_object, get_object_attributes, get_public_access_block,
+    head_object, put_bucket_accelerate_configuration, put_bucket_ownership_controls,
+    put_bucket_replication, put_bucket_request_payment, put_object, put_public_access_block,
+    upload_part,
+};
+use crate::core::post::{PostForm, PostPolicy};
+use crate::core::request::{PresignRequest, RequestOptions};
+use crate::core::retry::{RateLimiter, RetryConfig};
 use crate::core::verbs::HasBucketScope;
-use crate::core::S3Bucket;
+use crate::core::{RegionCache, S3Bucket};
+use crate::internal;
+use crate::internal::impl_async::InternalClient;
+use crate::internal::Presigner;
 use plus_aws::auth::Credentials;
+use plus_aws::http::request::RichHeaderMap;
 use plus_aws::index::RegionCode;
-use Error::{CredentialsError, RegionCodeError};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, Url};
+use Error::{ConfigError, CredentialsError, RegionCodeError, RetryConfigError, TransportError};
 
-#[derive(Debug)]
+/// Cheap to clone, and the clones share the connections and the caches.
+#[derive(Clone, Debug)]
 pub struct S3Client {
     pub credentials: Credentials,
     pub default_region: Option<RegionCode>,
     pub bucket: S3Bucket,
+    /// Applied to all the requests unless overridden by `WithOptions`.
+    pub default_options: RequestOptions,
+    /// Filled with the regions told by S3 when `default_region` is wrong for the bucket.
+    pub region_cache: RegionCache,
+    pub retry_config: RetryConfig,
+    /// Shared by the requests in the adaptive retry mode.
+    pub rate_limiter: RateLimiter,
+    config: S3ClientConfig,
+    pub(crate) internal_client: InternalClient,
 }
 
 impl HasBucketScope for &S3Client {
@@ -29,17 +68,94 @@ impl HasBucketScope for &S3Client {
     fn default_region(&self) -> &Option<RegionCode> {
         &self.default_region
     }
+
+    fn default_options(&self) -> &RequestOptions {
+        &self.default_options
+    }
+
+    fn region_cache(&self) -> &RegionCache {
+        &self.region_cache
+    }
+
+    fn retry_config(&self) -> &RetryConfig {
+        &self.retry_config
+    }
+
+    fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    fn config(&self) -> &S3ClientConfig {
+        &self.config
+    }
 }
 
 impl S3Client {
     pub fn from_env(bucket: S3Bucket) -> Result<S3Client> {
+        let config = S3ClientConfigBuilder::from_env()
+            .map_err(ConfigError)?
+            .build();
         Ok(S3Client {
             credentials: Credentials::from_env().map_err(CredentialsError)?,
             default_region: RegionCode::find_from_env().map_err(RegionCodeError)?,
             bucket,
+            default_options: RequestOptions::default(),
+            region_cache: RegionCache::default(),
+            retry_config: RetryConfig::from_env().map_err(RetryConfigError)?,
+            rate_limiter: RateLimiter::default(),
+            internal_client: InternalClient::new(&config).map_err(TransportError)?,
+            config,
+        })
+    }
+
+    pub fn with_default_options(self, default_options: RequestOptions) -> S3Client {
+        S3Client {
+            default_options,
+            ..self
+        }
+    }
+
+    pub fn with_retry_config(self, retry_config: RetryConfig) -> S3Client {
+        S3Client {
+            retry_config,
+            ..self
+        }
+    }
+
+    /// Replaces the connection pool with the one built from the config.
+    pub fn with_config(self, config: S3ClientConfig) -> Result<S3Client> {
+        Ok(S3Client {
+            internal_client: InternalClient::new(&config).map_err(TransportError)?,
+            config,
+            ..self
         })
     }
 
+    pub fn config(&self) -> &S3ClientConfig {
+        &self.config
+    }
+
+    /// Creates a URL to download the object without the credentials until it expires.
+    pub fn presign_get_object(&self, request: &PresignRequest) -> internal::Result<Url> {
+        Presigner::new(&self).presign(Method::GET, request, &HeaderMap::new())
+    }
+
+    /// Creates a URL to upload the object without the credentials until it expires.
+    pub fn presign_put_object(&self, request: &PresignRequest) -> internal::Result<Url> {
+        let headers = HeaderMap::new().push_if_exists(request.content_type())?;
+        Presigner::new(&self).presign(Method::PUT, request, &headers)
+    }
+
+    /// Creates the HTML form to upload the object restricted by the policy.
+    pub fn presign_post(&self, policy: &PostPolicy) -> internal::Result<PostForm> {
+        Presigner::new(&self).presign_post(policy)
+    }
+
+    /// Locates the object without the credentials, which is not readable unless it is public.
+    pub fn object_url(&self, object_key: &str) -> internal::Result<Url> {
+        Presigner::new(&self).object_url(object_key, None)
+    }
+
     pub async fn put_object<A>(&self, request: A) -> actions::Result<put_object::Response>
     where
         A: put_object::Request,
@@ -53,4 +169,395 @@ impl S3Client {
     {
         actions::get_object::Requester::get_object(self, request).await
     }
+
+    pub async fn get_object_attributes<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<get_object_attributes::Response>
+    where
+        A: get_object_attributes::Request,
+    {
+        get_object_attributes::Requester::get_object_attributes(self, request).await
+    }
+
+    pub async fn head_object<A>(&self, request: A) -> actions::Result<head_object::Response>
+    where
+        A: head_object::Request,
+    {
+        head_object::Requester::head_object(self, request).await
+    }
+
+    pub async fn put_bucket_replication<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<put_bucket_replication::Response>
+    where
+        A: put_bucket_replication::Request,
+    {
+        put_bucket_replication::Requester::put_bucket_replication(self, request).await
+    }
+
+    pub async fn get_bucket_replication<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<get_bucket_replication::Response>
+    where
+        A: get_bucket_replication::Request,
+    {
+        get_bucket_replication::Requester::get_bucket_replication(self, request).await
+    }
+
+    pub async fn delete_bucket_replication<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<delete_bucket_replication::Response>
+    where
+        A: delete_bucket_replication::Request,
+    {
+        delete_bucket_replication::Requester::delete_bucket_replication(self, request).await
+    }
+
+    pub async fn put_public_access_block<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<put_public_access_block::Response>
+    where
+        A: put_public_access_block::Request,
+    {
+        put_public_access_block::Requester::put_public_access_block(self, request).await
+    }
+
+    pub async fn get_public_access_block<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<get_public_access_block::Response>
+    where
+        A: get_public_access_block::Request,
+    {
+        get_public_access_block::Requester::get_public_access_block(self, request).await
+    }
+
+    pub async fn delete_public_access_block<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<delete_public_access_block::Response>
+    where
+        A: delete_public_access_block::Request,
+    {
+        delete_public_access_block::Requester::delete_public_access_block(self, request).await
+    }
+
+    pub async fn put_bucket_ownership_controls<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<put_bucket_ownership_controls::Response>
+    where
+        A: put_bucket_ownership_controls::Request,
+    {
+        put_bucket_ownership_controls::Requester::put_bucket_ownership_controls(self, request).await
+    }
+
+    pub async fn get_bucket_ownership_controls<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<get_bucket_ownership_controls::Response>
+    where
+        A: get_bucket_ownership_controls::Request,
+    {
+        get_bucket_ownership_controls::Requester::get_bucket_ownership_controls(self, request).await
+    }
+
+    pub async fn delete_bucket_ownership_controls<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<delete_bucket_ownership_controls::Response>
+    where
+        A: delete_bucket_ownership_controls::Request,
+    {
+        delete_bucket_ownership_controls::Requester::delete_bucket_ownership_controls(self, request)
+            .await
+    }
+
+    pub async fn put_bucket_request_payment<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<put_bucket_request_payment::Response>
+    where
+        A: put_bucket_request_payment::Request,
+    {
+        put_bucket_request_payment::Requester::put_bucket_request_payment(self, request).await
+    }
+
+    pub async fn get_bucket_request_payment<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<get_bucket_request_payment::Response>
+    where
+        A: get_bucket_request_payment::Request,
+    {
+        get_bucket_request_payment::Requester::get_bucket_request_payment(self, request).await
+    }
+
+    pub async fn put_bucket_accelerate_configuration<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<put_bucket_accelerate_configuration::Response>
+    where
+        A: put_bucket_accelerate_configuration::Request,
+    {
+        put_bucket_accelerate_configuration::Requester::put_bucket_accelerate_configuration(
+            self, request,
+        )
+        .await
+    }
+
+    pub async fn get_bucket_accelerate_configuration<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<get_bucket_accelerate_configuration::Response>
+    where
+        A: get_bucket_accelerate_configuration::Request,
+    {
+        get_bucket_accelerate_configuration::Requester::get_bucket_accelerate_configuration(
+            self, request,
+        )
+        .await
+    }
+
+    pub async fn create_multipart_upload<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<create_multipart_upload::Response>
+    where
+        A: create_multipart_upload::Request,
+    {
+        create_multipart_upload::Requester::create_multipart_upload(self, request).await
+    }
+
+    pub async fn upload_part<A>(&self, request: A) -> actions::Result<upload_part::Response>
+    where
+        A: upload_part::Request,
+    {
+        upload_part::Requester::upload_part(self, request).await
+    }
+
+    pub async fn complete_multipart_upload<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<complete_multipart_upload::Response>
+    where
+        A: complete_multipart_upload::Request,
+    {
+        complete_multipart_upload::Requester::complete_multipart_upload(self, request).await
+    }
+
+    pub async fn abort_multipart_upload<A>(
+        &self,
+        request: A,
+    ) -> actions::Result<abort_multipart_upload::Response>
+    where
+        A: abort_multipart_upload::Request,
+    {
+        abort_multipart_upload::Requester::abort_multipart_upload(self, request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_server::{
+        create_client, create_client_at, request_lines, response, serve, serve_after, serve_by,
+    };
+    use super::S3ClientConfig;
+    use crate::actions;
+    use crate::actions::put_object;
+    use crate::core::post::PostPolicy;
+    use crate::core::request::PresignRequest;
+    use crate::core::retry::RetryConfig;
+    use crate::core::S3Bucket;
+    use crate::internal;
+    use plus_aws::auth::v4::chrono::now;
+    use plus_aws::http::request::header::ContentType;
+    use plus_aws::index::RegionCode;
+    use reqwest::StatusCode;
+    use std::io::Write;
+    use std::time::Duration;
+    use tempfile::NamedTempFile;
+    use tokio::runtime::{Builder, Runtime};
+
+    fn assert_shareable<A: Clone + Send + Sync + 'static>() {}
+
+    #[test]
+    fn it_can_be_shared_by_tasks() {
+        assert_shareable::<super::S3Client>();
+    }
+
+    #[test]
+    fn it_can_presign_urls() -> internal::Result<()> {
+        let client = create_client(Some(RegionCode::any("eu-west-1")));
+        let request = PresignRequest::new("a b.txt", Duration::from_secs(300));
+        let url = client.presign_get_object(&request)?;
+        assert!(url.as_str().starts_with(
+            "http://localhost:9000/example/a%20b.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256"
+        ));
+
+        let query = url.query().unwrap();
+        assert!(query.contains("&X-Amz-Credential=AKID%2F"));
+        assert!(query.contains("%2Feu-west-1%2Fs3%2Faws4_request&"));
+        assert!(query.contains("&X-Amz-Expires=300&X-Amz-SignedHeaders=host&"));
+
+        let request = request
+            .with_region(RegionCode::any("ap-northeast-1"))
+            .with_content_type(ContentType::new("text/plain"));
+        let url = client.presign_put_object(&request)?;
+        let query = url.query().unwrap();
+        assert!(query.contains("%2Fap-northeast-1%2F"));
+        assert!(query.contains("&X-Amz-SignedHeaders=content-type%3Bhost&"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_presign_post() -> internal::Result<()> {
+        let client = create_client(Some(RegionCode::any("eu-west-1")));
+        let policy = PostPolicy::new(now() + chrono::Duration::hours(1)).key("a.txt");
+        let form = client.presign_post(&policy)?;
+        assert_eq!(form.url.as_str(), "http://localhost:9000/example");
+        assert_eq!(form.get("key"), Some("a.txt"));
+        assert!(form.get("x-amz-signature").is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn it_cannot_presign_urls_without_region() {
+        let client = create_client(None);
+        let request = PresignRequest::new("a.txt", Duration::from_secs(300));
+        match client.presign_get_object(&request) {
+            Err(internal::Error::RegionNotSpecified) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_returns_s3_error_without_retrying_stream() {
+        let (url, received) = serve("503 Service Unavailable", SLOW_DOWN);
+        let client = create_client_at(url, Some(RegionCode::any("us-east-1")));
+        let request = put_object::StreamRequest::new("a.txt", &b"Hello, World!"[..], 13);
+
+        let mut runtime = create_runtime();
+        let error = runtime.block_on(client.put_object(request)).unwrap_err();
+        let status = error.s3_error().map(|response| response.status());
+        assert_eq!(status, Some(StatusCode::SERVICE_UNAVAILABLE));
+        assert_eq!(
+            request_lines(&received),
+            vec!["HEAD /example HTTP/1.1", "PUT /example/a.txt HTTP/1.1"]
+        );
+    }
+
+    #[test]
+    fn it_retries_file_request_until_attempts_run_out() {
+        let (url, received) = serve("503 Service Unavailable", SLOW_DOWN);
+        let retry_config = RetryConfig {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(1),
+            ..RetryConfig::default()
+        };
+        let client = create_client_at(url, Some(RegionCode::any("us-east-1")))
+            .with_retry_config(retry_config);
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"Hello, World!").unwrap();
+        let request = put_object::FileRequest {
+            file_path: file.path().to_string_lossy().to_string(),
+            object_key: "a.txt".to_string(),
+            content_type: None,
+            region_code: None,
+            checksum_algorithm: None,
+        };
+        let mut runtime = create_runtime();
+        let error = runtime.block_on(client.put_object(request)).unwrap_err();
+        let status = error.s3_error().map(|response| response.status());
+        assert_eq!(status, Some(StatusCode::SERVICE_UNAVAILABLE));
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 4);
+        for request in received.iter() {
+            assert_eq!(request.request_line, "PUT /example/a.txt HTTP/1.1");
+            assert_eq!(request.body, b"Hello, World!");
+        }
+    }
+
+    #[test]
+    fn it_signs_again_for_redirected_region() {
+        let (url, received) = serve_by(|request| {
+            let authorization = request.header("authorization").unwrap_or_default();
+            if authorization.contains("/eu-west-1/s3/aws4_request") {
+                response("200 OK", &["ETag: \"abc\""], "")
+            } else {
+                let headers = ["x-amz-bucket-region: eu-west-1"];
+                response("301 Moved Permanently", &headers, PERMANENT_REDIRECT)
+            }
+        });
+        let client = create_client_at(url, Some(RegionCode::any("us-east-1")));
+        let request = put_object::BytesRequest::new("a.txt", "Hello, World!");
+
+        let mut runtime = create_runtime();
+        runtime.block_on(client.put_object(request)).unwrap();
+        assert_eq!(received.lock().unwrap().len(), 2);
+
+        let cached = client.region_cache.get(&S3Bucket::new("example"));
+        assert_eq!(cached.as_ref().map(|x| x.as_str()), Some("eu-west-1"));
+    }
+
+    #[test]
+    fn it_does_not_retry_access_denied() {
+        let (url, received) = serve("403 Forbidden", ACCESS_DENIED);
+        let client = create_client_at(url, Some(RegionCode::any("us-east-1")));
+        let request = put_object::BytesRequest::new("a.txt", "Hello, World!");
+
+        let mut runtime = create_runtime();
+        let error = runtime.block_on(client.put_object(request)).unwrap_err();
+        let status = error.s3_error().map(|response| response.status());
+        assert_eq!(status, Some(StatusCode::FORBIDDEN));
+        assert_eq!(received.lock().unwrap().len(), 1);
+    }
+
+    const SLOW_DOWN: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+        <Error><Code>SlowDown</Code><Message>Please reduce your request rate.</Message></Error>";
+
+    const PERMANENT_REDIRECT: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+        <Error><Code>PermanentRedirect</Code><Message>Use the specified endpoint.</Message></Error>";
+
+    const ACCESS_DENIED: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+        <Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>";
+
+    #[test]
+    fn it_times_out_waiting_for_response_after_body() {
+        let (url, _) = serve_after(Duration::from_secs(2), "200 OK", "");
+        let config = S3ClientConfig::builder()
+            .endpoint_url(url)
+            .read_timeout(Duration::from_millis(200))
+            .build();
+        let client = create_client(Some(RegionCode::any("us-east-1")))
+            .with_config(config)
+            .unwrap()
+            .with_retry_config(RetryConfig {
+                max_attempts: 1,
+                ..RetryConfig::default()
+            });
+        let request = put_object::BytesRequest::new("a.txt", "Hello, World!");
+
+        let mut runtime = create_runtime();
+        match runtime.block_on(client.put_object(request)) {
+            Err(actions::Error::PutObjectError(put_object::Error::InternalError(
+                internal::Error::ReadTimeout(_),
+            ))) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    fn create_runtime() -> Runtime {
+        Builder::new()
+            .basic_scheduler()
+            .enable_all()
+            .build()
+            .unwrap()
+    }
 }
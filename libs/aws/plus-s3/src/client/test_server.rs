@@ -0,0 +1,141 @@
+use crate::client::{S3Client, S3ClientConfig};
+use crate::core::request::RequestOptions;
+use crate::core::retry::{RateLimiter, RetryConfig};
+use crate::core::{RegionCache, S3Bucket};
+use crate::internal::impl_async::InternalClient;
+use plus_aws::auth::{AccessKey, Credentials, SecretKey};
+use plus_aws::index::RegionCode;
+use reqwest::Url;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// The request which has arrived at the local server.
+#[derive(Debug)]
+pub struct Received {
+    pub request_line: String,
+    /// Lowercased like `x-amz-date: 20200101T000000Z`.
+    pub headers: Vec<String>,
+    pub body: Vec<u8>,
+}
+
+impl Received {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        let prefix = format!("{}:", name);
+        self.headers
+            .iter()
+            .find_map(|line| line.strip_prefix(&prefix))
+            .map(|value| value.trim())
+    }
+}
+
+pub type ReceivedLog = Arc<Mutex<Vec<Received>>>;
+
+pub fn request_lines(received: &ReceivedLog) -> Vec<String> {
+    let received = received.lock().unwrap();
+    received.iter().map(|x| x.request_line.clone()).collect()
+}
+
+/// Answers every request by the status, and keeps the requests.
+pub fn serve(status: &'static str, body: &'static str) -> (Url, ReceivedLog) {
+    serve_after(Duration::from_secs(0), status, body)
+}
+
+pub fn serve_after(
+    delay: Duration,
+    status: &'static str,
+    body: &'static str,
+) -> (Url, ReceivedLog) {
+    serve_by(move |_| {
+        thread::sleep(delay);
+        response(status, &[], body)
+    })
+}
+
+/// Answers each request by the response the function builds from it.
+pub fn serve_by<F>(respond: F) -> (Url, ReceivedLog)
+where
+    F: Fn(&Received) -> String + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+    let received = Arc::new(Mutex::new(vec![]));
+    let shared = received.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let request = read_request(&mut stream);
+            let response = respond(&request);
+            shared.lock().unwrap().push(request);
+
+            // the client may have given up already.
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+    (url, received)
+}
+
+pub fn response(status: &str, headers: &[&str], body: &str) -> String {
+    let headers: String = headers.iter().map(|x| format!("{}\r\n", x)).collect();
+    format!(
+        "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        headers,
+        body.len(),
+        body
+    )
+}
+
+/// Reads the whole request not to reset the connection while it is sent.
+fn read_request(stream: &mut TcpStream) -> Received {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).unwrap();
+
+    let mut headers = vec![];
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        if line.trim_end().is_empty() {
+            break;
+        }
+        headers.push(line.trim_end().to_ascii_lowercase());
+    }
+    let mut received = Received {
+        request_line: request_line.trim_end().to_string(),
+        headers,
+        body: vec![],
+    };
+    let content_length = received
+        .header("content-length")
+        .map_or(0, |x| x.parse().unwrap());
+
+    received.body = vec![0; content_length];
+    reader.read_exact(&mut received.body).unwrap();
+    received
+}
+
+pub fn create_client(default_region: Option<RegionCode>) -> S3Client {
+    create_client_at(Url::parse("http://localhost:9000").unwrap(), default_region)
+}
+
+pub fn create_client_at(endpoint_url: Url, default_region: Option<RegionCode>) -> S3Client {
+    let config = S3ClientConfig::builder().endpoint_url(endpoint_url).build();
+
+    S3Client {
+        credentials: Credentials::builder()
+            .access_key(AccessKey::new("AKID"))
+            .secret_key(SecretKey::new("SECRET"))
+            .build(),
+        default_region,
+        bucket: S3Bucket::new("example"),
+        default_options: RequestOptions::default(),
+        region_cache: RegionCache::default(),
+        retry_config: RetryConfig::default(),
+        rate_limiter: RateLimiter::default(),
+        internal_client: InternalClient::new(&config).unwrap(),
+        config,
+    }
+}
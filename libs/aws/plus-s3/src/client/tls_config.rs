@@ -0,0 +1,16 @@
+use std::path::PathBuf;
+
+#[derive(Clone, Debug)]
+pub struct TlsConfig {
+    pub ca_bundle: Option<PathBuf>,
+    pub verify: bool,
+}
+
+impl Default for TlsConfig {
+    fn default() -> Self {
+        TlsConfig {
+            ca_bundle: None,
+            verify: true,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct S3Bucket(String);
 
 impl S3Bucket {
@@ -0,0 +1,77 @@
+use crate::core;
+use crate::core::checksum::Checksummer;
+use plus_aws::io::stream::DigestAlgorithm;
+use std::str::FromStr;
+
+/// rf.
+/// [Checking object integrity - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChecksumAlgorithm {
+    Crc32,
+    Crc32c,
+    Sha1,
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    pub fn all() -> Vec<ChecksumAlgorithm> {
+        vec![
+            ChecksumAlgorithm::Crc32,
+            ChecksumAlgorithm::Crc32c,
+            ChecksumAlgorithm::Sha1,
+            ChecksumAlgorithm::Sha256,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "CRC32",
+            ChecksumAlgorithm::Crc32c => "CRC32C",
+            ChecksumAlgorithm::Sha1 => "SHA1",
+            ChecksumAlgorithm::Sha256 => "SHA256",
+        }
+    }
+
+    /// Name of the header which carries the checksum value, like `x-amz-checksum-crc32`.
+    pub fn header_name(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "x-amz-checksum-crc32",
+            ChecksumAlgorithm::Crc32c => "x-amz-checksum-crc32c",
+            ChecksumAlgorithm::Sha1 => "x-amz-checksum-sha1",
+            ChecksumAlgorithm::Sha256 => "x-amz-checksum-sha256",
+        }
+    }
+
+    /// Length of the base64-encoded value, known before the payload is read.
+    pub fn value_length(&self) -> usize {
+        match self {
+            ChecksumAlgorithm::Crc32 | ChecksumAlgorithm::Crc32c => 8,
+            ChecksumAlgorithm::Sha1 => 28,
+            ChecksumAlgorithm::Sha256 => 44,
+        }
+    }
+
+    pub fn digest_algorithm(&self) -> DigestAlgorithm {
+        match self {
+            ChecksumAlgorithm::Crc32 => DigestAlgorithm::Crc32,
+            ChecksumAlgorithm::Crc32c => DigestAlgorithm::Crc32c,
+            ChecksumAlgorithm::Sha1 => DigestAlgorithm::Sha1,
+            ChecksumAlgorithm::Sha256 => DigestAlgorithm::Sha256,
+        }
+    }
+
+    pub fn checksummer(&self) -> Checksummer {
+        Checksummer::new(*self)
+    }
+}
+
+impl FromStr for ChecksumAlgorithm {
+    type Err = core::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ChecksumAlgorithm::all()
+            .into_iter()
+            .find(|algorithm| algorithm.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| core::Error::UnknownChecksumAlgorithm(s.to_string()))
+    }
+}
@@ -0,0 +1,54 @@
+use crate::core::checksum::{Checksum, ChecksumAlgorithm};
+use plus_aws::io::stream::MultiDigester;
+
+/// Calculates checksums incrementally.
+pub struct Checksummer {
+    algorithm: ChecksumAlgorithm,
+    digester: MultiDigester,
+}
+
+impl Checksummer {
+    pub fn new(algorithm: ChecksumAlgorithm) -> Self {
+        Checksummer {
+            algorithm,
+            digester: MultiDigester::new(&[algorithm.digest_algorithm()]),
+        }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.digester.update(bytes);
+    }
+
+    pub fn finish(self) -> Checksum {
+        let digests = self.digester.finish();
+        Checksum::from_digests(self.algorithm, &digests).expect("the algorithm is requested")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::checksum::ChecksumAlgorithm;
+
+    fn calculate(algorithm: ChecksumAlgorithm, bytes: &[u8]) -> String {
+        let mut checksummer = algorithm.checksummer();
+        let (head, tail) = bytes.split_at(5);
+        checksummer.update(head);
+        checksummer.update(tail);
+        checksummer.finish().value
+    }
+
+    #[test]
+    fn it_can_calculate_checksums() {
+        let bytes = b"Hello, World!";
+        assert_eq!(calculate(ChecksumAlgorithm::Crc32, bytes), "7ErD0A==");
+        assert_eq!(calculate(ChecksumAlgorithm::Crc32c, bytes), "TVUQaA==");
+        assert_eq!(
+            calculate(ChecksumAlgorithm::Sha1, bytes),
+            "CgqfKmdylCVXq1NV12r0Qvj2XgE="
+        );
+        assert_eq!(
+            calculate(ChecksumAlgorithm::Sha256, bytes),
+            "3/1gIbsr1bCvZ2KQgJ7DpTGR3YHH9wpLKGiKNiGCmG8="
+        );
+    }
+}
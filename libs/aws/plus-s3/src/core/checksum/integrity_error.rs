@@ -0,0 +1,16 @@
+/// Indicates that the received bytes are not the same as the stored object.
+#[derive(Fail, Debug)]
+pub enum IntegrityError {
+    #[fail(
+        display = "ChecksumMismatch > algorithm: {}, expected: {}, actual: {}",
+        algorithm, expected, actual
+    )]
+    ChecksumMismatch {
+        algorithm: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[fail(display = "Md5Mismatch > expected: {}, actual: {}", expected, actual)]
+    Md5Mismatch { expected: String, actual: String },
+}
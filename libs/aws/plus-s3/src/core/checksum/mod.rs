@@ -0,0 +1,20 @@
+mod algorithm;
+pub use algorithm::ChecksumAlgorithm;
+
+mod checksummer;
+pub use checksummer::Checksummer;
+
+mod integrity_error;
+pub use integrity_error::IntegrityError;
+
+mod mode;
+pub use mode::ChecksumMode;
+
+mod value;
+pub use value::Checksum;
+
+mod verifier;
+pub use verifier::{ChecksumVerifier, Md5Verifier, Verifier};
+
+mod verify;
+pub use verify::verify;
@@ -0,0 +1,24 @@
+use plus_aws::http::request::{HeaderFragment, ToHeaderFragment};
+
+/// To retrieve the checksum, `ChecksumMode` must be enabled.
+///
+/// rf.
+/// [GetObject - Request Syntax](https://docs.aws.amazon.com/AmazonS3/latest/API/API_GetObject.html#API_GetObject_RequestSyntax)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChecksumMode {
+    Enabled,
+}
+
+impl ChecksumMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ChecksumMode::Enabled => "ENABLED",
+        }
+    }
+}
+
+impl ToHeaderFragment for &ChecksumMode {
+    fn into(self) -> plus_aws::Result<HeaderFragment> {
+        ToHeaderFragment::into(("x-amz-checksum-mode", self.as_str()))
+    }
+}
@@ -0,0 +1,61 @@
+use crate::core;
+use crate::core::checksum::ChecksumAlgorithm;
+use crate::core::response::headers;
+use plus_aws::http::request::{HeaderFragment, ToHeaderFragment};
+use plus_aws::io::stream::Digests;
+use reqwest::header::HeaderMap;
+
+/// Base64-encoded checksum value of the specified algorithm.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Checksum {
+    pub algorithm: ChecksumAlgorithm,
+    pub value: String,
+}
+
+impl Checksum {
+    pub fn new<A: Into<String>>(algorithm: ChecksumAlgorithm, value: A) -> Self {
+        Checksum {
+            algorithm,
+            value: value.into(),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns `None` unless the digest of the algorithm was requested.
+    pub fn from_digests(algorithm: ChecksumAlgorithm, digests: &Digests) -> Option<Checksum> {
+        let value = digests.get(algorithm.digest_algorithm())?;
+        Some(Checksum::new(algorithm, value.to_base64()))
+    }
+
+    /// Checksum of multipart objects is calculated from the checksums of each part
+    /// and has the suffix which indicates the number of parts, like `-3`.
+    pub fn is_composite(&self) -> bool {
+        self.value.contains('-')
+    }
+
+    /// Finds the first `x-amz-checksum-*` header in the given map.
+    pub fn find_in(map: &HeaderMap) -> core::Result<Option<Checksum>> {
+        for algorithm in ChecksumAlgorithm::all() {
+            let name = algorithm.header_name();
+            if let Some(value) = map.get(name) {
+                let value = value
+                    .to_str()
+                    .map_err(|cause| headers::Error::InvalidCharacters {
+                        name: name.into(),
+                        cause,
+                    })?;
+                return Ok(Some(Checksum::new(algorithm, value)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl ToHeaderFragment for &Checksum {
+    fn into(self) -> plus_aws::Result<HeaderFragment> {
+        ToHeaderFragment::into((self.algorithm.header_name(), self.as_str()))
+    }
+}
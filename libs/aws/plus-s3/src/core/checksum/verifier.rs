@@ -0,0 +1,75 @@
+use crate::core::checksum::{Checksum, Checksummer, IntegrityError};
+use hex::ToHex;
+use md5::{Digest, Md5};
+
+/// Inspects received bytes incrementally, and judges them at the end.
+pub trait Verifier: Send {
+    fn update(&mut self, bytes: &[u8]);
+    fn verify(self: Box<Self>) -> Result<(), IntegrityError>;
+}
+
+pub struct ChecksumVerifier {
+    checksummer: Checksummer,
+    expected: Checksum,
+}
+
+impl ChecksumVerifier {
+    pub fn new(expected: Checksum) -> Self {
+        ChecksumVerifier {
+            checksummer: expected.algorithm.checksummer(),
+            expected,
+        }
+    }
+}
+
+impl Verifier for ChecksumVerifier {
+    fn update(&mut self, bytes: &[u8]) {
+        self.checksummer.update(bytes)
+    }
+
+    fn verify(self: Box<Self>) -> Result<(), IntegrityError> {
+        let actual = self.checksummer.finish();
+        if actual == self.expected {
+            Ok(())
+        } else {
+            Err(IntegrityError::ChecksumMismatch {
+                algorithm: self.expected.algorithm.as_str().to_string(),
+                expected: self.expected.value,
+                actual: actual.value,
+            })
+        }
+    }
+}
+
+/// Compares the MD5 digest with the hex string, such as a plain ETag.
+pub struct Md5Verifier {
+    md5: Md5,
+    expected: String,
+}
+
+impl Md5Verifier {
+    pub fn new<A: Into<String>>(expected: A) -> Self {
+        Md5Verifier {
+            md5: Md5::default(),
+            expected: expected.into(),
+        }
+    }
+}
+
+impl Verifier for Md5Verifier {
+    fn update(&mut self, bytes: &[u8]) {
+        self.md5.input(bytes)
+    }
+
+    fn verify(self: Box<Self>) -> Result<(), IntegrityError> {
+        let actual: String = self.md5.result().as_slice().encode_hex();
+        if actual.eq_ignore_ascii_case(&self.expected) {
+            Ok(())
+        } else {
+            Err(IntegrityError::Md5Mismatch {
+                expected: self.expected,
+                actual,
+            })
+        }
+    }
+}
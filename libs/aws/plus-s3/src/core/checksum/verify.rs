@@ -0,0 +1,88 @@
+use crate::core;
+use crate::core::checksum::Verifier;
+use bytes::Bytes;
+use futures_util::stream::{unfold, Stream, StreamExt};
+
+/// Passes through the given stream, and yields an error at the end of it
+/// if the received bytes are rejected by any of the verifiers.
+pub fn verify<S, E>(
+    stream: S,
+    verifiers: Vec<Box<dyn Verifier>>,
+) -> impl Stream<Item = Result<Bytes, E>>
+where
+    S: Stream<Item = Result<Bytes, E>>,
+    E: From<core::Error>,
+{
+    let state = (Box::pin(stream), Some(verifiers));
+
+    unfold(state, |(mut stream, verifiers)| async move {
+        let mut verifiers = verifiers?;
+        match stream.next().await {
+            Some(Ok(bytes)) => {
+                verifiers.iter_mut().for_each(|v| v.update(&bytes));
+                Some((Ok(bytes), (stream, Some(verifiers))))
+            }
+            Some(Err(e)) => Some((Err(e), (stream, None))),
+            None => {
+                let result = verifiers.into_iter().try_for_each(|v| v.verify());
+                match result {
+                    Ok(_) => None,
+                    Err(e) => Some((Err(E::from(e.into())), (stream, None))),
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify;
+    use crate::core;
+    use crate::core::checksum::{
+        Checksum, ChecksumAlgorithm, ChecksumVerifier, IntegrityError, Md5Verifier, Verifier,
+    };
+    use bytes::Bytes;
+    use futures::executor::block_on;
+    use futures_util::stream::{iter, TryStreamExt};
+
+    fn receive(verifiers: Vec<Box<dyn Verifier>>) -> core::Result<Vec<Bytes>> {
+        let chunks: Vec<core::Result<Bytes>> =
+            vec![Ok(Bytes::from("Hello, ")), Ok(Bytes::from("World!"))];
+        block_on(verify(iter(chunks), verifiers).try_collect())
+    }
+
+    #[test]
+    fn it_passes_matched_bytes() -> core::Result<()> {
+        let received = receive(vec![
+            Box::new(ChecksumVerifier::new(Checksum::new(
+                ChecksumAlgorithm::Crc32,
+                "7ErD0A==",
+            ))),
+            Box::new(Md5Verifier::new("65a8e27d8879283831b664bd8b7f0ad4")),
+        ])?;
+        assert_eq!(received.concat(), b"Hello, World!");
+        Ok(())
+    }
+
+    #[test]
+    fn it_fails_on_checksum_mismatch() {
+        let expected = Checksum::new(ChecksumAlgorithm::Crc32, "AAAAAA==");
+        match receive(vec![Box::new(ChecksumVerifier::new(expected))]) {
+            Err(core::Error::IntegrityError(IntegrityError::ChecksumMismatch {
+                actual, ..
+            })) => assert_eq!(actual, "7ErD0A=="),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_fails_on_md5_mismatch() {
+        let expected = "00000000000000000000000000000000";
+        match receive(vec![Box::new(Md5Verifier::new(expected))]) {
+            Err(core::Error::IntegrityError(IntegrityError::Md5Mismatch { actual, .. })) => {
+                assert_eq!(actual, "65a8e27d8879283831b664bd8b7f0ad4")
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
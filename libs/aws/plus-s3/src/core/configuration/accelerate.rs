@@ -0,0 +1,62 @@
+/// rf.
+/// [AccelerateConfiguration - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_AccelerateConfiguration.html)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccelerateConfiguration {
+    /// Not returned by `GetBucketAccelerateConfiguration` if never configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<BucketAccelerateStatus>,
+}
+
+impl AccelerateConfiguration {
+    pub fn new(status: BucketAccelerateStatus) -> Self {
+        AccelerateConfiguration {
+            status: Some(status),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum BucketAccelerateStatus {
+    Enabled,
+    Suspended,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccelerateConfiguration, BucketAccelerateStatus};
+    use crate::core::xml;
+
+    #[test]
+    fn it_can_be_serialized() -> crate::core::Result<()> {
+        let configuration = AccelerateConfiguration::new(BucketAccelerateStatus::Enabled);
+        assert_eq!(
+            xml::to_string(&configuration)?,
+            "<AccelerateConfiguration><Status>Enabled</Status></AccelerateConfiguration>"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_be_deserialized() -> crate::core::Result<()> {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <AccelerateConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <Status>Suspended</Status>
+            </AccelerateConfiguration>"#;
+
+        let configuration: AccelerateConfiguration = xml::from_str(text)?;
+        assert_eq!(
+            configuration.status,
+            Some(BucketAccelerateStatus::Suspended)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_be_deserialized_without_status() -> crate::core::Result<()> {
+        let text = r#"<AccelerateConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/"/>"#;
+        let configuration: AccelerateConfiguration = xml::from_str(text)?;
+        assert_eq!(configuration.status, None);
+        Ok(())
+    }
+}
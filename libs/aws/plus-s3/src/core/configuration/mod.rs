@@ -0,0 +1,12 @@
+pub mod accelerate;
+pub mod ownership_controls;
+pub mod public_access_block;
+pub mod replication;
+pub mod request_payment;
+
+/// A switch used by many bucket configurations.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Status {
+    Enabled,
+    Disabled,
+}
@@ -0,0 +1,93 @@
+use crate::core;
+use std::str::FromStr;
+
+/// rf.
+/// [OwnershipControls - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_OwnershipControls.html)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OwnershipControls {
+    #[serde(rename = "Rule", default)]
+    pub rules: Vec<OwnershipControlsRule>,
+}
+
+impl OwnershipControls {
+    pub fn new(object_ownership: ObjectOwnership) -> Self {
+        OwnershipControls {
+            rules: vec![OwnershipControlsRule { object_ownership }],
+        }
+    }
+
+    /// ACLs are disabled, and the bucket owner owns all the objects.
+    pub fn bucket_owner_enforced() -> Self {
+        Self::new(ObjectOwnership::BucketOwnerEnforced)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OwnershipControlsRule {
+    pub object_ownership: ObjectOwnership,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ObjectOwnership {
+    BucketOwnerEnforced,
+    BucketOwnerPreferred,
+    ObjectWriter,
+}
+
+impl ObjectOwnership {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ObjectOwnership::BucketOwnerEnforced => "BucketOwnerEnforced",
+            ObjectOwnership::BucketOwnerPreferred => "BucketOwnerPreferred",
+            ObjectOwnership::ObjectWriter => "ObjectWriter",
+        }
+    }
+}
+
+impl FromStr for ObjectOwnership {
+    type Err = core::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "BucketOwnerEnforced" => Ok(ObjectOwnership::BucketOwnerEnforced),
+            "BucketOwnerPreferred" => Ok(ObjectOwnership::BucketOwnerPreferred),
+            "ObjectWriter" => Ok(ObjectOwnership::ObjectWriter),
+            _ => Err(core::Error::UnknownObjectOwnership(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ObjectOwnership, OwnershipControls};
+    use crate::core::xml;
+
+    #[test]
+    fn it_can_be_serialized() -> crate::core::Result<()> {
+        assert_eq!(
+            xml::to_string(&OwnershipControls::bucket_owner_enforced())?,
+            concat!(
+                "<OwnershipControls>",
+                "<Rule><ObjectOwnership>BucketOwnerEnforced</ObjectOwnership></Rule>",
+                "</OwnershipControls>",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_be_deserialized() -> crate::core::Result<()> {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <OwnershipControls xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <Rule><ObjectOwnership>ObjectWriter</ObjectOwnership></Rule>
+            </OwnershipControls>"#;
+
+        let controls: OwnershipControls = xml::from_str(text)?;
+        assert_eq!(
+            controls.rules[0].object_ownership,
+            ObjectOwnership::ObjectWriter
+        );
+        Ok(())
+    }
+}
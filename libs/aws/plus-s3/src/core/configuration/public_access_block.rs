@@ -0,0 +1,70 @@
+/// rf.
+/// [PublicAccessBlockConfiguration - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_PublicAccessBlockConfiguration.html)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PublicAccessBlockConfiguration {
+    #[serde(default)]
+    pub block_public_acls: bool,
+
+    #[serde(default)]
+    pub ignore_public_acls: bool,
+
+    #[serde(default)]
+    pub block_public_policy: bool,
+
+    #[serde(default)]
+    pub restrict_public_buckets: bool,
+}
+
+impl PublicAccessBlockConfiguration {
+    /// Blocks all kinds of public access.
+    pub fn block_all() -> Self {
+        PublicAccessBlockConfiguration {
+            block_public_acls: true,
+            ignore_public_acls: true,
+            block_public_policy: true,
+            restrict_public_buckets: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PublicAccessBlockConfiguration;
+    use crate::core::xml;
+
+    #[test]
+    fn it_can_be_serialized() -> crate::core::Result<()> {
+        let configuration = PublicAccessBlockConfiguration {
+            restrict_public_buckets: false,
+            ..PublicAccessBlockConfiguration::block_all()
+        };
+        assert_eq!(
+            xml::to_string(&configuration)?,
+            concat!(
+                "<PublicAccessBlockConfiguration>",
+                "<BlockPublicAcls>true</BlockPublicAcls>",
+                "<IgnorePublicAcls>true</IgnorePublicAcls>",
+                "<BlockPublicPolicy>true</BlockPublicPolicy>",
+                "<RestrictPublicBuckets>false</RestrictPublicBuckets>",
+                "</PublicAccessBlockConfiguration>",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_be_deserialized() -> crate::core::Result<()> {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <PublicAccessBlockConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <BlockPublicAcls>true</BlockPublicAcls>
+              <IgnorePublicAcls>true</IgnorePublicAcls>
+              <BlockPublicPolicy>true</BlockPublicPolicy>
+              <RestrictPublicBuckets>true</RestrictPublicBuckets>
+            </PublicAccessBlockConfiguration>"#;
+
+        let configuration: PublicAccessBlockConfiguration = xml::from_str(text)?;
+        assert_eq!(configuration, PublicAccessBlockConfiguration::block_all());
+        Ok(())
+    }
+}
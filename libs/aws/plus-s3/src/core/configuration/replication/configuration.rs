@@ -0,0 +1,11 @@
+use crate::core::configuration::replication::ReplicationRule;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplicationConfiguration {
+    /// ARN of the IAM role that Amazon S3 assumes when replicating objects.
+    pub role: String,
+
+    #[serde(rename = "Rule", default)]
+    pub rules: Vec<ReplicationRule>,
+}
@@ -0,0 +1,85 @@
+use crate::core::configuration::Status;
+use crate::core::StorageClass;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Destination {
+    /// ARN of the bucket where replicas are stored.
+    pub bucket: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_class: Option<StorageClass>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_control_translation: Option<AccessControlTranslation>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_configuration: Option<EncryptionConfiguration>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replication_time: Option<ReplicationTime>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<Metrics>,
+}
+
+impl Destination {
+    pub fn new<A: Into<String>>(bucket_arn: A) -> Self {
+        Destination {
+            bucket: bucket_arn.into(),
+            account: None,
+            storage_class: None,
+            access_control_translation: None,
+            encryption_configuration: None,
+            replication_time: None,
+            metrics: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AccessControlTranslation {
+    /// The only valid value is `Destination`.
+    pub owner: String,
+}
+
+impl AccessControlTranslation {
+    pub fn destination() -> Self {
+        AccessControlTranslation {
+            owner: "Destination".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EncryptionConfiguration {
+    #[serde(rename = "ReplicaKmsKeyID")]
+    pub replica_kms_key_id: String,
+}
+
+/// S3 Replication Time Control (S3 RTC).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplicationTime {
+    pub status: Status,
+    pub time: ReplicationTimeValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplicationTimeValue {
+    pub minutes: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Metrics {
+    pub status: Status,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_threshold: Option<ReplicationTimeValue>,
+}
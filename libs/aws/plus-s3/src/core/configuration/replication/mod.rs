@@ -0,0 +1,125 @@
+/*
+    see also:
+    https://docs.aws.amazon.com/AmazonS3/latest/API/API_ReplicationConfiguration.html
+*/
+
+mod configuration;
+pub use configuration::ReplicationConfiguration;
+
+mod destination;
+pub use destination::{
+    AccessControlTranslation, Destination, EncryptionConfiguration, Metrics, ReplicationTime,
+    ReplicationTimeValue,
+};
+
+mod rule;
+pub use rule::{
+    DeleteMarkerReplication, ExistingObjectReplication, ReplicaModifications, ReplicationFilter,
+    ReplicationFilterAnd, ReplicationRule, SourceSelectionCriteria, SseKmsEncryptedObjects, Tag,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::configuration::Status;
+    use crate::core::{xml, StorageClass};
+
+    #[test]
+    fn it_can_be_serialized() -> crate::core::Result<()> {
+        let configuration = ReplicationConfiguration {
+            role: "arn:aws:iam::123456789012:role/replication".to_string(),
+            rules: vec![ReplicationRule {
+                id: Some("rule-1".to_string()),
+                priority: Some(1),
+                filter: Some(ReplicationFilter::prefix("logs/")),
+                delete_marker_replication: Some(DeleteMarkerReplication {
+                    status: Status::Enabled,
+                }),
+                ..ReplicationRule::new(Destination {
+                    storage_class: Some(StorageClass::StandardIa),
+                    ..Destination::new("arn:aws:s3:::destination-bucket")
+                })
+            }],
+        };
+        assert_eq!(
+            xml::to_string(&configuration)?,
+            concat!(
+                "<ReplicationConfiguration>",
+                "<Role>arn:aws:iam::123456789012:role/replication</Role>",
+                "<Rule>",
+                "<ID>rule-1</ID>",
+                "<Priority>1</Priority>",
+                "<Status>Enabled</Status>",
+                "<Filter><Prefix>logs/</Prefix></Filter>",
+                "<Destination>",
+                "<Bucket>arn:aws:s3:::destination-bucket</Bucket>",
+                "<StorageClass>STANDARD_IA</StorageClass>",
+                "</Destination>",
+                "<DeleteMarkerReplication><Status>Enabled</Status></DeleteMarkerReplication>",
+                "</Rule>",
+                "</ReplicationConfiguration>",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_be_deserialized() -> crate::core::Result<()> {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ReplicationConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <Role>arn:aws:iam::123456789012:role/replication</Role>
+              <Rule>
+                <ID>rule-1</ID>
+                <Priority>2</Priority>
+                <Status>Enabled</Status>
+                <Filter>
+                  <And>
+                    <Prefix>logs/</Prefix>
+                    <Tag><Key>k1</Key><Value>v1</Value></Tag>
+                    <Tag><Key>k2</Key><Value>v2</Value></Tag>
+                  </And>
+                </Filter>
+                <SourceSelectionCriteria>
+                  <SseKmsEncryptedObjects><Status>Enabled</Status></SseKmsEncryptedObjects>
+                </SourceSelectionCriteria>
+                <Destination>
+                  <Bucket>arn:aws:s3:::destination-bucket</Bucket>
+                  <EncryptionConfiguration>
+                    <ReplicaKmsKeyID>arn:aws:kms:us-east-1:123456789012:key/abc</ReplicaKmsKeyID>
+                  </EncryptionConfiguration>
+                  <ReplicationTime>
+                    <Status>Enabled</Status>
+                    <Time><Minutes>15</Minutes></Time>
+                  </ReplicationTime>
+                  <Metrics>
+                    <Status>Enabled</Status>
+                    <EventThreshold><Minutes>15</Minutes></EventThreshold>
+                  </Metrics>
+                </Destination>
+                <DeleteMarkerReplication><Status>Disabled</Status></DeleteMarkerReplication>
+              </Rule>
+            </ReplicationConfiguration>"#;
+
+        let configuration: ReplicationConfiguration = xml::from_str(text)?;
+        let rule = &configuration.rules[0];
+        let filter = rule.filter.as_ref().unwrap().and.as_ref().unwrap();
+        assert_eq!(filter.prefix.as_deref(), Some("logs/"));
+        assert_eq!(filter.tags.len(), 2);
+
+        let criteria = rule.source_selection_criteria.as_ref().unwrap();
+        assert_eq!(
+            criteria.sse_kms_encrypted_objects.as_ref().unwrap().status,
+            Status::Enabled
+        );
+        let destination = &rule.destination;
+        assert_eq!(
+            destination.replication_time.as_ref().unwrap().time.minutes,
+            15
+        );
+        assert_eq!(
+            rule.delete_marker_replication.as_ref().unwrap().status,
+            Status::Disabled
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use crate::core::configuration::replication::Destination;
+use crate::core::configuration::Status;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplicationRule {
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u32>,
+
+    /// Only used by the earlier version of the replication configuration,
+    /// `filter` should be used instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+
+    pub status: Status,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ReplicationFilter>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_selection_criteria: Option<SourceSelectionCriteria>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub existing_object_replication: Option<ExistingObjectReplication>,
+
+    pub destination: Destination,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_marker_replication: Option<DeleteMarkerReplication>,
+}
+
+impl ReplicationRule {
+    /// Creates an enabled rule which replicates all objects to the destination.
+    pub fn new(destination: Destination) -> Self {
+        ReplicationRule {
+            id: None,
+            priority: None,
+            prefix: None,
+            status: Status::Enabled,
+            filter: None,
+            source_selection_criteria: None,
+            existing_object_replication: None,
+            destination,
+            delete_marker_replication: None,
+        }
+    }
+}
+
+/// Exactly one of `prefix`, `tag` and `and` is expected to be specified.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplicationFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<Tag>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub and: Option<ReplicationFilterAnd>,
+}
+
+impl ReplicationFilter {
+    pub fn prefix<A: Into<String>>(prefix: A) -> Self {
+        ReplicationFilter {
+            prefix: Some(prefix.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn tag(tag: Tag) -> Self {
+        ReplicationFilter {
+            tag: Some(tag),
+            ..Default::default()
+        }
+    }
+
+    pub fn and(and: ReplicationFilterAnd) -> Self {
+        ReplicationFilter {
+            and: Some(and),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplicationFilterAnd {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+
+    #[serde(rename = "Tag", default)]
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Tag {
+    pub key: String,
+    pub value: String,
+}
+
+impl Tag {
+    pub fn new<A: Into<String>, B: Into<String>>(key: A, value: B) -> Self {
+        Tag {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SourceSelectionCriteria {
+    /// Selects objects encrypted with SSE-KMS as the replication source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sse_kms_encrypted_objects: Option<SseKmsEncryptedObjects>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replica_modifications: Option<ReplicaModifications>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SseKmsEncryptedObjects {
+    pub status: Status,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplicaModifications {
+    pub status: Status,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExistingObjectReplication {
+    pub status: Status,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteMarkerReplication {
+    pub status: Status,
+}
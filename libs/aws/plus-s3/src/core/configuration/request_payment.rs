@@ -0,0 +1,48 @@
+/// rf.
+/// [RequestPaymentConfiguration - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_RequestPaymentConfiguration.html)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RequestPaymentConfiguration {
+    pub payer: Payer,
+}
+
+impl RequestPaymentConfiguration {
+    pub fn new(payer: Payer) -> Self {
+        RequestPaymentConfiguration { payer }
+    }
+}
+
+/// Specifies who pays for the download and request fees.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Payer {
+    Requester,
+    BucketOwner,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Payer, RequestPaymentConfiguration};
+    use crate::core::xml;
+
+    #[test]
+    fn it_can_be_serialized() -> crate::core::Result<()> {
+        let configuration = RequestPaymentConfiguration::new(Payer::Requester);
+        assert_eq!(
+            xml::to_string(&configuration)?,
+            "<RequestPaymentConfiguration><Payer>Requester</Payer></RequestPaymentConfiguration>"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_can_be_deserialized() -> crate::core::Result<()> {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <RequestPaymentConfiguration xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <Payer>BucketOwner</Payer>
+            </RequestPaymentConfiguration>"#;
+
+        let configuration: RequestPaymentConfiguration = xml::from_str(text)?;
+        assert_eq!(configuration.payer, Payer::BucketOwner);
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::core;
+use std::str::FromStr;
+
+/// rf.
+/// [Virtual hosting of buckets - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/userguide/VirtualHosting.html)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AddressingStyle {
+    /// Path-style for custom endpoints and for bucket names unusable as a host name,
+    /// virtual-hosted-style otherwise.
+    #[default]
+    Auto,
+
+    /// `https://{bucket}.{host}/{key}`
+    Virtual,
+
+    /// `https://{host}/{bucket}/{key}`
+    Path,
+}
+
+impl FromStr for AddressingStyle {
+    type Err = core::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(AddressingStyle::Auto),
+            "virtual" => Ok(AddressingStyle::Virtual),
+            "path" => Ok(AddressingStyle::Path),
+            _ => Err(core::Error::UnknownAddressingStyle(s.to_string())),
+        }
+    }
+}
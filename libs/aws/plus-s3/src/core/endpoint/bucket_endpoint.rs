@@ -0,0 +1,367 @@
+use crate::core;
+use crate::core::endpoint::{AddressingStyle, EndpointVariants, S3Endpoint};
+use crate::core::S3Bucket;
+use plus_aws::auth::v4::canonical::uri_encode;
+use plus_aws::index::{Partition, RegionCode};
+use reqwest::Url;
+
+/// Resolves the URL of the objects in the bucket.
+#[derive(Clone, Copy, Debug)]
+pub struct BucketEndpoint<'a> {
+    pub endpoint: &'a S3Endpoint,
+    pub variants: EndpointVariants,
+    pub addressing_style: AddressingStyle,
+    pub bucket: &'a S3Bucket,
+    pub region: &'a RegionCode,
+}
+
+impl BucketEndpoint<'_> {
+    /// Fails for the keys with `.` or `..` segments,
+    /// which would be removed from the URL path and point to another object.
+    pub fn to_url(&self, object_key: &str) -> core::Result<Url> {
+        self.validate()?;
+        if object_key.split('/').any(|x| x == "." || x == "..") {
+            return Err(core::Error::InvalidObjectKey(object_key.to_string()));
+        }
+        let base = self.endpoint.base_url(self.region, self.variants)?;
+
+        // encoded in the same way as S3 builds the canonical URI to verify the signature.
+        let object_key = uri_encode(object_key, false);
+        let (mut url, path) = if self.is_path_style(&base) {
+            let url = self
+                .endpoint
+                .path_style_base_url(self.region, self.variants)?;
+            let path = match object_key.as_str() {
+                "" => format!("/{}", self.bucket.as_str()),
+                _ => format!("/{}/{}", self.bucket.as_str(), object_key),
+            };
+            (url, path)
+        } else {
+            let mut url = base;
+            let host = url
+                .host_str()
+                .ok_or_else(|| core::Error::HostNotFound(url.clone()))?;
+            let host = format!("{}.{}", self.bucket.as_str(), host);
+            url.set_host(Some(&host))?;
+            (url, format!("/{}", object_key))
+        };
+        url.set_path(&path);
+        Ok(url)
+    }
+
+    /// Used for the requests which must not be accelerated,
+    /// like the ones to configure the acceleration itself.
+    pub fn without_accelerate(self) -> Self {
+        BucketEndpoint {
+            variants: EndpointVariants {
+                accelerate: false,
+                ..self.variants
+            },
+            ..self
+        }
+    }
+
+    fn validate(&self) -> core::Result<()> {
+        let invalid = |reason: &str| Err(core::Error::InvalidEndpointConfiguration(reason.into()));
+        if self.endpoint.is_custom() && !self.variants.is_empty() {
+            return invalid("custom endpoint cannot be used with dual-stack, FIPS or accelerate");
+        }
+        if self.endpoint.is_custom() {
+            return Ok(());
+        }
+        let partition = self.region.partition();
+        if self.variants.dualstack && !partition.supports_dualstack() {
+            return invalid(&format!(
+                "dual-stack is not available in {}",
+                partition.as_str()
+            ));
+        }
+        if self.variants.fips && !partition.supports_fips() {
+            return invalid(&format!("FIPS is not available in {}", partition.as_str()));
+        }
+        if !self.variants.accelerate {
+            return Ok(());
+        }
+        if self.variants.fips {
+            return invalid("accelerate cannot be used with FIPS");
+        }
+        if partition != Partition::Aws {
+            return invalid(&format!(
+                "accelerate is not available in {}",
+                partition.as_str()
+            ));
+        }
+        if self.addressing_style == AddressingStyle::Path {
+            return invalid("accelerate cannot be used with path-style addressing");
+        }
+        if !is_dns_compatible(self.bucket, "https") {
+            return invalid("accelerate cannot be used with bucket names unusable as a host name");
+        }
+        Ok(())
+    }
+
+    fn is_path_style(&self, base: &Url) -> bool {
+        if self.variants.accelerate {
+            return false;
+        }
+        match self.addressing_style {
+            AddressingStyle::Virtual => false,
+            AddressingStyle::Path => true,
+            AddressingStyle::Auto => {
+                self.endpoint.is_custom() || !is_dns_compatible(self.bucket, base.scheme())
+            }
+        }
+    }
+}
+
+/// rf.
+/// [Bucket naming rules - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/userguide/bucketnamingrules.html)
+fn is_dns_compatible(bucket: &S3Bucket, scheme: &str) -> bool {
+    let name = bucket.as_str();
+    let valid_chars = name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.');
+
+    // the wildcard certificate does not match the names with dots.
+    let has_dots = name.contains('.');
+    (3..=63).contains(&name.len())
+        && valid_chars
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+        && !(has_dots && scheme == "https")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BucketEndpoint;
+    use crate::core;
+    use crate::core::endpoint::{AddressingStyle, EndpointVariants, S3Endpoint};
+    use crate::core::S3Bucket;
+    use plus_aws::auth::v4::canonical::CanonicalUri;
+    use plus_aws::index::RegionCode;
+    use reqwest::Url;
+
+    fn to_url(
+        endpoint: S3Endpoint,
+        style: AddressingStyle,
+        bucket: &str,
+        key: &str,
+    ) -> core::Result<String> {
+        to_url_with(endpoint, EndpointVariants::default(), style, bucket, key)
+    }
+
+    fn to_url_with(
+        endpoint: S3Endpoint,
+        variants: EndpointVariants,
+        style: AddressingStyle,
+        bucket: &str,
+        key: &str,
+    ) -> core::Result<String> {
+        to_url_in("eu-west-1", endpoint, variants, style, bucket, key)
+    }
+
+    fn to_url_in(
+        region: &str,
+        endpoint: S3Endpoint,
+        variants: EndpointVariants,
+        style: AddressingStyle,
+        bucket: &str,
+        key: &str,
+    ) -> core::Result<String> {
+        let endpoint = BucketEndpoint {
+            endpoint: &endpoint,
+            variants,
+            addressing_style: style,
+            bucket: &S3Bucket::new(bucket),
+            region: &RegionCode::any(region),
+        };
+        Ok(endpoint.to_url(key)?.to_string())
+    }
+
+    #[test]
+    fn it_uses_virtual_host_by_default() -> core::Result<()> {
+        let url = to_url(
+            S3Endpoint::Global,
+            AddressingStyle::Auto,
+            "example",
+            "a/b.txt",
+        )?;
+        assert_eq!(url, "https://example.s3.amazonaws.com/a/b.txt");
+
+        let url = to_url(
+            S3Endpoint::Regional,
+            AddressingStyle::Auto,
+            "example",
+            "a.txt",
+        )?;
+        assert_eq!(url, "https://example.s3.eu-west-1.amazonaws.com/a.txt");
+        Ok(())
+    }
+
+    #[test]
+    fn it_uses_path_style_for_names_with_dots() -> core::Result<()> {
+        let url = to_url(S3Endpoint::Regional, AddressingStyle::Auto, "a.b.c", "x")?;
+        assert_eq!(url, "https://s3.eu-west-1.amazonaws.com/a.b.c/x");
+
+        let url = to_url(S3Endpoint::Regional, AddressingStyle::Auto, "a.b.c", "")?;
+        assert_eq!(url, "https://s3.eu-west-1.amazonaws.com/a.b.c");
+
+        let url = to_url(S3Endpoint::Global, AddressingStyle::Auto, "a.b.c", "x")?;
+        assert_eq!(url, "https://s3.eu-west-1.amazonaws.com/a.b.c/x");
+
+        let (global, auto) = (S3Endpoint::Global, AddressingStyle::Auto);
+        let none = EndpointVariants::default();
+        let url = to_url_in("us-east-1", global, none, auto, "a.b.c", "x")?;
+        assert_eq!(url, "https://s3.amazonaws.com/a.b.c/x");
+        Ok(())
+    }
+
+    #[test]
+    fn it_uses_path_style_for_custom_endpoint() -> core::Result<()> {
+        let custom = S3Endpoint::Custom(Url::parse("http://localhost:9000").unwrap());
+        let url = to_url(custom.clone(), AddressingStyle::Auto, "example", "a b?.txt")?;
+        assert_eq!(url, "http://localhost:9000/example/a%20b%3F.txt");
+
+        let url = to_url(custom, AddressingStyle::Virtual, "example", "a.txt")?;
+        assert_eq!(url, "http://example.localhost:9000/a.txt");
+        Ok(())
+    }
+
+    #[test]
+    fn it_encodes_key_as_canonical_uri() -> core::Result<()> {
+        let url = to_url(
+            S3Endpoint::Global,
+            AddressingStyle::Auto,
+            "example",
+            "dir/a+b=c!.txt",
+        )?;
+        assert_eq!(url, "https://example.s3.amazonaws.com/dir/a%2Bb%3Dc%21.txt");
+
+        let url = to_url(
+            S3Endpoint::Global,
+            AddressingStyle::Path,
+            "example",
+            "a+b=c!.txt",
+        )?;
+        assert_eq!(
+            url,
+            "https://s3.eu-west-1.amazonaws.com/example/a%2Bb%3Dc%21.txt"
+        );
+
+        let url = Url::parse(&url).unwrap();
+        assert_eq!(
+            CanonicalUri::from(&url).as_str(),
+            "/example/a%2Bb%3Dc%21.txt"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_key_with_dot_segments() -> core::Result<()> {
+        let (global, auto) = (S3Endpoint::Global, AddressingStyle::Auto);
+        for key in &["a/../b.txt", "../b.txt", "a/./b.txt", "a/.."] {
+            let result = to_url(global.clone(), auto, "example", key);
+            assert!(matches!(result, Err(core::Error::InvalidObjectKey(_))));
+        }
+        let url = to_url(global.clone(), auto, "example", "a/%2E%2E/b.txt")?;
+        assert_eq!(url, "https://example.s3.amazonaws.com/a/%252E%252E/b.txt");
+
+        let url = to_url(global, auto, "example", "a/..b/.c")?;
+        assert_eq!(url, "https://example.s3.amazonaws.com/a/..b/.c");
+        Ok(())
+    }
+
+    #[test]
+    fn it_uses_variant_hosts() -> core::Result<()> {
+        let (global, auto) = (S3Endpoint::Global, AddressingStyle::Auto);
+        let dualstack = EndpointVariants {
+            dualstack: true,
+            ..Default::default()
+        };
+        let url = to_url_with(global.clone(), dualstack, auto, "example", "a.txt")?;
+        assert_eq!(
+            url,
+            "https://example.s3.dualstack.eu-west-1.amazonaws.com/a.txt"
+        );
+
+        let fips = EndpointVariants {
+            fips: true,
+            ..dualstack
+        };
+        let url = to_url_with(global.clone(), fips, auto, "example", "a.txt")?;
+        assert_eq!(
+            url,
+            "https://example.s3-fips.dualstack.eu-west-1.amazonaws.com/a.txt"
+        );
+
+        let accelerate = EndpointVariants {
+            accelerate: true,
+            ..Default::default()
+        };
+        let url = to_url_with(global, accelerate, auto, "example", "a.txt")?;
+        assert_eq!(url, "https://example.s3-accelerate.amazonaws.com/a.txt");
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_invalid_combinations() {
+        let accelerate = EndpointVariants {
+            accelerate: true,
+            ..Default::default()
+        };
+        let (global, auto) = (S3Endpoint::Global, AddressingStyle::Auto);
+        assert!(to_url_with(global.clone(), accelerate, auto, "a.b.c", "x").is_err());
+
+        let path = AddressingStyle::Path;
+        assert!(to_url_with(global.clone(), accelerate, path, "example", "x").is_err());
+
+        let fips = EndpointVariants {
+            fips: true,
+            ..accelerate
+        };
+        assert!(to_url_with(global, fips, auto, "example", "x").is_err());
+
+        let custom = S3Endpoint::Custom(Url::parse("http://localhost:9000").unwrap());
+        assert!(to_url_with(custom, accelerate, auto, "example", "x").is_err());
+    }
+
+    #[test]
+    fn it_uses_dns_suffix_of_partition() -> core::Result<()> {
+        let (global, auto) = (S3Endpoint::Global, AddressingStyle::Auto);
+        let none = EndpointVariants::default();
+        let url = to_url_in("cn-north-1", global.clone(), none, auto, "example", "a.txt")?;
+        assert_eq!(url, "https://example.s3.cn-north-1.amazonaws.com.cn/a.txt");
+
+        let url = to_url_in("us-gov-west-1", global.clone(), none, auto, "example", "a")?;
+        assert_eq!(url, "https://example.s3.us-gov-west-1.amazonaws.com/a");
+
+        let url = to_url_in("us-iso-east-1", global, none, auto, "example", "a")?;
+        assert_eq!(url, "https://example.s3.us-iso-east-1.c2s.ic.gov/a");
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_variants_unavailable_in_partition() {
+        let (global, auto) = (S3Endpoint::Global, AddressingStyle::Auto);
+        let accelerate = EndpointVariants {
+            accelerate: true,
+            ..Default::default()
+        };
+        let result = to_url_in(
+            "cn-north-1",
+            global.clone(),
+            accelerate,
+            auto,
+            "example",
+            "x",
+        );
+        assert!(result.is_err());
+
+        let dualstack = EndpointVariants {
+            dualstack: true,
+            ..Default::default()
+        };
+        let result = to_url_in("us-iso-east-1", global, dualstack, auto, "example", "x");
+        assert!(result.is_err());
+    }
+}
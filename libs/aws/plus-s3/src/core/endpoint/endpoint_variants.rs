@@ -0,0 +1,33 @@
+/// rf.
+/// * [Dual-stack endpoints - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/userguide/dual-stack-endpoints.html)
+/// * [FIPS Endpoints by Service](https://aws.amazon.com/compliance/fips/#FIPS_Endpoints_by_Service)
+/// * [Transfer Acceleration - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/userguide/transfer-acceleration.html)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EndpointVariants {
+    /// Accepts both IPv4 and IPv6.
+    pub dualstack: bool,
+    pub fips: bool,
+
+    /// Only available for virtual-hosted-style requests,
+    /// to the buckets with names compatible with DNS and without dots.
+    pub accelerate: bool,
+}
+
+impl EndpointVariants {
+    pub fn is_empty(&self) -> bool {
+        !self.dualstack && !self.fips && !self.accelerate
+    }
+
+    /// The first label of the host, like `s3-fips.dualstack`.
+    pub fn service_label(&self) -> String {
+        let service = match (self.accelerate, self.fips) {
+            (true, _) => "s3-accelerate",
+            (false, true) => "s3-fips",
+            (false, false) => "s3",
+        };
+        match self.dualstack {
+            true => format!("{}.dualstack", service),
+            false => service.to_string(),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod addressing_style;
+pub use addressing_style::AddressingStyle;
+
+mod bucket_endpoint;
+pub use bucket_endpoint::BucketEndpoint;
+
+mod endpoint_variants;
+pub use endpoint_variants::EndpointVariants;
+
+mod s3_endpoint;
+pub use s3_endpoint::S3Endpoint;
@@ -0,0 +1,57 @@
+use crate::core;
+use crate::core::endpoint::EndpointVariants;
+use plus_aws::index::{Partition, RegionCode};
+use reqwest::Url;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum S3Endpoint {
+    /// `s3.amazonaws.com`, which routes virtual-hosted-style requests to the bucket's region.
+    /// The other partitions have no such endpoint, so the regional one is used instead.
+    #[default]
+    Global,
+
+    /// `s3.{region}.{dns suffix}`, like `s3.cn-north-1.amazonaws.com.cn`
+    Regional,
+
+    /// Any S3-compatible store, like `http://localhost:9000`.
+    Custom(Url),
+}
+
+impl S3Endpoint {
+    /// The host follows the DNS suffix of the partition the region belongs to.
+    pub fn base_url(&self, region: &RegionCode, variants: EndpointVariants) -> core::Result<Url> {
+        let label = variants.service_label();
+        let partition = region.partition();
+        let suffix = partition.dns_suffix();
+        let url = match self {
+            S3Endpoint::Custom(url) => url.clone(),
+            _ if variants.accelerate => Url::parse(&format!("https://{}.{}", label, suffix))?,
+            S3Endpoint::Global if variants.is_empty() && partition == Partition::Aws => {
+                Url::parse("https://s3.amazonaws.com")?
+            }
+            S3Endpoint::Global | S3Endpoint::Regional => {
+                Url::parse(&format!("https://{}.{}.{}", label, region.as_str(), suffix))?
+            }
+        };
+        Ok(url)
+    }
+
+    /// `s3.amazonaws.com` serves path-style requests only for the buckets in us-east-1,
+    /// and answers the others with 301 PermanentRedirect, so the regional host is used instead.
+    pub fn path_style_base_url(
+        &self,
+        region: &RegionCode,
+        variants: EndpointVariants,
+    ) -> core::Result<Url> {
+        match self {
+            S3Endpoint::Global if region.as_str() != RegionCode::UsEast1.as_str() => {
+                S3Endpoint::Regional.base_url(region, variants)
+            }
+            _ => self.base_url(region, variants),
+        }
+    }
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, S3Endpoint::Custom(_))
+    }
+}
@@ -1,3 +1,4 @@
+use crate::core::checksum;
 use crate::core::response::headers;
 use failure::_core::fmt::Debug;
 
@@ -5,6 +6,15 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Fail, Debug)]
 pub enum Error {
+    #[fail(
+        display = "ContentLengthMismatch > expected: {}, actual: {}",
+        expected, actual
+    )]
+    ContentLengthMismatch { expected: u64, actual: u64 },
+
+    #[fail(display = "ContentLengthTooLarge > {}", 0)]
+    ContentLengthTooLarge(u64),
+
     #[fail(display = "env_extractor::Error > {}", 0)]
     EnvExtractorError(String),
 
@@ -14,17 +24,53 @@ pub enum Error {
     )]
     FileNotFound { path: String, description: String },
 
+    #[fail(display = "HostNotFound > {}", 0)]
+    HostNotFound(url::Url),
+
+    #[fail(display = "InvalidEndpointConfiguration > {}", 0)]
+    InvalidEndpointConfiguration(String),
+
+    #[fail(display = "InvalidObjectKey > {}", 0)]
+    InvalidObjectKey(String),
+
+    #[fail(display = "InvalidPostPolicy > {}", 0)]
+    InvalidPostPolicy(String),
+
+    #[fail(display = "checksum::IntegrityError > {}", 0)]
+    IntegrityError(checksum::IntegrityError),
+
     #[fail(display = "plus_aws::Error > {}", 0)]
     PlusAwsError(plus_aws::Error),
 
+    #[fail(display = "ReaderAlreadyConsumed")]
+    ReaderAlreadyConsumed,
+
     #[fail(display = "headers::Error > {}", 0)]
     S3HeaderError(headers::Error),
 
     #[fail(display = "std::io::Error > {}", 0)]
     StdIoError(std::io::Error),
 
+    #[fail(display = "UnknownAddressingStyle > {}", 0)]
+    UnknownAddressingStyle(String),
+
+    #[fail(display = "UnknownChecksumAlgorithm > {}", 0)]
+    UnknownChecksumAlgorithm(String),
+
+    #[fail(display = "UnknownObjectOwnership > {}", 0)]
+    UnknownObjectOwnership(String),
+
+    #[fail(display = "UnknownRetryMode > {}", 0)]
+    UnknownRetryMode(String),
+
+    #[fail(display = "UnsignedPayloadOverHttp > {}", 0)]
+    UnsignedPayloadOverHttp(url::Url),
+
     #[fail(display = "url::ParseError > {}", 0)]
     UrlParseError(url::ParseError),
+
+    #[fail(display = "quick_xml::DeError > {}", 0)]
+    XmlError(quick_xml::DeError),
 }
 
 impl<A: Debug> From<env_extractor::Error<A>> for Error {
@@ -33,6 +79,12 @@ impl<A: Debug> From<env_extractor::Error<A>> for Error {
     }
 }
 
+impl From<checksum::IntegrityError> for Error {
+    fn from(e: checksum::IntegrityError) -> Self {
+        Error::IntegrityError(e)
+    }
+}
+
 impl From<plus_aws::Error> for Error {
     fn from(e: plus_aws::Error) -> Self {
         Error::PlusAwsError(e)
@@ -56,3 +108,9 @@ impl From<url::ParseError> for Error {
         Error::UrlParseError(e)
     }
 }
+
+impl From<quick_xml::DeError> for Error {
+    fn from(e: quick_xml::DeError) -> Self {
+        Error::XmlError(e)
+    }
+}
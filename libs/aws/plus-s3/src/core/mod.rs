@@ -1,10 +1,26 @@
 mod bucket;
 pub use bucket::S3Bucket;
 
+pub mod checksum;
+pub mod configuration;
+pub mod endpoint;
+
 mod error;
 pub use error::Error;
 pub use error::Result;
 
+pub mod multipart;
+pub mod post;
+
+mod region_cache;
+pub use region_cache::RegionCache;
+
 pub mod request;
 pub mod response;
+pub mod retry;
+
+mod storage_class;
+pub use storage_class::StorageClass;
+
 pub mod verbs;
+pub mod xml;
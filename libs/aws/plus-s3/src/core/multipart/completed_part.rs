@@ -0,0 +1,94 @@
+use crate::core::checksum::{Checksum, ChecksumAlgorithm};
+
+/// Parts to be assembled into the object, in ascending order of the part number.
+///
+/// rf.
+/// [CompleteMultipartUpload - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/API_CompleteMultipartUpload.html)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompleteMultipartUpload {
+    #[serde(rename = "Part", default)]
+    pub parts: Vec<CompletedPart>,
+}
+
+impl CompleteMultipartUpload {
+    /// Sorts the parts, which may be uploaded in any order.
+    pub fn new(mut parts: Vec<CompletedPart>) -> Self {
+        parts.sort_by_key(|part| part.part_number);
+        CompleteMultipartUpload { parts }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CompletedPart {
+    pub part_number: u32,
+
+    #[serde(rename = "ETag")]
+    pub e_tag: String,
+
+    #[serde(rename = "ChecksumCRC32", skip_serializing_if = "Option::is_none")]
+    pub checksum_crc32: Option<String>,
+
+    #[serde(rename = "ChecksumCRC32C", skip_serializing_if = "Option::is_none")]
+    pub checksum_crc32c: Option<String>,
+
+    #[serde(rename = "ChecksumSHA1", skip_serializing_if = "Option::is_none")]
+    pub checksum_sha1: Option<String>,
+
+    #[serde(rename = "ChecksumSHA256", skip_serializing_if = "Option::is_none")]
+    pub checksum_sha256: Option<String>,
+}
+
+impl CompletedPart {
+    /// The checksum returned by `UploadPart` is required
+    /// if the upload was created with the checksum algorithm.
+    pub fn new<A: Into<String>>(part_number: u32, e_tag: A, checksum: Option<Checksum>) -> Self {
+        let mut part = CompletedPart {
+            part_number,
+            e_tag: e_tag.into(),
+            checksum_crc32: None,
+            checksum_crc32c: None,
+            checksum_sha1: None,
+            checksum_sha256: None,
+        };
+        if let Some(checksum) = checksum {
+            let field = match checksum.algorithm {
+                ChecksumAlgorithm::Crc32 => &mut part.checksum_crc32,
+                ChecksumAlgorithm::Crc32c => &mut part.checksum_crc32c,
+                ChecksumAlgorithm::Sha1 => &mut part.checksum_sha1,
+                ChecksumAlgorithm::Sha256 => &mut part.checksum_sha256,
+            };
+            *field = Some(checksum.value);
+        }
+        part
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CompleteMultipartUpload, CompletedPart};
+    use crate::core;
+    use crate::core::checksum::{Checksum, ChecksumAlgorithm};
+    use crate::core::xml;
+
+    #[test]
+    fn it_can_serialize_parts_in_order() -> core::Result<()> {
+        let upload = CompleteMultipartUpload::new(vec![
+            CompletedPart::new(2, "\"etag-2\"", None),
+            CompletedPart::new(
+                1,
+                "\"etag-1\"",
+                Some(Checksum::new(ChecksumAlgorithm::Crc32, "7ErD0A==")),
+            ),
+        ]);
+        assert_eq!(
+            xml::to_string(&upload)?,
+            "<CompleteMultipartUpload>\
+             <Part><PartNumber>1</PartNumber><ETag>&quot;etag-1&quot;</ETag>\
+             <ChecksumCRC32>7ErD0A==</ChecksumCRC32></Part>\
+             <Part><PartNumber>2</PartNumber><ETag>&quot;etag-2&quot;</ETag></Part>\
+             </CompleteMultipartUpload>"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+mod completed_part;
+pub use completed_part::{CompleteMultipartUpload, CompletedPart};
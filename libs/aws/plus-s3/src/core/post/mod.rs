@@ -0,0 +1,13 @@
+/*
+    see also:
+    https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-UsingHTTPPOST.html
+*/
+
+mod policy_condition;
+pub use policy_condition::PolicyCondition;
+
+mod post_form;
+pub use post_form::PostForm;
+
+mod post_policy;
+pub use post_policy::PostPolicy;
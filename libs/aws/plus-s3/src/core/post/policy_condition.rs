@@ -0,0 +1,64 @@
+use serde_json::{json, Map, Value};
+
+/// rf.
+/// [Creating a POST policy - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-HTTPPOSTConstructPolicy.html)
+#[derive(Clone, Debug, PartialEq)]
+pub enum PolicyCondition {
+    /// The form field must have the value.
+    Equals { field: String, value: String },
+
+    /// The form field must start with the prefix, or can be anything if it is empty.
+    StartsWith { field: String, prefix: String },
+
+    /// The size of the uploaded file in bytes.
+    ContentLengthRange { min: u64, max: u64 },
+}
+
+impl PolicyCondition {
+    pub fn equals<A: Into<String>, B: Into<String>>(field: A, value: B) -> Self {
+        PolicyCondition::Equals {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn starts_with<A: Into<String>, B: Into<String>>(field: A, prefix: B) -> Self {
+        PolicyCondition::StartsWith {
+            field: field.into(),
+            prefix: prefix.into(),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            PolicyCondition::Equals { field, value } => {
+                let mut map = Map::new();
+                map.insert(field.clone(), Value::from(value.as_str()));
+                Value::Object(map)
+            }
+            PolicyCondition::StartsWith { field, prefix } => {
+                json!(["starts-with", format!("${}", field), prefix])
+            }
+            PolicyCondition::ContentLengthRange { min, max } => {
+                json!(["content-length-range", min, max])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PolicyCondition;
+
+    #[test]
+    fn it_can_be_converted_to_json() {
+        let json = PolicyCondition::equals("acl", "public-read").to_json();
+        assert_eq!(json.to_string(), r#"{"acl":"public-read"}"#);
+
+        let json = PolicyCondition::starts_with("key", "user/").to_json();
+        assert_eq!(json.to_string(), r#"["starts-with","$key","user/"]"#);
+
+        let json = PolicyCondition::ContentLengthRange { min: 1, max: 10 }.to_json();
+        assert_eq!(json.to_string(), r#"["content-length-range",1,10]"#);
+    }
+}
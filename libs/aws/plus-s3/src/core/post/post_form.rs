@@ -0,0 +1,18 @@
+use reqwest::Url;
+
+/// The action and the fields of the HTML form,
+/// which must be followed by the `file` field since S3 ignores the fields after it.
+#[derive(Debug)]
+pub struct PostForm {
+    pub url: Url,
+    pub fields: Vec<(String, String)>,
+}
+
+impl PostForm {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::core::S3Bucket;
+use plus_aws::index::RegionCode;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Regions of buckets discovered by redirects,
+/// shared by the requests sent from the same client.
+#[derive(Clone, Debug, Default)]
+pub struct RegionCache(Arc<Mutex<HashMap<String, RegionCode>>>);
+
+impl RegionCache {
+    pub fn get(&self, bucket: &S3Bucket) -> Option<RegionCode> {
+        let map = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        map.get(bucket.as_str()).cloned()
+    }
+
+    pub fn insert(&self, bucket: &S3Bucket, region: RegionCode) {
+        let mut map = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        map.insert(bucket.as_str().to_string(), region);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RegionCache;
+    use crate::core::S3Bucket;
+    use plus_aws::index::RegionCode;
+
+    #[test]
+    fn it_is_shared_by_clones() {
+        let cache = RegionCache::default();
+        let bucket = S3Bucket::new("example-bucket");
+        cache.clone().insert(&bucket, RegionCode::any("eu-west-1"));
+
+        let region = cache.get(&bucket).map(|x| x.as_str().to_string());
+        assert_eq!(region, Some("eu-west-1".to_string()));
+        assert!(cache.get(&S3Bucket::new("other-bucket")).is_none());
+    }
+}
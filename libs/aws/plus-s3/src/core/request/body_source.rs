@@ -0,0 +1,372 @@
+use crate::core;
+use crate::core::checksum::{Checksum, ChecksumAlgorithm};
+use crate::core::request::{
+    FileRange, PayloadDigester, PayloadDigests, RequestBody, RequestResource, StreamingBody,
+};
+use bytes::{Bytes, BytesMut};
+use futures_util::stream::{self, Stream};
+use futures_util::{future, TryStreamExt};
+use plus_aws::auth::v4::canonical::HashedPayload;
+use plus_aws::auth::v4::streaming::StreamingPayload;
+use plus_aws::http::request::ToHeaderFragment;
+use plus_aws::io::stream::{bytes_stream, MultiDigester};
+use std::convert::TryFrom;
+use std::fmt;
+use std::io;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::pin::Pin;
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, Take};
+
+pub type ByteStream = Pin<Box<dyn Stream<Item = io::Result<Bytes>> + Send + Sync>>;
+
+/// Limits the memory allocated in advance, since the declared length may be wrong.
+const MAX_INITIAL_CAPACITY: usize = 8 * 1024 * 1024;
+
+/// Source of the request body.
+///
+/// `File`, `FileRange` and `Bytes` can be read as many times as needed,
+/// while `Stream` can be read only once.
+pub enum BodySource<'a> {
+    File(&'a Path),
+    FileRange(FileRange<'a>),
+    /// The whole content of the file, read from the start whatever the offset of the handle is.
+    ///
+    /// Taken by value, since the file is read twice by its clones sharing the offset:
+    /// the handle and its other clones must not be read until the body has been sent.
+    OpenFile(File),
+    Bytes(Bytes),
+    Stream {
+        stream: ByteStream,
+        content_length: u64,
+    },
+}
+
+impl BodySource<'_> {
+    /// Creates a resource which sends the body with its length and digests.
+    ///
+    /// `Stream` is buffered in memory, since the payload is hashed before it is sent.
+    pub async fn to_resource<'a>(
+        self,
+        checksum_algorithm: Option<ChecksumAlgorithm>,
+    ) -> core::Result<RequestResource<'a>> {
+        let digester = PayloadDigester::new(checksum_algorithm);
+        let (body, content_length, digests) = match self {
+            BodySource::File(path) => {
+                let range = FileRange::whole(path).await?;
+                digest_file(FileSource::Range(range), digester).await?
+            }
+            BodySource::FileRange(range) => digest_file(FileSource::Range(range), digester).await?,
+            BodySource::OpenFile(file) => {
+                digest_file(FileSource::of(file).await?, digester).await?
+            }
+            BodySource::Bytes(bytes) => {
+                let content_length = bytes.len() as u64;
+                let digests = digest_bytes(digester, &bytes);
+                (RequestBody::from(bytes), content_length, digests)
+            }
+            BodySource::Stream {
+                stream,
+                content_length,
+            } => {
+                let bytes = read_all(stream, content_length).await?;
+                let digests = digest_bytes(digester, &bytes);
+                (RequestBody::from(bytes), content_length, digests)
+            }
+        };
+        let mut headers = vec![];
+        if let Some(checksum) = &digests.checksum {
+            headers.push(ToHeaderFragment::into(checksum)?);
+        }
+        Ok(RequestResource {
+            body: Some(body),
+            hash: digests.hash,
+            content_length,
+            content_md5: Some(digests.content_md5),
+            headers,
+            ..RequestResource::empty()
+        })
+    }
+
+    /// Creates a resource which sends the body as `UNSIGNED-PAYLOAD` without hashing it.
+    ///
+    /// The body is still read in advance if the checksum is required,
+    /// except for `Stream` which is buffered to calculate it.
+    pub async fn to_unsigned_resource<'a>(
+        self,
+        checksum_algorithm: Option<ChecksumAlgorithm>,
+    ) -> core::Result<RequestResource<'a>> {
+        let checksum_of = |bytes: &[u8]| {
+            checksum_algorithm.map(|algorithm| {
+                let mut checksummer = algorithm.checksummer();
+                checksummer.update(bytes);
+                checksummer.finish()
+            })
+        };
+        let (body, content_length, checksum) = match self {
+            BodySource::File(path) => {
+                let range = FileRange::whole(path).await?;
+                checksum_file(FileSource::Range(range), checksum_algorithm).await?
+            }
+            BodySource::FileRange(range) => {
+                checksum_file(FileSource::Range(range), checksum_algorithm).await?
+            }
+            BodySource::OpenFile(file) => {
+                checksum_file(FileSource::of(file).await?, checksum_algorithm).await?
+            }
+            BodySource::Bytes(bytes) => {
+                let content_length = bytes.len() as u64;
+                let checksum = checksum_of(&bytes);
+                (RequestBody::from(bytes), content_length, checksum)
+            }
+            BodySource::Stream {
+                stream,
+                content_length,
+            } => match checksum_algorithm {
+                Some(_) => {
+                    let bytes = read_all(stream, content_length).await?;
+                    let checksum = checksum_of(&bytes);
+                    (RequestBody::from(bytes), content_length, checksum)
+                }
+                None => (RequestBody::Stream(stream), content_length, None),
+            },
+        };
+        let mut headers = vec![];
+        if let Some(checksum) = &checksum {
+            headers.push(ToHeaderFragment::into(checksum)?);
+        }
+        Ok(RequestResource {
+            body: Some(body),
+            hash: HashedPayload::unsigned(),
+            content_length,
+            headers,
+            ..RequestResource::empty()
+        })
+    }
+
+    /// Creates a body which is read only once, while it is sent by `aws-chunked` encoding.
+    pub async fn to_streaming_body(
+        self,
+        payload: StreamingPayload,
+        checksum_algorithm: Option<ChecksumAlgorithm>,
+    ) -> core::Result<StreamingBody> {
+        let (stream, content_length): (ByteStream, u64) = match self {
+            BodySource::File(path) => {
+                let range = FileRange::whole(path).await?;
+                let stream = bytes_stream::from_reader(range.open().await?);
+                (Box::pin(stream), range.length)
+            }
+            BodySource::FileRange(range) => {
+                let stream = bytes_stream::from_reader(range.open().await?);
+                (Box::pin(stream), range.length)
+            }
+            BodySource::OpenFile(file) => {
+                let source = FileSource::of(file).await?;
+                let stream = bytes_stream::from_reader(source.open().await?);
+                (Box::pin(stream), source.length())
+            }
+            BodySource::Bytes(bytes) => {
+                let content_length = bytes.len() as u64;
+                (Box::pin(stream::once(future::ok(bytes))), content_length)
+            }
+            BodySource::Stream {
+                stream,
+                content_length,
+            } => (stream, content_length),
+        };
+        Ok(StreamingBody::new(
+            stream,
+            content_length,
+            payload,
+            checksum_algorithm,
+        ))
+    }
+}
+
+impl fmt::Debug for BodySource<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BodySource::File(path) => f.debug_tuple("File").field(path).finish(),
+            BodySource::FileRange(range) => f.debug_tuple("FileRange").field(range).finish(),
+            BodySource::OpenFile(file) => f.debug_tuple("OpenFile").field(file).finish(),
+            BodySource::Bytes(bytes) => f.debug_tuple("Bytes").field(bytes).finish(),
+            BodySource::Stream { content_length, .. } => f
+                .debug_struct("Stream")
+                .field("content_length", content_length)
+                .finish(),
+        }
+    }
+}
+
+/// The capacity of the buffer to read the content of the length in memory.
+pub(crate) fn buffer_capacity(content_length: u64) -> core::Result<usize> {
+    let length = usize::try_from(content_length)
+        .map_err(|_| core::Error::ContentLengthTooLarge(content_length))?;
+    Ok(length.min(MAX_INITIAL_CAPACITY))
+}
+
+/// The file which can be opened again to be read from the start.
+enum FileSource<'a> {
+    Range(FileRange<'a>),
+    Handle { file: File, length: u64 },
+}
+
+impl<'a> FileSource<'a> {
+    async fn of(file: File) -> core::Result<FileSource<'a>> {
+        let length = file.metadata().await?.len();
+        Ok(FileSource::Handle { file, length })
+    }
+
+    fn length(&self) -> u64 {
+        match self {
+            FileSource::Range(range) => range.length,
+            FileSource::Handle { length, .. } => *length,
+        }
+    }
+
+    async fn open(&self) -> core::Result<Take<File>> {
+        match self {
+            FileSource::Range(range) => range.open().await,
+            // read one after another, so that the clones do not move the offset meanwhile.
+            FileSource::Handle { file, length } => {
+                let mut file = file.try_clone().await?;
+                file.seek(SeekFrom::Start(0)).await?;
+                Ok(file.take(*length))
+            }
+        }
+    }
+}
+
+/// Reads the file once to digest it, and again to send it.
+async fn digest_file(
+    source: FileSource<'_>,
+    digester: PayloadDigester,
+) -> core::Result<(RequestBody, u64, PayloadDigests)> {
+    let digests = digester.digest_reader(source.open().await?).await?;
+    let stream = bytes_stream::from_reader(source.open().await?);
+    let body = RequestBody::Stream(Box::pin(stream));
+    Ok((body, source.length(), digests))
+}
+
+/// Reads the file in advance only if the checksum is required.
+async fn checksum_file(
+    source: FileSource<'_>,
+    checksum_algorithm: Option<ChecksumAlgorithm>,
+) -> core::Result<(RequestBody, u64, Option<Checksum>)> {
+    let checksum = match checksum_algorithm {
+        Some(algorithm) => {
+            let digester = MultiDigester::new(&[algorithm.digest_algorithm()]);
+            let reader = source.open().await?;
+            let digests = digester.on_blocking_pool().digest_reader(reader).await?;
+            Checksum::from_digests(algorithm, &digests)
+        }
+        None => None,
+    };
+    let stream = bytes_stream::from_reader(source.open().await?);
+    let body = RequestBody::Stream(Box::pin(stream));
+    Ok((body, source.length(), checksum))
+}
+
+fn digest_bytes(mut digester: PayloadDigester, bytes: &[u8]) -> PayloadDigests {
+    digester.update(bytes);
+    digester.finish()
+}
+
+/// Fails as soon as the stream exceeds the length, not to keep growing the buffer.
+async fn read_all(stream: ByteStream, content_length: u64) -> core::Result<Bytes> {
+    let mismatch = |actual: usize| core::Error::ContentLengthMismatch {
+        expected: content_length,
+        actual: actual as u64,
+    };
+    let buffer = BytesMut::with_capacity(buffer_capacity(content_length)?);
+    let buffer = stream
+        .map_err(core::Error::from)
+        .try_fold(buffer, |mut buffer, item| {
+            buffer.extend_from_slice(&item);
+            if buffer.len() as u64 > content_length {
+                return future::err(mismatch(buffer.len()));
+            }
+            future::ok(buffer)
+        })
+        .await?;
+
+    if buffer.len() as u64 != content_length {
+        return Err(mismatch(buffer.len()));
+    }
+    Ok(buffer.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BodySource;
+    use crate::core;
+    use crate::core::checksum::ChecksumAlgorithm;
+    use bytes::Bytes;
+    use futures::executor::block_on;
+    use futures_util::{future, stream, StreamExt, TryStreamExt};
+    use plus_aws::auth::v4::canonical::HashedPayload;
+    use std::io::Write;
+    use tokio::fs::File;
+    use tokio::io::AsyncReadExt;
+    use tokio::runtime::Builder;
+
+    #[test]
+    fn it_reads_open_file_from_start() -> core::Result<()> {
+        let mut tmp = tempfile::NamedTempFile::new()?;
+        tmp.write_all(b"Hello, World!")?;
+
+        let mut runtime = Builder::new().basic_scheduler().enable_all().build()?;
+        runtime.block_on(async {
+            let mut file = File::open(tmp.path()).await?;
+            file.read_exact(&mut [0; 7]).await?;
+
+            let resource = BodySource::OpenFile(file).to_resource(None).await?;
+            assert_eq!(resource.content_length, 13);
+            let expected = HashedPayload::from_bytes(b"Hello, World!");
+            assert_eq!(resource.hash.as_str(), expected.as_str());
+
+            let body = resource.body.unwrap().into_stream();
+            let body: Vec<Bytes> = body.try_collect().await?;
+            assert_eq!(body.concat(), b"Hello, World!");
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn it_does_not_allocate_declared_length_of_stream() {
+        let stream = Box::pin(stream::once(future::ok(Bytes::from("Hello"))));
+        let source = BodySource::Stream {
+            stream,
+            content_length: u64::MAX,
+        };
+        match block_on(source.to_resource(None)) {
+            Err(core::Error::ContentLengthMismatch { actual, .. }) => assert_eq!(actual, 5),
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn it_stops_reading_stream_longer_than_declared() {
+        let chunks = stream::repeat(Bytes::from("Hello")).map(Ok);
+        let source = BodySource::Stream {
+            stream: Box::pin(chunks),
+            content_length: 12,
+        };
+        match block_on(source.to_resource(None)) {
+            Err(core::Error::ContentLengthMismatch { actual, .. }) => assert_eq!(actual, 15),
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn it_sends_unsigned_payload_with_checksum() -> core::Result<()> {
+        let source = BodySource::Bytes("Hello, World!".into());
+        let resource = block_on(source.to_unsigned_resource(Some(ChecksumAlgorithm::Crc32)))?;
+        assert_eq!(resource.hash.as_str(), "UNSIGNED-PAYLOAD");
+        assert_eq!(resource.content_length, 13);
+        assert!(resource.content_md5.is_none());
+        assert_eq!(resource.headers.len(), 1);
+        Ok(())
+    }
+}
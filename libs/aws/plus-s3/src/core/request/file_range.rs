@@ -0,0 +1,45 @@
+use crate::core;
+use std::io::ErrorKind::NotFound;
+use std::io::SeekFrom;
+use std::path::Path;
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, Take};
+
+/// Part of the file to be sent, like a part of the multipart upload.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FileRange<'a> {
+    pub path: &'a Path,
+    pub offset: u64,
+    pub length: u64,
+}
+
+impl FileRange<'_> {
+    /// Covers the whole of the file.
+    pub async fn whole(path: &Path) -> core::Result<FileRange<'_>> {
+        let file = open_file(path).await?;
+        Ok(FileRange {
+            path,
+            offset: 0,
+            length: file.metadata().await?.len(),
+        })
+    }
+
+    /// Opens the file which is read until the end of the range.
+    pub async fn open(&self) -> core::Result<Take<File>> {
+        let mut file = open_file(self.path).await?;
+        if self.offset > 0 {
+            file.seek(SeekFrom::Start(self.offset)).await?;
+        }
+        Ok(file.take(self.length))
+    }
+}
+
+async fn open_file(path: &Path) -> core::Result<File> {
+    File::open(path).await.map_err(|e| match e {
+        _ if e.kind() == NotFound => core::Error::FileNotFound {
+            path: path.to_string_lossy().to_string(),
+            description: e.to_string(),
+        },
+        _ => core::Error::StdIoError(e),
+    })
+}
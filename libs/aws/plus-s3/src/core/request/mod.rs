@@ -1,3 +1,34 @@
+mod body_source;
+pub(crate) use body_source::buffer_capacity;
+pub use body_source::{BodySource, ByteStream};
+
+mod file_range;
+pub use file_range::FileRange;
+
+mod payload_signing;
+pub use payload_signing::PayloadSigning;
+
+mod payload_digests;
+pub use payload_digests::{PayloadDigester, PayloadDigests};
+
+mod presign_request;
+pub use presign_request::PresignRequest;
+
+mod request_body;
+pub use request_body::RequestBody;
+
+mod request_options;
+pub use request_options::{RequestOptions, RequestPayer};
+
+mod request_payload;
+pub use request_payload::RequestPayload;
+
 mod request_resource;
 pub use request_resource::RequestResource;
 pub use request_resource::ResourceLoader;
+
+mod streaming_body;
+pub use streaming_body::StreamingBody;
+
+mod with_options;
+pub use with_options::WithOptions;
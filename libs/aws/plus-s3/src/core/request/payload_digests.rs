@@ -0,0 +1,83 @@
+use crate::core::checksum::{Checksum, ChecksumAlgorithm};
+use plus_aws::auth::v4::canonical::HashedPayload;
+use plus_aws::http::request::header::ContentMd5;
+use plus_aws::io::stream::{DigestAlgorithm, Digests, MultiDigester};
+use std::io;
+use tokio::io::AsyncRead;
+
+/// Digests of a payload which are required to upload it.
+pub struct PayloadDigests {
+    pub hash: HashedPayload,
+    pub content_md5: ContentMd5,
+    pub checksum: Option<Checksum>,
+}
+
+/// Calculates all the `PayloadDigests` in a single pass.
+pub struct PayloadDigester {
+    digester: MultiDigester,
+    checksum_algorithm: Option<ChecksumAlgorithm>,
+}
+
+impl PayloadDigester {
+    pub fn new(checksum_algorithm: Option<ChecksumAlgorithm>) -> Self {
+        let mut algorithms = vec![DigestAlgorithm::Sha256, DigestAlgorithm::Md5];
+        if let Some(algorithm) = checksum_algorithm {
+            algorithms.push(algorithm.digest_algorithm());
+        }
+        PayloadDigester {
+            digester: MultiDigester::new(&algorithms),
+            checksum_algorithm,
+        }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.digester.update(bytes);
+    }
+
+    pub fn finish(self) -> PayloadDigests {
+        let digests = self.digester.finish();
+        to_payload_digests(digests, self.checksum_algorithm)
+    }
+
+    /// Reads the reader once with large buffers, hashing it on the blocking thread pool.
+    pub async fn digest_reader<R>(self, reader: R) -> io::Result<PayloadDigests>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let digester = self.digester.on_blocking_pool();
+        let digests = digester.digest_reader(reader).await?;
+        Ok(to_payload_digests(digests, self.checksum_algorithm))
+    }
+}
+
+fn to_payload_digests(
+    digests: Digests,
+    checksum_algorithm: Option<ChecksumAlgorithm>,
+) -> PayloadDigests {
+    PayloadDigests {
+        hash: digests.hashed_payload().expect("SHA-256 is requested"),
+        content_md5: digests.content_md5().expect("MD5 is requested"),
+        checksum: checksum_algorithm.and_then(|x| Checksum::from_digests(x, &digests)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PayloadDigester;
+    use crate::core::checksum::ChecksumAlgorithm;
+
+    #[test]
+    fn it_calculates_all_digests() {
+        let mut digester = PayloadDigester::new(Some(ChecksumAlgorithm::Crc32));
+        digester.update(b"Hello, ");
+        digester.update(b"World!");
+
+        let digests = digester.finish();
+        assert_eq!(
+            digests.hash.as_str(),
+            "dffd6021bb2bd5b0af676290809ec3a53191dd81c7f70a4b28688a362182986f"
+        );
+        assert_eq!(digests.content_md5.as_str(), "ZajifYh5KDgxtmS9i38K1A==");
+        assert_eq!(digests.checksum.unwrap().as_str(), "7ErD0A==");
+    }
+}
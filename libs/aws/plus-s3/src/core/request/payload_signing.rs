@@ -0,0 +1,66 @@
+use crate::core;
+use plus_aws::auth::v4::streaming::StreamingPayload;
+use url::Url;
+
+/// How the payload of uploads is signed.
+///
+/// rf.
+/// [Authenticating Requests: Using the Authorization Header (AWS Signature Version 4)](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-auth-using-authorization-header.html)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PayloadSigning {
+    /// Hashes the whole payload before sending it, so that the source is read twice.
+    #[default]
+    Signed,
+    /// Sends the payload as `UNSIGNED-PAYLOAD` without hashing it,
+    /// which is allowed only over HTTPS.
+    Unsigned,
+    /// Sends the payload by `aws-chunked` encoding, signing each chunk as it is read.
+    Streaming,
+    /// Sends the payload by `aws-chunked` encoding without signing the chunks,
+    /// protected by the trailing checksum instead, which is allowed only over HTTPS.
+    StreamingUnsignedTrailer,
+}
+
+impl PayloadSigning {
+    /// Returns `None` if the payload is not streamed.
+    pub fn to_streaming_payload(&self, has_trailer: bool) -> Option<StreamingPayload> {
+        match self {
+            PayloadSigning::Signed | PayloadSigning::Unsigned => None,
+            PayloadSigning::Streaming if has_trailer => Some(StreamingPayload::SignedWithTrailer),
+            PayloadSigning::Streaming => Some(StreamingPayload::Signed),
+            PayloadSigning::StreamingUnsignedTrailer => Some(StreamingPayload::UnsignedWithTrailer),
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(self, PayloadSigning::Signed | PayloadSigning::Streaming)
+    }
+
+    /// Refuses to send the unsigned payload over plain HTTP,
+    /// where nothing protects it from being tampered with.
+    pub fn validate(&self, url: &Url) -> core::Result<()> {
+        if !self.is_signed() && url.scheme() != "https" {
+            return Err(core::Error::UnsignedPayloadOverHttp(url.clone()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PayloadSigning;
+    use url::Url;
+
+    #[test]
+    fn it_refuses_unsigned_payload_over_http() {
+        let http = Url::parse("http://localhost:9000/bucket/key").unwrap();
+        let https = Url::parse("https://bucket.s3.amazonaws.com/key").unwrap();
+        assert!(PayloadSigning::Signed.validate(&http).is_ok());
+        assert!(PayloadSigning::Streaming.validate(&http).is_ok());
+        assert!(PayloadSigning::Unsigned.validate(&http).is_err());
+        assert!(PayloadSigning::StreamingUnsignedTrailer
+            .validate(&http)
+            .is_err());
+        assert!(PayloadSigning::Unsigned.validate(&https).is_ok());
+    }
+}
@@ -0,0 +1,61 @@
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use plus_aws::http::request::header::ContentType;
+use plus_aws::index::RegionCode;
+use std::time::Duration;
+
+/// The object and the period that the holder of a presigned URL can access.
+#[derive(Debug)]
+pub struct PresignRequest {
+    object_key: String,
+    expires_in: Duration,
+    region: Option<RegionCode>,
+    content_type: Option<ContentType>,
+}
+
+impl PresignRequest {
+    pub fn new<A: Into<String>>(object_key: A, expires_in: Duration) -> Self {
+        PresignRequest {
+            object_key: object_key.into(),
+            expires_in,
+            region: None,
+            content_type: None,
+        }
+    }
+
+    /// Overrides the region of the client, which is not corrected by redirects
+    /// since the URL is sent by someone else.
+    pub fn with_region(self, region: RegionCode) -> Self {
+        PresignRequest {
+            region: Some(region),
+            ..self
+        }
+    }
+
+    /// Signed for uploads, so the uploader has to send the same `Content-Type`.
+    pub fn with_content_type(self, content_type: ContentType) -> Self {
+        PresignRequest {
+            content_type: Some(content_type),
+            ..self
+        }
+    }
+
+    pub fn expires_in(&self) -> Duration {
+        self.expires_in
+    }
+
+    pub fn region(&self) -> Option<&RegionCode> {
+        self.region.as_ref()
+    }
+
+    pub fn content_type(&self) -> Option<&ContentType> {
+        self.content_type.as_ref()
+    }
+}
+
+impl HasObjectKey for PresignRequest {
+    fn get_object_key(&self) -> &str {
+        &self.object_key
+    }
+}
+
+impl HasQueryParams for PresignRequest {}
@@ -0,0 +1,69 @@
+use crate::core::request::ByteStream;
+use bytes::Bytes;
+use futures_util::stream;
+use std::fmt;
+
+/// Body of the request, which is sent as a stream
+/// so that the progress of the upload can be watched by the read timeout.
+pub enum RequestBody {
+    Bytes(Bytes),
+    Stream(ByteStream),
+}
+
+impl RequestBody {
+    /// Small enough to tell the progress of the bytes, and cheap since they are not copied.
+    const CHUNK_SIZE: usize = 64 * 1024;
+
+    pub fn into_stream(self) -> ByteStream {
+        match self {
+            RequestBody::Bytes(bytes) => {
+                let chunks = (0..bytes.len())
+                    .step_by(RequestBody::CHUNK_SIZE)
+                    .map(move |start| {
+                        let end = bytes.len().min(start + RequestBody::CHUNK_SIZE);
+                        Ok(bytes.slice(start..end))
+                    });
+                Box::pin(stream::iter(chunks))
+            }
+            RequestBody::Stream(stream) => stream,
+        }
+    }
+}
+
+impl From<Bytes> for RequestBody {
+    fn from(bytes: Bytes) -> Self {
+        RequestBody::Bytes(bytes)
+    }
+}
+
+impl From<Vec<u8>> for RequestBody {
+    fn from(bytes: Vec<u8>) -> Self {
+        RequestBody::Bytes(Bytes::from(bytes))
+    }
+}
+
+impl fmt::Debug for RequestBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestBody::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            RequestBody::Stream(_) => f.debug_tuple("Stream").finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RequestBody;
+    use bytes::Bytes;
+    use futures::executor::block_on;
+    use futures_util::TryStreamExt;
+
+    #[test]
+    fn it_splits_bytes_into_chunks() {
+        let bytes = Bytes::from(vec![b'a'; RequestBody::CHUNK_SIZE + 10]);
+        let stream = RequestBody::from(bytes).into_stream();
+        let chunks: Vec<Bytes> = block_on(stream.try_collect()).unwrap();
+        let lengths: Vec<usize> = chunks.iter().map(|chunk| chunk.len()).collect();
+        assert_eq!(lengths, vec![RequestBody::CHUNK_SIZE, 10]);
+    }
+}
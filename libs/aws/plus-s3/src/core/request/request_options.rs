@@ -0,0 +1,90 @@
+use crate::core::request::PayloadSigning;
+use plus_aws::http::request::{HeaderFragment, ToHeaderFragment};
+
+/// Options which can be applied to any request.
+///
+/// rf.
+/// [Using Requester Pays buckets - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/userguide/RequesterPaysBuckets.html)
+/// [Verifying bucket ownership - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/userguide/bucket-owner-condition.html)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RequestOptions {
+    pub request_payer: Option<RequestPayer>,
+    /// Account ID of the expected bucket owner.
+    pub expected_bucket_owner: Option<String>,
+    /// How the payload of uploads is signed, which sends no header by itself.
+    pub payload_signing: Option<PayloadSigning>,
+}
+
+impl RequestOptions {
+    /// Returns options which prefer the given overrides to `self`.
+    pub fn merge(&self, overrides: &RequestOptions) -> RequestOptions {
+        RequestOptions {
+            request_payer: overrides
+                .request_payer
+                .clone()
+                .or_else(|| self.request_payer.clone()),
+            expected_bucket_owner: overrides
+                .expected_bucket_owner
+                .clone()
+                .or_else(|| self.expected_bucket_owner.clone()),
+            payload_signing: overrides.payload_signing.or(self.payload_signing),
+        }
+    }
+
+    pub fn to_headers(&self) -> plus_aws::Result<Vec<HeaderFragment>> {
+        let mut headers = vec![];
+        if let Some(payer) = &self.request_payer {
+            headers.push(ToHeaderFragment::into((
+                "x-amz-request-payer",
+                payer.as_str(),
+            ))?);
+        }
+        if let Some(owner) = &self.expected_bucket_owner {
+            headers.push(ToHeaderFragment::into((
+                "x-amz-expected-bucket-owner",
+                owner.as_str(),
+            ))?);
+        }
+        Ok(headers)
+    }
+}
+
+/// Confirms that the requester knows they will be charged for the request.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RequestPayer {
+    Requester,
+}
+
+impl RequestPayer {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RequestPayer::Requester => "requester",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RequestOptions, RequestPayer};
+    use crate::core::request::PayloadSigning;
+
+    #[test]
+    fn it_prefers_overrides() {
+        let defaults = RequestOptions {
+            request_payer: Some(RequestPayer::Requester),
+            expected_bucket_owner: Some("111122223333".to_string()),
+            payload_signing: Some(PayloadSigning::Streaming),
+        };
+        let overrides = RequestOptions {
+            expected_bucket_owner: Some("444455556666".to_string()),
+            ..Default::default()
+        };
+        let merged = defaults.merge(&overrides);
+        assert_eq!(merged.request_payer, Some(RequestPayer::Requester));
+        assert_eq!(
+            merged.expected_bucket_owner.as_deref(),
+            Some("444455556666")
+        );
+        assert_eq!(merged.payload_signing, Some(PayloadSigning::Streaming));
+    }
+}
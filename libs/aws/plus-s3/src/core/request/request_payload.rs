@@ -0,0 +1,9 @@
+use crate::core::checksum::ChecksumAlgorithm;
+use crate::core::request::BodySource;
+
+/// Body of the upload, which is digested in the way of `PayloadSigning`.
+#[derive(Debug)]
+pub struct RequestPayload<'a> {
+    pub source: BodySource<'a>,
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+}
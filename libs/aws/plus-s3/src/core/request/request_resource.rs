@@ -1,20 +1,128 @@
 use crate::core;
+use crate::core::checksum::ChecksumAlgorithm;
+use crate::core::request::{
+    PayloadSigning, RequestBody, RequestOptions, RequestPayload, StreamingBody,
+};
 use chrono::{DateTime, Utc};
 use plus_aws::auth::v4::canonical::HashedPayload;
-use plus_aws::http::request::header::ContentType;
+use plus_aws::auth::v4::chrono::now;
+use plus_aws::http::request::header::{ContentMd5, ContentType};
+use plus_aws::http::request::HeaderFragment;
 use plus_aws::index::RegionCode;
-use reqwest::Body;
 
 pub struct RequestResource<'a> {
-    pub body: Option<Body>,
+    pub body: Option<RequestBody>,
+    /// Replaces `body` when it is loaded in the way of `PayloadSigning`.
+    pub payload: Option<RequestPayload<'a>>,
     pub hash: HashedPayload,
     pub region: Option<&'a RegionCode>,
     pub content_type: Option<&'a ContentType>,
     pub content_length: u64,
+    pub content_md5: Option<ContentMd5>,
+    /// Additional headers to be signed, such as `x-amz-*`.
+    pub headers: Vec<HeaderFragment>,
+    /// Overrides the default options of the client.
+    pub options: RequestOptions,
     pub requested_at: DateTime<Utc>,
 }
 
+impl<'a> RequestResource<'a> {
+    /// Creates a resource which has no body.
+    pub fn empty() -> RequestResource<'a> {
+        RequestResource {
+            body: None,
+            payload: None,
+            hash: HashedPayload::empty(),
+            region: None,
+            content_type: None,
+            content_length: 0,
+            content_md5: None,
+            headers: vec![],
+            options: RequestOptions::default(),
+            requested_at: now(),
+        }
+    }
+
+    /// Creates a resource which sends the given XML document with its Content-MD5.
+    pub fn xml(document: String) -> RequestResource<'a> {
+        let bytes = document.into_bytes();
+        RequestResource {
+            hash: HashedPayload::from_bytes(&bytes),
+            content_length: bytes.len() as u64,
+            content_md5: Some(ContentMd5::from_bytes(&bytes)),
+            body: Some(RequestBody::from(bytes)),
+            ..Self::empty()
+        }
+    }
+
+    /// Loads the payload, returning the body to be signed by chunks if it is streamed.
+    ///
+    /// The unsigned payload is always followed by the checksum,
+    /// which is CRC32 unless the algorithm is specified.
+    pub async fn load_payload(
+        mut self,
+        signing: PayloadSigning,
+    ) -> core::Result<(RequestResource<'a>, Option<StreamingBody>)> {
+        let payload = match self.payload.take() {
+            Some(payload) => payload,
+            None => return Ok((self, None)),
+        };
+        let checksum_algorithm = match signing {
+            PayloadSigning::StreamingUnsignedTrailer => payload
+                .checksum_algorithm
+                .or(Some(ChecksumAlgorithm::Crc32)),
+            _ => payload.checksum_algorithm,
+        };
+        match signing.to_streaming_payload(checksum_algorithm.is_some()) {
+            Some(streaming) => {
+                let body = payload
+                    .source
+                    .to_streaming_body(streaming, checksum_algorithm)
+                    .await?;
+                self.hash = body.hashed_payload();
+                self.content_length = body.content_length();
+                self.content_md5 = None;
+                self.headers.extend(body.headers()?);
+                Ok((self, Some(body)))
+            }
+            None => {
+                let loaded = match signing {
+                    PayloadSigning::Unsigned => {
+                        payload
+                            .source
+                            .to_unsigned_resource(checksum_algorithm)
+                            .await?
+                    }
+                    _ => payload.source.to_resource(checksum_algorithm).await?,
+                };
+                self.body = loaded.body;
+                self.hash = loaded.hash;
+                self.content_length = loaded.content_length;
+                self.content_md5 = loaded.content_md5;
+                self.headers.extend(loaded.headers);
+                Ok((self, None))
+            }
+        }
+    }
+}
+
 #[async_trait]
 pub trait ResourceLoader {
     async fn load(&self) -> core::Result<RequestResource>;
+
+    /// Tells whether the request can be loaded again to be retried or redirected.
+    fn is_replayable(&self) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+impl<A: ResourceLoader + Sync> ResourceLoader for &mut A {
+    async fn load(&self) -> core::Result<RequestResource> {
+        (**self).load().await
+    }
+
+    fn is_replayable(&self) -> bool {
+        (**self).is_replayable()
+    }
 }
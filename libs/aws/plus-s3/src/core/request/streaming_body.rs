@@ -0,0 +1,216 @@
+use crate::core::checksum::{ChecksumAlgorithm, Checksummer};
+use crate::core::request::{ByteStream, RequestBody};
+use bytes::{Bytes, BytesMut};
+use futures_util::stream::{self, Stream, StreamExt};
+use plus_aws::auth::v4::canonical::HashedPayload;
+use plus_aws::auth::v4::streaming::{ChunkEncoder, StreamingPayload};
+use plus_aws::http::request::{HeaderFragment, ToHeaderFragment};
+use std::io;
+
+/// Payload sent by `aws-chunked` encoding,
+/// whose source is read only once while it is sent.
+///
+/// rf.
+/// [Signature Calculations for the Authorization Header: Transferring Payload in Multiple Chunks (Chunked Upload)](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming.html)
+pub struct StreamingBody {
+    source: ByteStream,
+    decoded_length: u64,
+    payload: StreamingPayload,
+    checksum_algorithm: Option<ChecksumAlgorithm>,
+}
+
+impl StreamingBody {
+    /// Size of each chunk except the last one, which must be at least 8 KB.
+    pub const CHUNK_SIZE: usize = 64 * 1024;
+
+    pub fn new(
+        source: ByteStream,
+        decoded_length: u64,
+        payload: StreamingPayload,
+        checksum_algorithm: Option<ChecksumAlgorithm>,
+    ) -> Self {
+        StreamingBody {
+            source,
+            decoded_length,
+            payload,
+            checksum_algorithm,
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        self.payload.is_signed()
+    }
+
+    pub fn hashed_payload(&self) -> HashedPayload {
+        self.payload.to_hashed_payload()
+    }
+
+    /// Length of the encoded payload, which is sent as `Content-Length`.
+    pub fn content_length(&self) -> u64 {
+        let trailer_length = self
+            .checksum_algorithm
+            .map(|algorithm| (algorithm.header_name().len() + 1 + algorithm.value_length()) as u64);
+
+        ChunkEncoder::encoded_length(
+            self.is_signed(),
+            self.decoded_length,
+            Self::CHUNK_SIZE as u64,
+            trailer_length,
+        )
+    }
+
+    pub fn headers(&self) -> plus_aws::Result<Vec<HeaderFragment>> {
+        let mut headers = vec![
+            ToHeaderFragment::into(("content-encoding", "aws-chunked"))?,
+            ToHeaderFragment::into((
+                "x-amz-decoded-content-length",
+                self.decoded_length.to_string().as_str(),
+            ))?,
+        ];
+        if let Some(algorithm) = &self.checksum_algorithm {
+            headers.push(ToHeaderFragment::into((
+                "x-amz-trailer",
+                algorithm.header_name(),
+            ))?);
+        }
+        Ok(headers)
+    }
+
+    pub fn into_body(self, encoder: ChunkEncoder) -> RequestBody {
+        RequestBody::Stream(Box::pin(self.into_stream(encoder)))
+    }
+
+    fn into_stream(self, encoder: ChunkEncoder) -> impl Stream<Item = io::Result<Bytes>> {
+        let state = EncodingState {
+            source: self.source,
+            buffer: BytesMut::new(),
+            encoder,
+            checksummer: self.checksum_algorithm.map(Checksummer::new),
+            decoded_length: self.decoded_length,
+            read_length: 0,
+            finished: false,
+        };
+        stream::unfold(state, |mut state| async move {
+            let chunk = state.next_chunk().await?;
+            Some((chunk, state))
+        })
+    }
+}
+
+struct EncodingState {
+    source: ByteStream,
+    buffer: BytesMut,
+    encoder: ChunkEncoder,
+    checksummer: Option<Checksummer>,
+    decoded_length: u64,
+    read_length: u64,
+    finished: bool,
+}
+
+impl EncodingState {
+    /// Reads the source until a chunk is filled, since the source yields the bytes of any size.
+    async fn next_chunk(&mut self) -> Option<io::Result<Bytes>> {
+        if self.finished {
+            return None;
+        }
+        while self.buffer.len() < StreamingBody::CHUNK_SIZE {
+            match self.source.next().await {
+                Some(Ok(bytes)) => {
+                    if let Some(checksummer) = self.checksummer.as_mut() {
+                        checksummer.update(&bytes);
+                    }
+                    self.read_length += bytes.len() as u64;
+                    self.buffer.extend_from_slice(&bytes);
+                }
+                Some(Err(e)) => {
+                    self.finished = true;
+                    return Some(Err(e));
+                }
+                None => break,
+            }
+        }
+        if !self.buffer.is_empty() {
+            let size = self.buffer.len().min(StreamingBody::CHUNK_SIZE);
+            let chunk = self.buffer.split_to(size);
+            return Some(Ok(self.encoder.encode(&chunk)));
+        }
+        self.finished = true;
+        if self.read_length != self.decoded_length {
+            return Some(Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "ContentLengthMismatch > expected: {}, actual: {}",
+                    self.decoded_length, self.read_length
+                ),
+            )));
+        }
+        let last = match self.checksummer.take() {
+            Some(checksummer) => {
+                let checksum = checksummer.finish();
+                self.encoder
+                    .encode_last_with_trailer(checksum.algorithm.header_name(), checksum.as_str())
+            }
+            None => self.encoder.encode(&[]),
+        };
+        Some(Ok(last))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamingBody;
+    use crate::core::checksum::ChecksumAlgorithm;
+    use bytes::Bytes;
+    use futures::executor::block_on;
+    use futures_util::stream::{self, TryStreamExt};
+    use plus_aws::auth::v4::streaming::{ChunkEncoder, StreamingPayload};
+    use std::io;
+
+    fn create_body(pieces: Vec<Bytes>, decoded_length: u64) -> StreamingBody {
+        let source = stream::iter(pieces.into_iter().map(Ok));
+        StreamingBody::new(
+            Box::pin(source),
+            decoded_length,
+            StreamingPayload::UnsignedWithTrailer,
+            Some(ChecksumAlgorithm::Crc32),
+        )
+    }
+
+    fn encode(body: StreamingBody) -> io::Result<Vec<u8>> {
+        let stream = body.into_stream(ChunkEncoder::unsigned());
+        let chunks: Vec<Bytes> = block_on(stream.try_collect())?;
+        Ok(chunks.concat())
+    }
+
+    #[test]
+    fn it_sends_trailing_checksum() -> io::Result<()> {
+        let body = create_body(vec!["Hello, ".into(), "World!".into()], 13);
+        let content_length = body.content_length();
+        let encoded = encode(body)?;
+        assert_eq!(
+            String::from_utf8(encoded.clone()).unwrap(),
+            "d\r\nHello, World!\r\n0\r\nx-amz-checksum-crc32:7ErD0A==\r\n\r\n"
+        );
+        assert_eq!(encoded.len() as u64, content_length);
+        Ok(())
+    }
+
+    #[test]
+    fn it_splits_source_into_chunks() -> io::Result<()> {
+        let mut data = Bytes::from(vec![b'a'; StreamingBody::CHUNK_SIZE + 10]);
+        let head = data.split_to(100);
+        let body = create_body(vec![head, data], StreamingBody::CHUNK_SIZE as u64 + 10);
+        let content_length = body.content_length();
+        let encoded = encode(body)?;
+        assert!(encoded.starts_with(b"10000\r\naaa"));
+        assert_eq!(encoded.len() as u64, content_length);
+        Ok(())
+    }
+
+    #[test]
+    fn it_fails_if_source_is_shorter_than_length() {
+        let body = create_body(vec!["Hello".into()], 13);
+        let error = encode(body).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::core;
+use crate::core::request::{RequestOptions, RequestResource, ResourceLoader};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
+use bytes::Bytes;
+use futures_util::stream::Stream;
+use plus_aws::io::stream::BodyReceiver;
+
+/// Wraps a request to override the default options of the client.
+#[derive(Debug)]
+pub struct WithOptions<A> {
+    pub request: A,
+    pub options: RequestOptions,
+}
+
+impl<A> WithOptions<A> {
+    pub fn new(request: A, options: RequestOptions) -> Self {
+        WithOptions { request, options }
+    }
+}
+
+impl<A: HasObjectKey> HasObjectKey for WithOptions<A> {
+    fn get_object_key(&self) -> &str {
+        self.request.get_object_key()
+    }
+}
+
+impl<A: HasQueryParams> HasQueryParams for WithOptions<A> {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        self.request.get_query_params()
+    }
+}
+
+#[async_trait]
+impl<A: ResourceLoader + Sync> ResourceLoader for WithOptions<A> {
+    async fn load(&self) -> core::Result<RequestResource<'_>> {
+        let resource = self.request.load().await?;
+        Ok(RequestResource {
+            options: resource.options.merge(&self.options),
+            ..resource
+        })
+    }
+
+    fn is_replayable(&self) -> bool {
+        self.request.is_replayable()
+    }
+}
+
+#[async_trait]
+impl<A: BodyReceiver + Send> BodyReceiver for WithOptions<A> {
+    type Err = A::Err;
+
+    async fn receive_body_from<S>(&mut self, body: S) -> Result<usize, Self::Err>
+    where
+        S: Stream<Item = Result<Bytes, Self::Err>>,
+        S: Send,
+    {
+        self.request.receive_body_from(body).await
+    }
+}
@@ -0,0 +1,9 @@
+string_header!(AcceptRanges, "Accept-Ranges");
+string_header!(CacheControl, "Cache-Control");
+string_header!(ContentDisposition, "Content-Disposition");
+string_header!(ContentEncoding, "Content-Encoding");
+string_header!(ContentLanguage, "Content-Language");
+parsed_header!(ContentLength(u64), "Content-Length");
+string_header!(ContentRange, "Content-Range");
+string_header!(ContentType, "Content-Type");
+string_header!(Expires, "Expires");
@@ -16,6 +16,19 @@ impl ETag {
     pub fn into_string(self) -> String {
         self.0
     }
+
+    /// Returns the MD5 digest in hex if the ETag is a plain one,
+    /// which is not the case for multipart objects (like `"...-3"`).
+    /// Note that ETags of objects encrypted by SSE-KMS or SSE-C are not MD5 either.
+    pub fn as_md5(&self) -> Option<&str> {
+        let value = self.0.trim_matches('"');
+        let is_md5 = value.len() == 32 && value.chars().all(|c| c.is_ascii_hexdigit());
+        if is_md5 {
+            Some(value)
+        } else {
+            None
+        }
+    }
 }
 
 impl<'a> AwsHeader<'a> for ETag {
@@ -25,3 +38,17 @@ impl<'a> AwsHeader<'a> for ETag {
         Ok(Self::new(a))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ETag;
+
+    #[test]
+    fn it_returns_md5_only_for_plain_etag() {
+        let plain = ETag::new("\"65a8e27d8879283831b664bd8b7f0ad4\"");
+        assert_eq!(plain.as_md5(), Some("65a8e27d8879283831b664bd8b7f0ad4"));
+
+        let multipart = ETag::new("\"d41d8cd98f00b204e9800998ecf8427e-2\"");
+        assert_eq!(multipart.as_md5(), None);
+    }
+}
@@ -0,0 +1,13 @@
+parsed_header!(
+    BucketKeyEnabled(bool),
+    "x-amz-server-side-encryption-bucket-key-enabled"
+);
+string_header!(
+    SseCustomerKeyMd5,
+    "x-amz-server-side-encryption-customer-key-md5"
+);
+string_header!(
+    SseKmsEncryptionContext,
+    "x-amz-server-side-encryption-context"
+);
+string_header!(SseKmsKeyId, "x-amz-server-side-encryption-aws-kms-key-id");
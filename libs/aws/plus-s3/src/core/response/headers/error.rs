@@ -10,4 +10,7 @@ pub enum Error {
         name, cause
     )]
     InvalidCharacters { name: String, cause: ToStrError },
+
+    #[fail(display = "invalid value found. name: {}, value: {}", name, value)]
+    InvalidValue { name: String, value: String },
 }
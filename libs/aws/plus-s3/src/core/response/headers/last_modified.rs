@@ -0,0 +1,23 @@
+use crate::core;
+use crate::core::response::headers::AwsHeader;
+
+#[derive(Debug)]
+pub struct LastModified(String);
+
+impl LastModified {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl<'a> AwsHeader<'a> for LastModified {
+    const HEADER_NAME: &'a str = "Last-Modified";
+
+    fn new<A: Into<String>>(a: A) -> core::Result<Self> {
+        Ok(Self(a.into()))
+    }
+}
@@ -0,0 +1,56 @@
+/// Defines a header which holds its value as it is.
+macro_rules! string_header {
+    ($(#[$meta:meta])* $name:ident, $header_name:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl<'a> crate::core::response::headers::AwsHeader<'a> for $name {
+            const HEADER_NAME: &'a str = $header_name;
+
+            fn new<A: Into<String>>(a: A) -> crate::core::Result<Self> {
+                Ok($name(a.into()))
+            }
+        }
+    };
+}
+
+/// Defines a header which holds its value parsed, like a number.
+macro_rules! parsed_header {
+    ($(#[$meta:meta])* $name:ident($type:ty), $header_name:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $name(pub $type);
+
+        impl $name {
+            pub fn value(&self) -> $type {
+                self.0
+            }
+        }
+
+        impl<'a> crate::core::response::headers::AwsHeader<'a> for $name {
+            const HEADER_NAME: &'a str = $header_name;
+
+            fn new<A: Into<String>>(a: A) -> crate::core::Result<Self> {
+                let value = a.into();
+                let parsed = value.parse().map_err(|_| {
+                    crate::core::response::headers::Error::InvalidValue {
+                        name: $header_name.to_string(),
+                        value: value.clone(),
+                    }
+                })?;
+                Ok($name(parsed))
+            }
+        }
+    };
+}
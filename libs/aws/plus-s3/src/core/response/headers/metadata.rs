@@ -0,0 +1,34 @@
+use crate::core;
+use crate::core::response::headers::Error;
+use reqwest::header::HeaderMap;
+use std::collections::BTreeMap;
+
+const PREFIX: &str = "x-amz-meta-";
+
+/// User-defined metadata given by `x-amz-meta-*` headers, keyed without the prefix.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Metadata(BTreeMap<String, String>);
+
+impl Metadata {
+    pub fn find_in(map: &HeaderMap) -> core::Result<Metadata> {
+        let mut metadata = BTreeMap::new();
+        for (name, value) in map {
+            if let Some(key) = name.as_str().strip_prefix(PREFIX) {
+                let value = value.to_str().map_err(|cause| Error::InvalidCharacters {
+                    name: name.to_string(),
+                    cause,
+                })?;
+                metadata.insert(key.to_string(), value.to_string());
+            }
+        }
+        Ok(Metadata(metadata))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    pub fn into_map(self) -> BTreeMap<String, String> {
+        self.0
+    }
+}
@@ -4,9 +4,45 @@ use reqwest::header::HeaderMap;
 mod error;
 pub use error::Error;
 
+#[macro_use]
+mod macros;
+
+mod content;
+pub use content::{
+    AcceptRanges, CacheControl, ContentDisposition, ContentEncoding, ContentLanguage,
+    ContentLength, ContentRange, ContentType, Expires,
+};
+
 mod e_tag;
 pub use e_tag::ETag;
 
+mod encryption;
+pub use encryption::{BucketKeyEnabled, SseCustomerKeyMd5, SseKmsEncryptionContext, SseKmsKeyId};
+
+mod last_modified;
+pub use last_modified::LastModified;
+
+mod metadata;
+pub use metadata::Metadata;
+
+mod object;
+pub use object::{
+    Expiration, MissingMeta, ObjectLockLegalHoldStatus, ObjectLockMode, ObjectLockRetainUntilDate,
+    PartsCount, RequestCharged, Restore, TagCount, WebsiteRedirectLocation,
+};
+
+mod replication_status;
+pub use replication_status::ReplicationStatus;
+
+mod server_side_encryption;
+pub use server_side_encryption::ServerSideEncryption;
+
+mod sse_customer_algorithm;
+pub use sse_customer_algorithm::SseCustomerAlgorithm;
+
+mod version_id;
+pub use version_id::VersionId;
+
 pub trait AwsHeader<'a>: Sized {
     const HEADER_NAME: &'a str;
     fn new<A: Into<String>>(a: A) -> core::Result<Self>;
@@ -14,22 +50,30 @@ pub trait AwsHeader<'a>: Sized {
 
 pub trait AwsHeaderMap {
     fn as_required<'a, A: AwsHeader<'a>>(&self) -> core::Result<A>;
+
+    fn as_optional<'a, A: AwsHeader<'a>>(&self) -> core::Result<Option<A>>;
 }
 
 impl AwsHeaderMap for HeaderMap {
     fn as_required<'a, A: AwsHeader<'a>>(&self) -> core::Result<A> {
         let value = self
-            .get(A::HEADER_NAME)
-            .ok_or_else(|| Error::HeaderNotFound(A::HEADER_NAME.into()))?
-            .to_str()
-            .or_else(|cause| {
-                Err(Error::InvalidCharacters {
+            .as_optional()?
+            .ok_or_else(|| Error::HeaderNotFound(A::HEADER_NAME.into()))?;
+
+        Ok(value)
+    }
+
+    fn as_optional<'a, A: AwsHeader<'a>>(&self) -> core::Result<Option<A>> {
+        let value = match self.get(A::HEADER_NAME) {
+            None => None,
+            Some(value) => {
+                let value = value.to_str().map_err(|cause| Error::InvalidCharacters {
                     name: A::HEADER_NAME.into(),
                     cause,
-                })
-            })
-            .map(A::new)?;
-
-        Ok(value?)
+                })?;
+                Some(A::new(value)?)
+            }
+        };
+        Ok(value)
     }
 }
@@ -0,0 +1,22 @@
+string_header!(
+    /// Expiration date and the rule ID configured by the lifecycle,
+    /// like `expiry-date="Fri, 23 Dec 2022 00:00:00 GMT", rule-id="rule-1"`.
+    Expiration,
+    "x-amz-expiration"
+);
+parsed_header!(
+    /// Number of metadata entries which are not returned in `x-amz-meta-*` headers.
+    MissingMeta(u32),
+    "x-amz-missing-meta"
+);
+string_header!(ObjectLockLegalHoldStatus, "x-amz-object-lock-legal-hold");
+string_header!(ObjectLockMode, "x-amz-object-lock-mode");
+string_header!(
+    ObjectLockRetainUntilDate,
+    "x-amz-object-lock-retain-until-date"
+);
+parsed_header!(PartsCount(u32), "x-amz-mp-parts-count");
+string_header!(RequestCharged, "x-amz-request-charged");
+string_header!(Restore, "x-amz-restore");
+parsed_header!(TagCount(u32), "x-amz-tagging-count");
+string_header!(WebsiteRedirectLocation, "x-amz-website-redirect-location");
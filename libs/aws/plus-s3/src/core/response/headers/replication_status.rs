@@ -0,0 +1,41 @@
+use crate::core;
+use crate::core::response::headers::AwsHeader;
+
+/// rf.
+/// [Getting replication status information - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/userguide/replication-status.html)
+#[derive(Debug, PartialEq)]
+pub enum ReplicationStatus {
+    Complete,
+    Pending,
+    Failed,
+    Replica,
+    Unknown(String),
+}
+
+impl ReplicationStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReplicationStatus::Complete => "COMPLETE",
+            ReplicationStatus::Pending => "PENDING",
+            ReplicationStatus::Failed => "FAILED",
+            ReplicationStatus::Replica => "REPLICA",
+            ReplicationStatus::Unknown(x) => x,
+        }
+    }
+}
+
+impl<'a> AwsHeader<'a> for ReplicationStatus {
+    const HEADER_NAME: &'a str = "x-amz-replication-status";
+
+    fn new<A: Into<String>>(a: A) -> core::Result<Self> {
+        let value = a.into();
+        let status = match value.as_str() {
+            "COMPLETE" | "COMPLETED" => ReplicationStatus::Complete,
+            "PENDING" => ReplicationStatus::Pending,
+            "FAILED" => ReplicationStatus::Failed,
+            "REPLICA" => ReplicationStatus::Replica,
+            _ => ReplicationStatus::Unknown(value),
+        };
+        Ok(status)
+    }
+}
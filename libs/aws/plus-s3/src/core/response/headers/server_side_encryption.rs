@@ -0,0 +1,45 @@
+use crate::core;
+use crate::core::response::headers::AwsHeader;
+
+/// rf.
+/// [Protecting data with server-side encryption - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/userguide/serv-side-encryption.html)
+#[derive(Debug, PartialEq)]
+pub enum ServerSideEncryption {
+    Aes256,
+    AwsKms,
+    AwsKmsDsse,
+    Unknown(String),
+}
+
+impl ServerSideEncryption {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ServerSideEncryption::Aes256 => "AES256",
+            ServerSideEncryption::AwsKms => "aws:kms",
+            ServerSideEncryption::AwsKmsDsse => "aws:kms:dsse",
+            ServerSideEncryption::Unknown(x) => x,
+        }
+    }
+
+    pub fn is_kms(&self) -> bool {
+        matches!(
+            self,
+            ServerSideEncryption::AwsKms | ServerSideEncryption::AwsKmsDsse
+        )
+    }
+}
+
+impl<'a> AwsHeader<'a> for ServerSideEncryption {
+    const HEADER_NAME: &'a str = "x-amz-server-side-encryption";
+
+    fn new<A: Into<String>>(a: A) -> core::Result<Self> {
+        let value = a.into();
+        let encryption = match value.as_str() {
+            "AES256" => ServerSideEncryption::Aes256,
+            "aws:kms" => ServerSideEncryption::AwsKms,
+            "aws:kms:dsse" => ServerSideEncryption::AwsKmsDsse,
+            _ => ServerSideEncryption::Unknown(value),
+        };
+        Ok(encryption)
+    }
+}
@@ -0,0 +1,24 @@
+use crate::core;
+use crate::core::response::headers::AwsHeader;
+
+/// Returned when the object is encrypted with a customer-provided key (SSE-C).
+#[derive(Debug)]
+pub struct SseCustomerAlgorithm(String);
+
+impl SseCustomerAlgorithm {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl<'a> AwsHeader<'a> for SseCustomerAlgorithm {
+    const HEADER_NAME: &'a str = "x-amz-server-side-encryption-customer-algorithm";
+
+    fn new<A: Into<String>>(a: A) -> core::Result<Self> {
+        Ok(SseCustomerAlgorithm(a.into()))
+    }
+}
@@ -0,0 +1,23 @@
+use crate::core;
+use crate::core::response::headers::AwsHeader;
+
+#[derive(Debug)]
+pub struct VersionId(String);
+
+impl VersionId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl<'a> AwsHeader<'a> for VersionId {
+    const HEADER_NAME: &'a str = "x-amz-version-id";
+
+    fn new<A: Into<String>>(a: A) -> core::Result<Self> {
+        Ok(Self(a.into()))
+    }
+}
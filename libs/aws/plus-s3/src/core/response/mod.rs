@@ -1 +1,7 @@
 pub mod headers;
+
+mod s3_error_body;
+pub use s3_error_body::S3ErrorBody;
+
+mod s3_error_code;
+pub use s3_error_code::S3ErrorCode;
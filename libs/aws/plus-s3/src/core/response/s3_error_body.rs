@@ -0,0 +1,94 @@
+use crate::core;
+use crate::core::response::S3ErrorCode;
+
+/// The `<Error>` element returned with a failed response.
+///
+/// rf.
+/// [Error responses - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/API/ErrorResponses.html#RESTErrorResponses)
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename = "Error")]
+pub struct S3ErrorBody {
+    #[serde(rename = "Code")]
+    pub code: S3ErrorCode,
+
+    #[serde(rename = "Message", default)]
+    pub message: Option<String>,
+
+    #[serde(rename = "RequestId", default)]
+    pub request_id: Option<String>,
+
+    #[serde(rename = "HostId", default)]
+    pub host_id: Option<String>,
+
+    #[serde(rename = "Resource", default)]
+    pub resource: Option<String>,
+
+    #[serde(rename = "BucketName", default)]
+    pub bucket_name: Option<String>,
+
+    #[serde(rename = "Key", default)]
+    pub key: Option<String>,
+
+    /// Sent with `AuthorizationHeaderMalformed` and `PermanentRedirect`.
+    #[serde(rename = "Region", default)]
+    pub region: Option<String>,
+
+    /// Sent with `PermanentRedirect` and `TemporaryRedirect`.
+    #[serde(rename = "Endpoint", default)]
+    pub endpoint: Option<String>,
+}
+
+impl S3ErrorBody {
+    pub fn from_xml(xml: &str) -> core::Result<S3ErrorBody> {
+        core::xml::from_str(xml)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::S3ErrorBody;
+    use crate::core;
+    use crate::core::response::S3ErrorCode;
+
+    #[test]
+    fn it_parses_error_xml() -> core::Result<()> {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <Error>
+              <Code>NoSuchKey</Code>
+              <Message>The resource you requested does not exist</Message>
+              <Key>mykey.txt</Key>
+              <RequestId>4442587FB7D0A2F9</RequestId>
+              <HostId>uHnVkd0Jjw0nYd3B8lSk=</HostId>
+            </Error>"#;
+        let body = S3ErrorBody::from_xml(xml)?;
+        assert_eq!(body.code, S3ErrorCode::NoSuchKey);
+        assert_eq!(
+            body.message.as_deref(),
+            Some("The resource you requested does not exist")
+        );
+        assert_eq!(body.key.as_deref(), Some("mykey.txt"));
+        assert_eq!(body.request_id.as_deref(), Some("4442587FB7D0A2F9"));
+        assert_eq!(body.host_id.as_deref(), Some("uHnVkd0Jjw0nYd3B8lSk="));
+        assert_eq!(body.bucket_name, None);
+        Ok(())
+    }
+
+    #[test]
+    fn it_parses_redirect_fields() -> core::Result<()> {
+        let xml = r#"<Error>
+              <Code>PermanentRedirect</Code>
+              <Message>The bucket you are attempting to access must be addressed using the specified endpoint.</Message>
+              <Endpoint>example-bucket.s3.eu-west-1.amazonaws.com</Endpoint>
+              <Bucket>example-bucket</Bucket>
+              <Region>eu-west-1</Region>
+            </Error>"#;
+        let body = S3ErrorBody::from_xml(xml)?;
+        assert_eq!(body.code, S3ErrorCode::PermanentRedirect);
+        assert_eq!(body.region.as_deref(), Some("eu-west-1"));
+        assert_eq!(
+            body.endpoint.as_deref(),
+            Some("example-bucket.s3.eu-west-1.amazonaws.com")
+        );
+        Ok(())
+    }
+}
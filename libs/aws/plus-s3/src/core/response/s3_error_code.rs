@@ -0,0 +1,280 @@
+use reqwest::StatusCode;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// rf.
+/// [Error responses - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/API/ErrorResponses.html#ErrorCodeList)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum S3ErrorCode {
+    AccessDenied,
+    AuthorizationHeaderMalformed,
+    BadDigest,
+    BadRequest,
+    BandwidthLimitExceeded,
+    BucketAlreadyExists,
+    BucketAlreadyOwnedByYou,
+    BucketNotEmpty,
+    EntityTooLarge,
+    EntityTooSmall,
+    ExpiredToken,
+    IllegalLocationConstraintException,
+    InternalError,
+    InvalidAccessKeyId,
+    InvalidArgument,
+    InvalidBucketName,
+    InvalidDigest,
+    InvalidObjectState,
+    InvalidPart,
+    InvalidPartOrder,
+    InvalidRange,
+    InvalidRequest,
+    InvalidToken,
+    KeyTooLongError,
+    MalformedXml,
+    MethodNotAllowed,
+    NoSuchBucket,
+    NoSuchBucketPolicy,
+    NoSuchKey,
+    NoSuchPublicAccessBlockConfiguration,
+    NoSuchUpload,
+    NoSuchVersion,
+    NotFound,
+    NotImplemented,
+    NotModified,
+    OwnershipControlsNotFoundError,
+    PermanentRedirect,
+    PreconditionFailed,
+    ReplicationConfigurationNotFoundError,
+    RequestTimeTooSkewed,
+    RequestLimitExceeded,
+    RequestTimeout,
+    RequestThrottled,
+    ServiceUnavailable,
+    SignatureDoesNotMatch,
+    SlowDown,
+    Throttling,
+    ThrottlingException,
+    TooManyRequestsException,
+    TemporaryRedirect,
+    XAmzContentSha256Mismatch,
+    Unknown(String),
+}
+
+impl S3ErrorCode {
+    pub fn new<A: Into<String>>(value: A) -> Self {
+        let value = value.into();
+        match value.as_str() {
+            "AccessDenied" => S3ErrorCode::AccessDenied,
+            "AuthorizationHeaderMalformed" => S3ErrorCode::AuthorizationHeaderMalformed,
+            "BadDigest" => S3ErrorCode::BadDigest,
+            "BadRequest" => S3ErrorCode::BadRequest,
+            "BandwidthLimitExceeded" => S3ErrorCode::BandwidthLimitExceeded,
+            "BucketAlreadyExists" => S3ErrorCode::BucketAlreadyExists,
+            "BucketAlreadyOwnedByYou" => S3ErrorCode::BucketAlreadyOwnedByYou,
+            "BucketNotEmpty" => S3ErrorCode::BucketNotEmpty,
+            "EntityTooLarge" => S3ErrorCode::EntityTooLarge,
+            "EntityTooSmall" => S3ErrorCode::EntityTooSmall,
+            "ExpiredToken" => S3ErrorCode::ExpiredToken,
+            "IllegalLocationConstraintException" => S3ErrorCode::IllegalLocationConstraintException,
+            "InternalError" => S3ErrorCode::InternalError,
+            "InvalidAccessKeyId" => S3ErrorCode::InvalidAccessKeyId,
+            "InvalidArgument" => S3ErrorCode::InvalidArgument,
+            "InvalidBucketName" => S3ErrorCode::InvalidBucketName,
+            "InvalidDigest" => S3ErrorCode::InvalidDigest,
+            "InvalidObjectState" => S3ErrorCode::InvalidObjectState,
+            "InvalidPart" => S3ErrorCode::InvalidPart,
+            "InvalidPartOrder" => S3ErrorCode::InvalidPartOrder,
+            "InvalidRange" => S3ErrorCode::InvalidRange,
+            "InvalidRequest" => S3ErrorCode::InvalidRequest,
+            "InvalidToken" => S3ErrorCode::InvalidToken,
+            "KeyTooLongError" => S3ErrorCode::KeyTooLongError,
+            "MalformedXML" => S3ErrorCode::MalformedXml,
+            "MethodNotAllowed" => S3ErrorCode::MethodNotAllowed,
+            "NoSuchBucket" => S3ErrorCode::NoSuchBucket,
+            "NoSuchBucketPolicy" => S3ErrorCode::NoSuchBucketPolicy,
+            "NoSuchKey" => S3ErrorCode::NoSuchKey,
+            "NoSuchPublicAccessBlockConfiguration" => {
+                S3ErrorCode::NoSuchPublicAccessBlockConfiguration
+            }
+            "NoSuchUpload" => S3ErrorCode::NoSuchUpload,
+            "NoSuchVersion" => S3ErrorCode::NoSuchVersion,
+            "NotFound" => S3ErrorCode::NotFound,
+            "NotImplemented" => S3ErrorCode::NotImplemented,
+            "NotModified" => S3ErrorCode::NotModified,
+            "OwnershipControlsNotFoundError" => S3ErrorCode::OwnershipControlsNotFoundError,
+            "PermanentRedirect" => S3ErrorCode::PermanentRedirect,
+            "PreconditionFailed" => S3ErrorCode::PreconditionFailed,
+            "ReplicationConfigurationNotFoundError" => {
+                S3ErrorCode::ReplicationConfigurationNotFoundError
+            }
+            "RequestTimeTooSkewed" => S3ErrorCode::RequestTimeTooSkewed,
+            "RequestLimitExceeded" => S3ErrorCode::RequestLimitExceeded,
+            "RequestTimeout" => S3ErrorCode::RequestTimeout,
+            "RequestThrottled" => S3ErrorCode::RequestThrottled,
+            "ServiceUnavailable" => S3ErrorCode::ServiceUnavailable,
+            "SignatureDoesNotMatch" => S3ErrorCode::SignatureDoesNotMatch,
+            "SlowDown" => S3ErrorCode::SlowDown,
+            "Throttling" => S3ErrorCode::Throttling,
+            "ThrottlingException" => S3ErrorCode::ThrottlingException,
+            "TooManyRequestsException" => S3ErrorCode::TooManyRequestsException,
+            "TemporaryRedirect" => S3ErrorCode::TemporaryRedirect,
+            "XAmzContentSHA256Mismatch" => S3ErrorCode::XAmzContentSha256Mismatch,
+            _ => S3ErrorCode::Unknown(value),
+        }
+    }
+
+    /// Guesses the code from the status, since HEAD responses have no body.
+    pub fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::MOVED_PERMANENTLY => S3ErrorCode::PermanentRedirect,
+            StatusCode::NOT_MODIFIED => S3ErrorCode::NotModified,
+            StatusCode::TEMPORARY_REDIRECT => S3ErrorCode::TemporaryRedirect,
+            StatusCode::BAD_REQUEST => S3ErrorCode::BadRequest,
+            StatusCode::FORBIDDEN => S3ErrorCode::AccessDenied,
+            StatusCode::NOT_FOUND => S3ErrorCode::NotFound,
+            StatusCode::METHOD_NOT_ALLOWED => S3ErrorCode::MethodNotAllowed,
+            StatusCode::PRECONDITION_FAILED => S3ErrorCode::PreconditionFailed,
+            StatusCode::RANGE_NOT_SATISFIABLE => S3ErrorCode::InvalidRange,
+            StatusCode::INTERNAL_SERVER_ERROR => S3ErrorCode::InternalError,
+            StatusCode::NOT_IMPLEMENTED => S3ErrorCode::NotImplemented,
+            StatusCode::SERVICE_UNAVAILABLE => S3ErrorCode::ServiceUnavailable,
+            _ => S3ErrorCode::Unknown(status.as_str().to_string()),
+        }
+    }
+
+    pub fn is_throttling(&self) -> bool {
+        matches!(
+            self,
+            S3ErrorCode::BandwidthLimitExceeded
+                | S3ErrorCode::RequestLimitExceeded
+                | S3ErrorCode::RequestThrottled
+                | S3ErrorCode::SlowDown
+                | S3ErrorCode::Throttling
+                | S3ErrorCode::ThrottlingException
+                | S3ErrorCode::TooManyRequestsException
+        )
+    }
+
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            S3ErrorCode::InternalError
+                | S3ErrorCode::RequestTimeout
+                | S3ErrorCode::ServiceUnavailable
+        )
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            S3ErrorCode::AccessDenied => "AccessDenied",
+            S3ErrorCode::AuthorizationHeaderMalformed => "AuthorizationHeaderMalformed",
+            S3ErrorCode::BadDigest => "BadDigest",
+            S3ErrorCode::BadRequest => "BadRequest",
+            S3ErrorCode::BandwidthLimitExceeded => "BandwidthLimitExceeded",
+            S3ErrorCode::BucketAlreadyExists => "BucketAlreadyExists",
+            S3ErrorCode::BucketAlreadyOwnedByYou => "BucketAlreadyOwnedByYou",
+            S3ErrorCode::BucketNotEmpty => "BucketNotEmpty",
+            S3ErrorCode::EntityTooLarge => "EntityTooLarge",
+            S3ErrorCode::EntityTooSmall => "EntityTooSmall",
+            S3ErrorCode::ExpiredToken => "ExpiredToken",
+            S3ErrorCode::IllegalLocationConstraintException => "IllegalLocationConstraintException",
+            S3ErrorCode::InternalError => "InternalError",
+            S3ErrorCode::InvalidAccessKeyId => "InvalidAccessKeyId",
+            S3ErrorCode::InvalidArgument => "InvalidArgument",
+            S3ErrorCode::InvalidBucketName => "InvalidBucketName",
+            S3ErrorCode::InvalidDigest => "InvalidDigest",
+            S3ErrorCode::InvalidObjectState => "InvalidObjectState",
+            S3ErrorCode::InvalidPart => "InvalidPart",
+            S3ErrorCode::InvalidPartOrder => "InvalidPartOrder",
+            S3ErrorCode::InvalidRange => "InvalidRange",
+            S3ErrorCode::InvalidRequest => "InvalidRequest",
+            S3ErrorCode::InvalidToken => "InvalidToken",
+            S3ErrorCode::KeyTooLongError => "KeyTooLongError",
+            S3ErrorCode::MalformedXml => "MalformedXML",
+            S3ErrorCode::MethodNotAllowed => "MethodNotAllowed",
+            S3ErrorCode::NoSuchBucket => "NoSuchBucket",
+            S3ErrorCode::NoSuchBucketPolicy => "NoSuchBucketPolicy",
+            S3ErrorCode::NoSuchKey => "NoSuchKey",
+            S3ErrorCode::NoSuchPublicAccessBlockConfiguration => {
+                "NoSuchPublicAccessBlockConfiguration"
+            }
+            S3ErrorCode::NoSuchUpload => "NoSuchUpload",
+            S3ErrorCode::NoSuchVersion => "NoSuchVersion",
+            S3ErrorCode::NotFound => "NotFound",
+            S3ErrorCode::NotImplemented => "NotImplemented",
+            S3ErrorCode::NotModified => "NotModified",
+            S3ErrorCode::OwnershipControlsNotFoundError => "OwnershipControlsNotFoundError",
+            S3ErrorCode::PermanentRedirect => "PermanentRedirect",
+            S3ErrorCode::PreconditionFailed => "PreconditionFailed",
+            S3ErrorCode::ReplicationConfigurationNotFoundError => {
+                "ReplicationConfigurationNotFoundError"
+            }
+            S3ErrorCode::RequestTimeTooSkewed => "RequestTimeTooSkewed",
+            S3ErrorCode::RequestLimitExceeded => "RequestLimitExceeded",
+            S3ErrorCode::RequestTimeout => "RequestTimeout",
+            S3ErrorCode::RequestThrottled => "RequestThrottled",
+            S3ErrorCode::ServiceUnavailable => "ServiceUnavailable",
+            S3ErrorCode::SignatureDoesNotMatch => "SignatureDoesNotMatch",
+            S3ErrorCode::SlowDown => "SlowDown",
+            S3ErrorCode::Throttling => "Throttling",
+            S3ErrorCode::ThrottlingException => "ThrottlingException",
+            S3ErrorCode::TooManyRequestsException => "TooManyRequestsException",
+            S3ErrorCode::TemporaryRedirect => "TemporaryRedirect",
+            S3ErrorCode::XAmzContentSha256Mismatch => "XAmzContentSHA256Mismatch",
+            S3ErrorCode::Unknown(x) => x,
+        }
+    }
+}
+
+impl FromStr for S3ErrorCode {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for S3ErrorCode {
+    fn from(s: String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<S3ErrorCode> for String {
+    fn from(code: S3ErrorCode) -> Self {
+        code.as_str().to_string()
+    }
+}
+
+impl fmt::Display for S3ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::S3ErrorCode;
+    use reqwest::StatusCode;
+
+    #[test]
+    fn it_keeps_unknown_code() {
+        let code = S3ErrorCode::new("SomethingNew");
+        assert_eq!(code, S3ErrorCode::Unknown("SomethingNew".to_string()));
+        assert_eq!(code.as_str(), "SomethingNew");
+    }
+
+    #[test]
+    fn it_guesses_code_from_status() {
+        assert_eq!(
+            S3ErrorCode::from_status(StatusCode::NOT_FOUND),
+            S3ErrorCode::NotFound
+        );
+        assert_eq!(
+            S3ErrorCode::from_status(StatusCode::IM_A_TEAPOT),
+            S3ErrorCode::Unknown("418".to_string())
+        );
+    }
+}
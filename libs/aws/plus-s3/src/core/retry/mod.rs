@@ -0,0 +1,11 @@
+mod rate_limiter;
+pub use rate_limiter::RateLimiter;
+
+mod retry_config;
+pub use retry_config::RetryConfig;
+
+mod retry_kind;
+pub use retry_kind::RetryKind;
+
+mod retry_mode;
+pub use retry_mode::RetryMode;
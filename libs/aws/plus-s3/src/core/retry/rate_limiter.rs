@@ -0,0 +1,111 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Limits the sending rate of a client in the adaptive retry mode.
+///
+/// Nothing is limited until the first throttling error. After that, the rate
+/// is cut to a fraction of the measured rate at each throttling error,
+/// and raised little by little at each success.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiter(Arc<Mutex<State>>);
+
+#[derive(Debug, Default)]
+struct State {
+    /// Requests per second allowed, or `None` if not throttled yet.
+    allowed_rate: Option<f64>,
+
+    /// Requests per second actually sent, smoothed.
+    measured_rate: f64,
+
+    last_sent_at: Option<Instant>,
+}
+
+const MIN_RATE: f64 = 0.5;
+const DECREASE_FACTOR: f64 = 0.7;
+const INCREASE_STEP: f64 = 0.5;
+const SMOOTHING: f64 = 0.8;
+
+impl RateLimiter {
+    /// Records a request to be sent, and returns how long it has to wait.
+    pub fn reserve(&self, now: Instant) -> Duration {
+        let mut state = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        let sent_at = match (state.allowed_rate, state.last_sent_at) {
+            (Some(rate), Some(last)) => {
+                let earliest = last + Duration::from_secs_f64(1.0 / rate);
+                if earliest > now {
+                    earliest
+                } else {
+                    now
+                }
+            }
+            _ => now,
+        };
+        if let Some(last) = state.last_sent_at {
+            let interval = sent_at.duration_since(last).as_secs_f64();
+            if interval > 0.0 {
+                state.measured_rate =
+                    SMOOTHING * state.measured_rate + (1.0 - SMOOTHING) / interval;
+            }
+        }
+        state.last_sent_at = Some(sent_at);
+        sent_at.duration_since(now)
+    }
+
+    pub fn on_throttled(&self) {
+        let mut state = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        let current = match state.allowed_rate {
+            Some(rate) => rate.min(state.measured_rate.max(MIN_RATE)),
+            None => state.measured_rate,
+        };
+        state.allowed_rate = Some((current * DECREASE_FACTOR).max(MIN_RATE));
+    }
+
+    pub fn on_succeeded(&self) {
+        let mut state = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(rate) = state.allowed_rate {
+            state.allowed_rate = Some(rate + INCREASE_STEP);
+        }
+    }
+
+    pub fn allowed_rate(&self) -> Option<f64> {
+        self.0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .allowed_rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RateLimiter, MIN_RATE};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn it_does_not_wait_before_throttled() {
+        let limiter = RateLimiter::default();
+        let now = Instant::now();
+        assert_eq!(limiter.reserve(now), Duration::from_secs(0));
+        assert_eq!(limiter.reserve(now), Duration::from_secs(0));
+        assert_eq!(limiter.allowed_rate(), None);
+    }
+
+    #[test]
+    fn it_waits_after_throttled() {
+        let limiter = RateLimiter::default();
+        let now = Instant::now();
+        limiter.reserve(now);
+        limiter.on_throttled();
+        assert_eq!(limiter.allowed_rate(), Some(MIN_RATE));
+
+        let wait = limiter.reserve(now);
+        assert_eq!(wait, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn it_raises_rate_after_succeeded() {
+        let limiter = RateLimiter::default();
+        limiter.on_throttled();
+        limiter.on_succeeded();
+        assert_eq!(limiter.allowed_rate(), Some(MIN_RATE + 0.5));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::core;
+use crate::core::retry::RetryMode;
+use plus_aws::env::aws;
+use rand::Rng;
+use std::cmp::min;
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct RetryConfig {
+    pub mode: RetryMode,
+
+    /// Includes the first attempt, so `1` disables retries.
+    pub max_attempts: u32,
+
+    pub base_delay: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            mode: RetryMode::Standard,
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(20),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Reads `AWS_RETRY_MODE` and `AWS_MAX_ATTEMPTS` like the AWS CLI.
+    pub fn from_env() -> core::Result<RetryConfig> {
+        let default = RetryConfig::default();
+        let max_attempts: Option<u32> = aws::max_attempts().as_optional()?;
+        Ok(RetryConfig {
+            mode: aws::retry_mode().as_optional()?.unwrap_or(default.mode),
+            max_attempts: max_attempts.unwrap_or(default.max_attempts).max(1),
+            ..default
+        })
+    }
+
+    /// Picks the delay before the next attempt with "full jitter".
+    ///
+    /// rf.
+    /// [Exponential Backoff And Jitter | AWS Architecture Blog](https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/)
+    pub fn backoff<R: Rng>(&self, attempt: u32, rng: &mut R) -> Duration {
+        let ceiling = min(self.max_backoff, self.ceiling(attempt));
+        ceiling.mul_f64(rng.gen_range(0.0, 1.0))
+    }
+
+    fn ceiling(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        self.base_delay
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_backoff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryConfig;
+    use rand::rngs::mock::StepRng;
+    use std::time::Duration;
+
+    #[test]
+    fn it_doubles_ceiling_until_max_backoff() {
+        let config = RetryConfig::default();
+        assert_eq!(config.ceiling(1), Duration::from_millis(100));
+        assert_eq!(config.ceiling(2), Duration::from_millis(200));
+        assert_eq!(config.ceiling(4), Duration::from_millis(800));
+
+        let mut rng = StepRng::new(u64::MAX, 0);
+        assert!(config.backoff(100, &mut rng) <= config.max_backoff);
+    }
+
+    #[test]
+    fn it_picks_delay_under_ceiling() {
+        let config = RetryConfig::default();
+        let mut rng = rand::thread_rng();
+        for attempt in 1..10 {
+            assert!(config.backoff(attempt, &mut rng) < config.ceiling(attempt));
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use reqwest::StatusCode;
+
+/// The reason why a failed attempt can be retried.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RetryKind {
+    /// Told to slow down, like `SlowDown` or 429.
+    Throttling,
+
+    /// Server side errors which may not happen again, like 500 or 503.
+    Transient,
+
+    /// The connection has been lost, or no response has arrived in time.
+    Transport,
+}
+
+impl RetryKind {
+    pub fn from_status(status: StatusCode) -> Option<RetryKind> {
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => Some(RetryKind::Throttling),
+            StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => Some(RetryKind::Transient),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::core;
+use std::str::FromStr;
+
+/// rf.
+/// [Retry behavior - AWS SDKs and Tools](https://docs.aws.amazon.com/sdkref/latest/guide/feature-retry-behavior.html)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RetryMode {
+    Standard,
+
+    /// Same as `Standard`, and also slows down the sending rate
+    /// after being throttled.
+    Adaptive,
+}
+
+impl RetryMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RetryMode::Standard => "standard",
+            RetryMode::Adaptive => "adaptive",
+        }
+    }
+}
+
+impl FromStr for RetryMode {
+    type Err = core::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "standard" => Ok(RetryMode::Standard),
+            "adaptive" => Ok(RetryMode::Adaptive),
+            _ => Err(core::Error::UnknownRetryMode(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::core;
+use crate::core::response::headers::AwsHeader;
+use std::convert::Infallible;
+use std::str::FromStr;
+
+/// rf.
+/// [Using Amazon S3 storage classes - Amazon Simple Storage Service](https://docs.aws.amazon.com/AmazonS3/latest/userguide/storage-class-intro.html)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum StorageClass {
+    Standard,
+    ReducedRedundancy,
+    StandardIa,
+    OnezoneIa,
+    IntelligentTiering,
+    Glacier,
+    GlacierIr,
+    DeepArchive,
+    Outposts,
+    Unknown(String),
+}
+
+impl StorageClass {
+    pub fn new<A: Into<String>>(value: A) -> Self {
+        let value = value.into();
+        match value.as_str() {
+            "STANDARD" => StorageClass::Standard,
+            "REDUCED_REDUNDANCY" => StorageClass::ReducedRedundancy,
+            "STANDARD_IA" => StorageClass::StandardIa,
+            "ONEZONE_IA" => StorageClass::OnezoneIa,
+            "INTELLIGENT_TIERING" => StorageClass::IntelligentTiering,
+            "GLACIER" => StorageClass::Glacier,
+            "GLACIER_IR" => StorageClass::GlacierIr,
+            "DEEP_ARCHIVE" => StorageClass::DeepArchive,
+            "OUTPOSTS" => StorageClass::Outposts,
+            _ => StorageClass::Unknown(value),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            StorageClass::Standard => "STANDARD",
+            StorageClass::ReducedRedundancy => "REDUCED_REDUNDANCY",
+            StorageClass::StandardIa => "STANDARD_IA",
+            StorageClass::OnezoneIa => "ONEZONE_IA",
+            StorageClass::IntelligentTiering => "INTELLIGENT_TIERING",
+            StorageClass::Glacier => "GLACIER",
+            StorageClass::GlacierIr => "GLACIER_IR",
+            StorageClass::DeepArchive => "DEEP_ARCHIVE",
+            StorageClass::Outposts => "OUTPOSTS",
+            StorageClass::Unknown(x) => x,
+        }
+    }
+}
+
+impl FromStr for StorageClass {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for StorageClass {
+    fn from(s: String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<StorageClass> for String {
+    fn from(class: StorageClass) -> Self {
+        class.as_str().to_string()
+    }
+}
+
+impl<'a> AwsHeader<'a> for StorageClass {
+    const HEADER_NAME: &'a str = "x-amz-storage-class";
+
+    fn new<A: Into<String>>(a: A) -> core::Result<Self> {
+        Ok(StorageClass::new(a))
+    }
+}
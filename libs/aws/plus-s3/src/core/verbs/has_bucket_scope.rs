@@ -1,4 +1,7 @@
-use crate::core::S3Bucket;
+use crate::client::S3ClientConfig;
+use crate::core::request::RequestOptions;
+use crate::core::retry::{RateLimiter, RetryConfig};
+use crate::core::{RegionCache, S3Bucket};
 use plus_aws::auth::Credentials;
 use plus_aws::index::RegionCode;
 
@@ -6,4 +9,9 @@ pub trait HasBucketScope {
     fn credentials(&self) -> &Credentials;
     fn bucket(&self) -> &S3Bucket;
     fn default_region(&self) -> &Option<RegionCode>;
+    fn default_options(&self) -> &RequestOptions;
+    fn region_cache(&self) -> &RegionCache;
+    fn retry_config(&self) -> &RetryConfig;
+    fn rate_limiter(&self) -> &RateLimiter;
+    fn config(&self) -> &S3ClientConfig;
 }
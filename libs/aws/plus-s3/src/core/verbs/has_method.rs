@@ -20,3 +20,27 @@ pub trait IsPut<MARKER> {}
 impl<A, B: IsPut<A>> HasMethod<PutImpl<A>> for B {
     const METHOD: Method = Method::PUT;
 }
+
+pub struct DeleteImpl<A>(PhantomData<A>);
+
+pub trait IsDelete<MARKER> {}
+
+impl<A, B: IsDelete<A>> HasMethod<DeleteImpl<A>> for B {
+    const METHOD: Method = Method::DELETE;
+}
+
+pub struct HeadImpl<A>(PhantomData<A>);
+
+pub trait IsHead<MARKER> {}
+
+impl<A, B: IsHead<A>> HasMethod<HeadImpl<A>> for B {
+    const METHOD: Method = Method::HEAD;
+}
+
+pub struct PostImpl<A>(PhantomData<A>);
+
+pub trait IsPost<MARKER> {}
+
+impl<A, B: IsPost<A>> HasMethod<PostImpl<A>> for B {
+    const METHOD: Method = Method::POST;
+}
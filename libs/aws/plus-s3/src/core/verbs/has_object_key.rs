@@ -3,3 +3,9 @@ use std::fmt::Debug;
 pub trait HasObjectKey: Debug {
     fn get_object_key(&self) -> &str;
 }
+
+impl<A: HasObjectKey> HasObjectKey for &mut A {
+    fn get_object_key(&self) -> &str {
+        (**self).get_object_key()
+    }
+}
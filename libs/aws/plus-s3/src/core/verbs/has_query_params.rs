@@ -0,0 +1,13 @@
+/// Query parameters appended to the endpoint,
+/// used to select a subresource like `?replication`.
+pub trait HasQueryParams {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        vec![]
+    }
+}
+
+impl<A: HasQueryParams> HasQueryParams for &mut A {
+    fn get_query_params(&self) -> Vec<(&str, String)> {
+        (**self).get_query_params()
+    }
+}
@@ -9,5 +9,11 @@ pub use has_bucket_scope::HasBucketScope;
 
 mod has_method;
 pub use has_method::HasMethod;
+pub use has_method::IsDelete;
 pub use has_method::IsGet;
+pub use has_method::IsHead;
+pub use has_method::IsPost;
 pub use has_method::IsPut;
+
+mod has_query_params;
+pub use has_query_params::HasQueryParams;
@@ -1,6 +1,6 @@
 use crate::core;
-use crate::core::verbs::HasObjectKey;
-use crate::core::S3Bucket;
+use crate::core::endpoint::BucketEndpoint;
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
 use reqwest::Url;
 use std::fmt::Debug;
 
@@ -8,14 +8,18 @@ pub trait ToEndpoint: Debug {
     fn to_endpoint(&self) -> core::Result<Url>;
 }
 
-impl<A: HasObjectKey> ToEndpoint for (&S3Bucket, &A) {
+impl<A: HasObjectKey + HasQueryParams> ToEndpoint for (&BucketEndpoint<'_>, &A) {
     fn to_endpoint(&self) -> core::Result<Url> {
-        let (bucket, request) = self;
-        let full = format!(
-            "https://{}.s3.amazonaws.com/{}",
-            bucket.as_str(),
-            request.get_object_key(),
-        );
-        Ok(Url::parse(&full)?)
+        let (endpoint, request) = self;
+        let params = request.get_query_params();
+        let endpoint = match params.iter().any(|(key, _)| *key == "accelerate") {
+            true => endpoint.without_accelerate(),
+            false => **endpoint,
+        };
+        let mut url = endpoint.to_url(request.get_object_key())?;
+        if !params.is_empty() {
+            url.query_pairs_mut().extend_pairs(params);
+        }
+        Ok(url)
     }
 }
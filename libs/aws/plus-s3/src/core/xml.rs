@@ -0,0 +1,13 @@
+use crate::core;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+pub fn to_string<A: Serialize>(value: &A) -> core::Result<String> {
+    let xml = quick_xml::se::to_string(value)?;
+    Ok(xml)
+}
+
+pub fn from_str<A: DeserializeOwned>(xml: &str) -> core::Result<A> {
+    let value = quick_xml::de::from_str(xml)?;
+    Ok(value)
+}
@@ -1,4 +1,5 @@
-use crate::{actions, client, core, internal};
+use crate::internal::impl_async::S3ErrorResponse;
+use crate::{actions, client, core, internal, transfer};
 
 /// see also: libs/aws/plus-s3-macros
 /// plus-s3-macros helps to integrate plus_s3::*::Error into one plus-s3::Error.
@@ -16,6 +17,21 @@ pub enum Error {
 
     #[fail(display = "internal::Error > {}", 0)]
     InternalError(internal::Error),
+
+    #[fail(display = "transfer::Error > {}", 0)]
+    TransferError(transfer::Error),
+}
+
+impl Error {
+    /// Returns the response when S3 has rejected the request.
+    pub fn s3_error(&self) -> Option<&S3ErrorResponse> {
+        match self {
+            Error::ActionsError(e) => e.s3_error(),
+            Error::InternalError(e) => e.s3_error(),
+            Error::TransferError(e) => e.s3_error(),
+            _ => None,
+        }
+    }
 }
 
 impl From<actions::Error> for Error {
@@ -41,3 +57,9 @@ impl From<internal::Error> for Error {
         Error::InternalError(e)
     }
 }
+
+impl From<transfer::Error> for Error {
+    fn from(e: transfer::Error) -> Self {
+        Error::TransferError(e)
+    }
+}
@@ -1,9 +1,17 @@
+use crate::core::retry::RetryKind;
 use crate::internal::impl_async::S3ErrorResponse;
+use std::time::Duration;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Fail, Debug)]
 pub enum Error {
+    #[fail(display = "OperationTimeout > {:?}", 0)]
+    OperationTimeout(Duration),
+
+    #[fail(display = "ReadTimeout > {:?}", 0)]
+    ReadTimeout(Duration),
+
     #[fail(display = "region not specified")]
     RegionNotSpecified,
 
@@ -17,12 +25,33 @@ pub enum Error {
     S3CoreError(crate::core::Error),
 
     #[fail(display = "S3ErrorResponse > {}", 0)]
-    S3Error(S3ErrorResponse),
+    S3Error(Box<S3ErrorResponse>),
 
     #[fail(display = "std::io::Error > {}", 0)]
     StdIoError(std::io::Error),
 }
 
+impl Error {
+    /// Returns the response when S3 has rejected the request.
+    pub fn s3_error(&self) -> Option<&S3ErrorResponse> {
+        match self {
+            Error::S3Error(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    pub fn retry_kind(&self) -> Option<RetryKind> {
+        match self {
+            Error::S3Error(e) => e.retry_kind(),
+            Error::ReadTimeout(_) => Some(RetryKind::Transport),
+            Error::ReqwestError(e) if e.is_timeout() || e.is_connect() || e.is_request() => {
+                Some(RetryKind::Transport)
+            }
+            _ => None,
+        }
+    }
+}
+
 impl From<reqwest::Error> for Error {
     fn from(e: reqwest::Error) -> Self {
         Error::ReqwestError(e)
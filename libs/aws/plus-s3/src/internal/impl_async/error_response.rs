@@ -1,27 +1,229 @@
+use crate::core::response::{S3ErrorBody, S3ErrorCode};
+use crate::core::retry::RetryKind;
 use crate::internal;
+use crate::internal::impl_async::read_text;
 use reqwest::header::HeaderMap;
 use reqwest::StatusCode;
 use std::fmt;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct S3ErrorResponse {
     status: StatusCode,
     headers: HeaderMap,
+    code: S3ErrorCode,
+    body: Option<Box<S3ErrorBody>>,
     text: String,
 }
 
 impl S3ErrorResponse {
-    pub async fn dump(response: reqwest::Response) -> internal::Result<S3ErrorResponse> {
-        Ok(S3ErrorResponse {
-            status: response.status(),
-            headers: response.headers().clone(),
-            text: response.text().await?,
-        })
+    pub async fn dump(
+        response: reqwest::Response,
+        read_timeout: Option<Duration>,
+    ) -> internal::Result<S3ErrorResponse> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let text = read_text(response, read_timeout).await?;
+        Ok(S3ErrorResponse::new(status, headers, text))
+    }
+
+    /// Falls back to the status code when the body is not an `<Error>` element,
+    /// which is always the case for HEAD requests.
+    pub fn new(status: StatusCode, headers: HeaderMap, text: String) -> S3ErrorResponse {
+        let body = S3ErrorBody::from_xml(&text).ok().map(Box::new);
+        let code = match &body {
+            Some(body) => body.code.clone(),
+            None => S3ErrorCode::from_status(status),
+        };
+        S3ErrorResponse {
+            status,
+            headers,
+            code,
+            body,
+            text,
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn code(&self) -> &S3ErrorCode {
+        &self.code
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.body_field(|body| &body.message)
+            .or_else(|| self.status.canonical_reason())
+    }
+
+    pub fn request_id(&self) -> Option<&str> {
+        self.body_field(|body| &body.request_id)
+            .or_else(|| self.header("x-amz-request-id"))
+    }
+
+    pub fn host_id(&self) -> Option<&str> {
+        self.body_field(|body| &body.host_id)
+            .or_else(|| self.header("x-amz-id-2"))
+    }
+
+    pub fn resource(&self) -> Option<&str> {
+        self.body_field(|body| &body.resource)
+    }
+
+    pub fn bucket_name(&self) -> Option<&str> {
+        self.body_field(|body| &body.bucket_name)
+    }
+
+    pub fn key(&self) -> Option<&str> {
+        self.body_field(|body| &body.key)
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.body_field(|body| &body.region)
+            .or_else(|| self.header("x-amz-bucket-region"))
+    }
+
+    pub fn endpoint(&self) -> Option<&str> {
+        self.body_field(|body| &body.endpoint)
+    }
+
+    /// Tells whether the request has been signed for a region other than the bucket's.
+    pub fn is_region_redirect(&self) -> bool {
+        let redirected = match self.code {
+            S3ErrorCode::PermanentRedirect
+            | S3ErrorCode::TemporaryRedirect
+            | S3ErrorCode::AuthorizationHeaderMalformed => true,
+            // HEAD responses only have the status and the headers.
+            S3ErrorCode::BadRequest => self.body.is_none(),
+            _ => false,
+        };
+        redirected && self.region().is_some()
+    }
+
+    pub fn retry_kind(&self) -> Option<RetryKind> {
+        if self.code.is_throttling() {
+            Some(RetryKind::Throttling)
+        } else if self.code.is_transient() {
+            Some(RetryKind::Transient)
+        } else {
+            RetryKind::from_status(self.status)
+        }
+    }
+
+    /// The raw body, which is empty for HEAD requests.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn body_field<F>(&self, f: F) -> Option<&str>
+    where
+        F: Fn(&S3ErrorBody) -> &Option<String>,
+    {
+        self.body.as_ref().and_then(|body| f(body).as_deref())
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
     }
 }
 
 impl fmt::Display for S3ErrorResponse {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(self, f)
+        write!(f, "{} ({})", self.code, self.status.as_u16())?;
+        if let Some(message) = self.message() {
+            write!(f, ": {}", message)?;
+        }
+        if let Some(request_id) = self.request_id() {
+            write!(f, " [RequestId: {}]", request_id)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::S3ErrorResponse;
+    use crate::core::response::S3ErrorCode;
+    use crate::core::retry::RetryKind;
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use reqwest::StatusCode;
+
+    #[test]
+    fn it_reads_error_body() {
+        let text =
+            "<Error><Code>NoSuchKey</Code><Message>The specified key does not exist.</Message>\
+            <Key>foo.txt</Key><RequestId>4442587FB7D0A2F9</RequestId></Error>";
+        let response = S3ErrorResponse::new(StatusCode::NOT_FOUND, HeaderMap::new(), text.into());
+        assert_eq!(response.code(), &S3ErrorCode::NoSuchKey);
+        assert_eq!(response.key(), Some("foo.txt"));
+        assert_eq!(
+            response.to_string(),
+            "NoSuchKey (404): The specified key does not exist. [RequestId: 4442587FB7D0A2F9]"
+        );
+    }
+
+    #[test]
+    fn it_reads_status_without_body() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-amz-request-id",
+            HeaderValue::from_static("0A49CE4060975EAC"),
+        );
+        let response = S3ErrorResponse::new(StatusCode::FORBIDDEN, headers, "".into());
+        assert_eq!(response.code(), &S3ErrorCode::AccessDenied);
+        assert_eq!(response.request_id(), Some("0A49CE4060975EAC"));
+        assert_eq!(
+            response.to_string(),
+            "AccessDenied (403): Forbidden [RequestId: 0A49CE4060975EAC]"
+        );
+    }
+
+    #[test]
+    fn it_classifies_retryable_errors() {
+        let text = "<Error><Code>SlowDown</Code><Message>Please reduce your request rate.</Message></Error>";
+        let response = S3ErrorResponse::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            HeaderMap::new(),
+            text.into(),
+        );
+        assert_eq!(response.retry_kind(), Some(RetryKind::Throttling));
+
+        let response = S3ErrorResponse::new(StatusCode::BAD_GATEWAY, HeaderMap::new(), "".into());
+        assert_eq!(response.retry_kind(), Some(RetryKind::Transient));
+
+        let response = S3ErrorResponse::new(StatusCode::NOT_FOUND, HeaderMap::new(), "".into());
+        assert_eq!(response.retry_kind(), None);
+    }
+
+    #[test]
+    fn it_finds_region_from_redirect_body() {
+        let text = "<Error><Code>AuthorizationHeaderMalformed</Code>\
+            <Message>the region 'us-east-1' is wrong; expecting 'eu-west-1'</Message>\
+            <Region>eu-west-1</Region></Error>";
+        let response = S3ErrorResponse::new(StatusCode::BAD_REQUEST, HeaderMap::new(), text.into());
+        assert!(response.is_region_redirect());
+        assert_eq!(response.region(), Some("eu-west-1"));
+    }
+
+    #[test]
+    fn it_finds_region_from_head_redirect() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-amz-bucket-region", HeaderValue::from_static("eu-west-1"));
+        let response = S3ErrorResponse::new(StatusCode::MOVED_PERMANENTLY, headers, "".into());
+        assert!(response.is_region_redirect());
+        assert_eq!(response.region(), Some("eu-west-1"));
+    }
+
+    #[test]
+    fn it_ignores_other_errors_with_region() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-amz-bucket-region", HeaderValue::from_static("eu-west-1"));
+        let response = S3ErrorResponse::new(StatusCode::FORBIDDEN, headers, "".into());
+        assert!(!response.is_region_redirect());
     }
 }
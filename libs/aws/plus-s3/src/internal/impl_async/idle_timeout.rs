@@ -0,0 +1,43 @@
+use crate::internal;
+use bytes::BytesMut;
+use futures_util::{future, stream, Stream, StreamExt, TryStreamExt};
+use reqwest::Response;
+use std::time::Duration;
+
+/// Fails the stream when no item arrives within the timeout.
+pub fn with_idle_timeout<S, T, E>(
+    stream: S,
+    timeout: Option<Duration>,
+) -> impl Stream<Item = Result<T, E>>
+where
+    S: Stream<Item = Result<T, E>>,
+    E: From<internal::Error>,
+{
+    let stream = Box::pin(stream);
+    stream::unfold(Some(stream), move |state| async move {
+        let mut stream = state?;
+        let next = match timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, stream.next()).await {
+                Ok(next) => next,
+                Err(_) => {
+                    let error = internal::Error::ReadTimeout(timeout);
+                    return Some((Err(error.into()), None));
+                }
+            },
+            None => stream.next().await,
+        };
+        next.map(|item| (item, Some(stream)))
+    })
+}
+
+/// Reads the whole body as a text, failing when no chunk arrives within the timeout.
+pub async fn read_text(response: Response, timeout: Option<Duration>) -> internal::Result<String> {
+    let stream = response.bytes_stream().map_err(internal::Error::from);
+    let bytes = with_idle_timeout(stream, timeout)
+        .try_fold(BytesMut::new(), |mut buffer, chunk| {
+            buffer.extend_from_slice(&chunk);
+            future::ok(buffer)
+        })
+        .await?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
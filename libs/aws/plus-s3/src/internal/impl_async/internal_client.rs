@@ -1,50 +1,236 @@
-use super::RequestProvider;
+use super::{InternalRequest, Progress, RequestProvider};
+use crate::client::S3ClientConfig;
 use crate::core::request::ResourceLoader;
-use crate::core::verbs::HasObjectKey;
+use crate::core::retry::{RetryKind, RetryMode};
+use crate::core::verbs::{HasObjectKey, HasQueryParams};
 use crate::internal;
-use crate::internal::error::Error::S3Error;
+use crate::internal::error::Error::{OperationTimeout, S3Error};
 use crate::internal::impl_async::S3ErrorResponse;
-use reqwest::{Client, Response, StatusCode};
+use futures_util::future::{self, Either};
+use plus_aws::index::RegionCode;
+use reqwest::redirect::Policy;
+use reqwest::{Body, Certificate, Client, Response, StatusCode};
 use std::fmt::Debug;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use tokio::time::delay_for;
 
-#[derive(Debug)]
-pub struct InternalClient {}
-
-impl Default for InternalClient {
-    fn default() -> Self {
-        InternalClient {}
-    }
+/// Holds the connection pool, which is shared by its clones.
+#[derive(Clone, Debug)]
+pub struct InternalClient {
+    client: Client,
 }
 
 impl InternalClient {
+    pub fn new(config: &S3ClientConfig) -> internal::Result<InternalClient> {
+        let builder = Client::builder()
+            .redirect(Policy::none())
+            .user_agent(config.user_agent());
+
+        let builder = match config.connect_timeout {
+            Some(timeout) => builder.connect_timeout(timeout),
+            None => builder,
+        };
+        let builder = match &config.tls.ca_bundle {
+            Some(path) => {
+                builder.add_root_certificate(Certificate::from_pem(&std::fs::read(path)?)?)
+            }
+            None => builder,
+        };
+        let builder = builder.danger_accept_invalid_certs(!config.tls.verify);
+        let client = InternalClient {
+            client: builder.build()?,
+        };
+        Ok(client)
+    }
+
+    /// Sends the request until it succeeds or the attempts run out.
+    ///
+    /// The request is loaded again for each attempt,
+    /// so that the body is reopened and signed at the time.
+    /// The one which cannot be loaded again is sent only once,
+    /// after asking the region of the bucket in advance if it is unknown.
     pub async fn request_by<'a, A>(
         &'a self,
-        provider: RequestProvider<'a, A>,
+        mut provider: RequestProvider<'a, A>,
     ) -> internal::Result<Response>
     where
         A: ResourceLoader,
         A: HasObjectKey,
+        A: HasQueryParams,
     {
-        let request = provider.provide().await?;
-        eprintln!("request > {:#?}", request);
-
-        let client: Client = Client::builder().timeout(Duration::from_secs(5)).build()?;
-        let builder = client
-            .request(request.method, request.url)
-            .headers(request.headers);
+        let config = provider.retry_config().clone();
+        let limiter = provider.rate_limiter().clone();
+        let client_config = provider.client_config().clone();
+        let deadline = client_config
+            .operation_timeout
+            .map(|timeout| (timeout, Instant::now() + timeout));
+        let replayable = provider.is_replayable();
+        if !replayable && provider.is_region_unresolved() {
+            if let Some(region) = self
+                .probe_region(&provider, client_config.read_timeout)
+                .await
+            {
+                provider.remember_region(region);
+            }
+        }
+        let mut attempt = 1;
+        let mut redirected = false;
+        loop {
+            if config.mode == RetryMode::Adaptive {
+                delay_for(limiter.reserve(Instant::now())).await;
+            }
+            let request = provider.provide().await?;
+            let signed_region = request.region.clone();
+            let sent = send(&self.client, request, client_config.read_timeout, deadline);
+            let error = match sent.await {
+                Ok(response) => {
+                    limiter.on_succeeded();
+                    return Ok(response);
+                }
+                Err(error) => error,
+            };
+            if !redirected {
+                if let Some(region) = redirected_region(&error, &signed_region) {
+                    if !replayable {
+                        // the body has been consumed, but the next request can be sent there.
+                        provider.remember_region(region);
+                        return Err(error);
+                    }
+                    // not counted as an attempt, since the region is told by S3 itself.
+                    redirected = true;
+                    provider.redirect_to(region);
+                    continue;
+                }
+            }
+            if !replayable {
+                return Err(error);
+            }
+            let kind = match error.retry_kind() {
+                Some(kind) if attempt < config.max_attempts => kind,
+                _ => return Err(error),
+            };
+            if kind == RetryKind::Throttling {
+                limiter.on_throttled();
+            }
+            let delay = config.backoff(attempt, &mut rand::thread_rng());
+            if let Some((_, deadline)) = deadline {
+                if Instant::now() + delay >= deadline {
+                    return Err(error);
+                }
+            }
+            delay_for(delay).await;
+            attempt += 1;
+        }
+    }
 
-        let builder = match request.body {
-            Some(body) => builder.body(body),
-            _ => builder,
+    /// Asks the region by `HEAD` without credentials,
+    /// since S3 tells it by `x-amz-bucket-region` even if the access is denied.
+    async fn probe_region<A>(
+        &self,
+        provider: &RequestProvider<'_, A>,
+        read_timeout: Option<Duration>,
+    ) -> Option<RegionCode>
+    where
+        A: ResourceLoader,
+        A: HasObjectKey,
+        A: HasQueryParams,
+    {
+        let sent = self.client.head(provider.default_bucket_url()?).send();
+        let response = match read_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, sent).await.ok()?,
+            None => sent.await,
         };
-        let response: reqwest::Response = builder.timeout(Duration::from_secs(5)).send().await?;
-        let status: StatusCode = response.status();
-        if status.is_success() {
-            eprintln!("response > {:#?}", response);
-            Ok(response)
-        } else {
-            Err(S3Error(S3ErrorResponse::dump(response).await?))
+        let response = response.ok()?;
+        let region = response.headers().get("x-amz-bucket-region")?;
+        region.to_str().ok()?.parse().ok()
+    }
+}
+
+async fn send(
+    client: &Client,
+    request: InternalRequest,
+    read_timeout: Option<Duration>,
+    deadline: Option<(Duration, Instant)>,
+) -> internal::Result<Response> {
+    let builder = client
+        .request(request.method, request.url)
+        .headers(request.headers);
+
+    let builder = match deadline {
+        Some((timeout, deadline)) => {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(OperationTimeout(timeout));
+            }
+            // also bounds the response body, which is read after returning.
+            builder.timeout(deadline - now)
         }
+        None => builder,
+    };
+    // the upload may take a long time, so it times out only when the body stops being sent.
+    let progress = Progress::start();
+    let builder = match request.body {
+        Some(body) => builder.body(Body::wrap_stream(progress.watch(body.into_stream()))),
+        _ => builder,
+    };
+    let response: reqwest::Response = match read_timeout {
+        Some(timeout) => {
+            let sent = Box::pin(builder.send());
+            let idle = Box::pin(progress.idle_timeout(timeout));
+            match future::select(sent, idle).await {
+                Either::Left((response, _)) => response?,
+                Either::Right((error, _)) => return Err(error),
+            }
+        }
+        None => builder.send().await?,
+    };
+    let status: StatusCode = response.status();
+    if status.is_success() {
+        Ok(response)
+    } else {
+        Err(S3Error(Box::new(
+            S3ErrorResponse::dump(response, read_timeout).await?,
+        )))
+    }
+}
+
+/// Gives up the redirect if the region is implausible,
+/// since it is put into the host of the following requests.
+fn redirected_region(error: &internal::Error, signed: &RegionCode) -> Option<RegionCode> {
+    let error = error.s3_error().filter(|e| e.is_region_redirect())?;
+    error
+        .region()
+        .filter(|region| *region != signed.as_str())
+        .and_then(|region| region.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::redirected_region;
+    use crate::internal;
+    use crate::internal::impl_async::S3ErrorResponse;
+    use plus_aws::index::RegionCode;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    fn redirect_to(region: &str) -> internal::Error {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-amz-bucket-region", region.parse().unwrap());
+        let text = "<Error><Code>PermanentRedirect</Code></Error>".to_string();
+        let response = S3ErrorResponse::new(StatusCode::MOVED_PERMANENTLY, headers, text);
+        internal::Error::S3Error(Box::new(response))
+    }
+
+    #[test]
+    fn it_follows_plausible_region() {
+        let signed = RegionCode::any("us-east-1");
+        let region = redirected_region(&redirect_to("eu-west-1"), &signed);
+        assert_eq!(region.as_ref().map(|r| r.as_str()), Some("eu-west-1"));
+    }
+
+    #[test]
+    fn it_ignores_implausible_region() {
+        let signed = RegionCode::any("us-east-1");
+        assert!(redirected_region(&redirect_to("evil.example/"), &signed).is_none());
     }
 }
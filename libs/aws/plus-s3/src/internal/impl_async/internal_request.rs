@@ -1,12 +1,15 @@
+use crate::core::request::RequestBody;
+use plus_aws::index::RegionCode;
 use reqwest::header::HeaderMap;
-use reqwest::Body;
 use reqwest::Method;
 use url::Url;
 
 #[derive(Debug)]
 pub struct InternalRequest {
+    /// The region used to sign this request.
+    pub region: RegionCode,
     pub url: Url,
     pub method: Method,
-    pub body: Option<Body>,
+    pub body: Option<RequestBody>,
     pub headers: HeaderMap,
 }
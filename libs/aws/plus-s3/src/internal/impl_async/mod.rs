@@ -1,9 +1,15 @@
+mod idle_timeout;
+pub use idle_timeout::{read_text, with_idle_timeout};
+
 mod internal_client;
 pub use internal_client::InternalClient;
 
 mod internal_request;
 pub use internal_request::InternalRequest;
 
+mod progress;
+use progress::Progress;
+
 mod request_provider;
 pub use request_provider::RequestProvider;
 
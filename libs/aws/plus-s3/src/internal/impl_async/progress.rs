@@ -0,0 +1,49 @@
+use crate::core::request::ByteStream;
+use crate::internal;
+use futures_util::stream::{self, Stream, StreamExt};
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::task::Poll;
+use std::time::{Duration, Instant};
+use tokio::time::delay_for;
+
+/// Remembers when the request has made progress lastly,
+/// which is each time a chunk of the body is taken to be sent.
+#[derive(Clone, Debug)]
+pub struct Progress(Arc<Mutex<Instant>>);
+
+impl Progress {
+    pub fn start() -> Progress {
+        Progress(Arc::new(Mutex::new(Instant::now())))
+    }
+
+    /// Also makes progress at the end of the body,
+    /// from which the response is waited for.
+    pub fn watch(
+        &self,
+        body: ByteStream,
+    ) -> impl Stream<Item = io::Result<bytes::Bytes>> + Send + Sync + 'static {
+        let on_chunk = self.clone();
+        let on_end = self.clone();
+        body.inspect(move |_| on_chunk.touch())
+            .chain(stream::poll_fn(move |_| {
+                on_end.touch();
+                Poll::Ready(None)
+            }))
+    }
+
+    /// Fails when no progress has been made within the timeout.
+    pub async fn idle_timeout(&self, timeout: Duration) -> internal::Error {
+        loop {
+            let idle = self.0.lock().expect("poisoned").elapsed();
+            if idle >= timeout {
+                return internal::Error::ReadTimeout(timeout);
+            }
+            delay_for(timeout - idle).await;
+        }
+    }
+
+    fn touch(&self) {
+        *self.0.lock().expect("poisoned") = Instant::now();
+    }
+}
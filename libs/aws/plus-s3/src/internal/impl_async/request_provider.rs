@@ -1,32 +1,45 @@
 use super::InternalRequest;
-use crate::core::request::ResourceLoader;
-use crate::core::verbs::{HasBucketScope, HasMethod, HasObjectKey, ToEndpoint};
+use crate::client::S3ClientConfig;
+use crate::core::endpoint::BucketEndpoint;
+use crate::core::request::{RequestOptions, ResourceLoader};
+use crate::core::retry::{RateLimiter, RetryConfig};
+use crate::core::verbs::{HasBucketScope, HasMethod, HasObjectKey, HasQueryParams, ToEndpoint};
+use crate::core::{RegionCache, S3Bucket};
 use crate::internal;
 use crate::internal::Error::RegionNotSpecified;
 use crate::internal::RequestParts;
 use plus_aws::auth::v4::request::AuthorizationFactory;
+use plus_aws::auth::v4::streaming::ChunkEncoder;
 use plus_aws::auth::Credentials;
 use plus_aws::http::request::{header, RichHeaderMap};
 use plus_aws::index::RegionCode;
-use reqwest::header::HeaderMap;
+use reqwest::header::{HeaderMap, AUTHORIZATION};
 use reqwest::{Method, Url};
 
 pub struct RequestProvider<'a, A>
 where
     A: ResourceLoader,
     A: HasObjectKey,
+    A: HasQueryParams,
 {
     credentials: &'a Credentials,
-    url: Url,
     method: Method,
     resource_loader: &'a A,
     default_region: &'a Option<RegionCode>,
+    default_options: &'a RequestOptions,
+    bucket: &'a S3Bucket,
+    region_cache: &'a RegionCache,
+    redirected_region: Option<RegionCode>,
+    retry_config: &'a RetryConfig,
+    rate_limiter: &'a RateLimiter,
+    client_config: &'a S3ClientConfig,
 }
 
 impl<A> RequestProvider<'_, A>
 where
     A: ResourceLoader,
     A: HasObjectKey,
+    A: HasQueryParams,
 {
     pub fn new<'a, X, ANY>(scope: &'a X, request: &'a A) -> internal::Result<RequestProvider<'a, A>>
     where
@@ -35,42 +48,129 @@ where
     {
         let provider = RequestProvider {
             credentials: scope.credentials(),
-            url: (scope.bucket(), request).to_endpoint()?,
             method: A::METHOD,
             resource_loader: request,
             default_region: scope.default_region(),
+            default_options: scope.default_options(),
+            bucket: scope.bucket(),
+            region_cache: scope.region_cache(),
+            redirected_region: None,
+            retry_config: scope.retry_config(),
+            rate_limiter: scope.rate_limiter(),
+            client_config: scope.config(),
         };
         Ok(provider)
     }
 
-    pub async fn provide(self) -> internal::Result<InternalRequest> {
+    pub fn retry_config(&self) -> &RetryConfig {
+        self.retry_config
+    }
+
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        self.rate_limiter
+    }
+
+    pub fn client_config(&self) -> &S3ClientConfig {
+        self.client_config
+    }
+
+    pub fn is_replayable(&self) -> bool {
+        self.resource_loader.is_replayable()
+    }
+
+    /// Tells whether the region of the bucket is still unknown except for the default.
+    pub fn is_region_unresolved(&self) -> bool {
+        self.redirected_region.is_none() && self.region_cache.get(self.bucket).is_none()
+    }
+
+    /// The URL of the bucket in the default region, which may tell the actual region.
+    pub fn default_bucket_url(&self) -> Option<Url> {
+        let endpoint = BucketEndpoint {
+            endpoint: &self.client_config.endpoint,
+            variants: self.client_config.endpoint_variants,
+            addressing_style: self.client_config.addressing_style,
+            bucket: self.bucket,
+            region: self.default_region.as_ref()?,
+        };
+        endpoint.to_url("").ok()
+    }
+
+    /// Remembers the region for the bucket without changing this request.
+    pub fn remember_region(&self, region: RegionCode) {
+        self.region_cache.insert(self.bucket, region);
+    }
+
+    /// Signs the following requests for the region told by S3,
+    /// which is also remembered for the bucket.
+    pub fn redirect_to(&mut self, region: RegionCode) {
+        self.region_cache.insert(self.bucket, region.clone());
+        self.redirected_region = Some(region);
+    }
+
+    pub async fn provide(&self) -> internal::Result<InternalRequest> {
         let resource = self.resource_loader.load().await?;
-        let region_code = resource
-            .region
-            .or_else(|| self.default_region.as_ref())
+        let options = self.default_options.merge(&resource.options);
+        let cached_region = self.region_cache.get(self.bucket);
+        let region_code = self
+            .redirected_region
+            .as_ref()
+            .or(resource.region)
+            .or(cached_region.as_ref())
+            .or(self.default_region.as_ref())
             .ok_or_else(|| RegionNotSpecified)?;
 
+        let endpoint = BucketEndpoint {
+            endpoint: &self.client_config.endpoint,
+            variants: self.client_config.endpoint_variants,
+            addressing_style: self.client_config.addressing_style,
+            bucket: self.bucket,
+            region: region_code,
+        };
+        let url = (&endpoint, self.resource_loader).to_endpoint()?;
+        let payload_signing = options.payload_signing.unwrap_or_default();
+        if resource.payload.is_some() {
+            payload_signing.validate(&url)?;
+        }
+        let (resource, streaming_body) = resource.load_payload(payload_signing).await?;
         let parts = RequestParts::new(
-            self.url,
-            self.method,
-            &region_code,
+            url,
+            self.method.clone(),
+            region_code,
             resource.hash,
             resource.requested_at,
         );
         let factory = AuthorizationFactory::new(self.credentials, &parts);
-        let headers: HeaderMap = HeaderMap::new()
+        let mut headers: HeaderMap = HeaderMap::new()
             .host(&parts.url)?
             .push_if_exists(resource.content_type)?
             .push(header::ContentLength::new(resource.content_length))?
+            .push_if_exists(resource.content_md5)?
+            .push_all(resource.headers)?
+            .push_all(options.to_headers()?)?
             .push(header::AmzContentSha256::new(parts.hashed_payload.as_str()))?
-            .push(header::AmzDate::new(factory.amz_date().as_str()))?
-            .authorize_with(factory)?
-            .push(header::Date::from(&parts.requested_at))?;
+            .push(header::AmzDate::new(factory.amz_date().as_str()))?;
+
+        let authorization = factory.create_from(&headers);
+        headers.insert(AUTHORIZATION, authorization.to_header_value()?);
+
+        let body = match streaming_body {
+            Some(streaming_body) => {
+                let encoder = if streaming_body.is_signed() {
+                    ChunkEncoder::signed(factory.chunk_signer(authorization.signature()))
+                } else {
+                    ChunkEncoder::unsigned()
+                };
+                Some(streaming_body.into_body(encoder))
+            }
+            None => resource.body,
+        };
+        let headers = headers.push(header::Date::from(&parts.requested_at))?;
 
         Ok(InternalRequest {
+            region: region_code.clone(),
             url: parts.url,
             method: parts.method,
-            body: resource.body,
+            body,
             headers,
         })
     }
@@ -4,5 +4,8 @@ mod error;
 pub use error::Error;
 pub use error::Result;
 
+mod presigner;
+pub use presigner::Presigner;
+
 mod request_parts;
 pub use request_parts::RequestParts;
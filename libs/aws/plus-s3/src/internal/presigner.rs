@@ -0,0 +1,96 @@
+use crate::client::S3ClientConfig;
+use crate::core::endpoint::BucketEndpoint;
+use crate::core::post::{PostForm, PostPolicy};
+use crate::core::request::PresignRequest;
+use crate::core::verbs::{HasBucketScope, ToEndpoint};
+use crate::core::{RegionCache, S3Bucket};
+use crate::internal;
+use crate::internal::Error::RegionNotSpecified;
+use plus_aws::auth::v4::chrono::{now, DateStamp};
+use plus_aws::auth::v4::request::PresignedUrlFactory;
+use plus_aws::auth::v4::sign::CredentialScope;
+use plus_aws::auth::Credentials;
+use plus_aws::index::{RegionCode, ServiceCode};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, Url};
+
+/// Creates the URLs signed by the credentials of the client,
+/// which can be used without the credentials until they expire.
+pub struct Presigner<'a> {
+    credentials: &'a Credentials,
+    default_region: &'a Option<RegionCode>,
+    bucket: &'a S3Bucket,
+    region_cache: &'a RegionCache,
+    client_config: &'a S3ClientConfig,
+}
+
+impl Presigner<'_> {
+    pub fn new<X: HasBucketScope>(scope: &X) -> Presigner<'_> {
+        Presigner {
+            credentials: scope.credentials(),
+            default_region: scope.default_region(),
+            bucket: scope.bucket(),
+            region_cache: scope.region_cache(),
+            client_config: scope.config(),
+        }
+    }
+
+    /// The given headers have to be sent by the holder of the URL as they are.
+    pub fn presign(
+        &self,
+        method: Method,
+        request: &PresignRequest,
+        headers: &HeaderMap,
+    ) -> internal::Result<Url> {
+        let region_code = self.resolve_region(request.region())?;
+        let url = (&self.bucket_endpoint(&region_code), request).to_endpoint()?;
+        let signed_at = now();
+        let scope =
+            CredentialScope::v4(DateStamp::from(&signed_at), &region_code, &ServiceCode::S3);
+        let factory =
+            PresignedUrlFactory::new(self.credentials, &scope, &signed_at, request.expires_in())?;
+
+        Ok(factory.create_from(&method, &url, headers)?)
+    }
+
+    /// Creates the form to upload the object to the bucket without the credentials.
+    pub fn presign_post(&self, policy: &PostPolicy) -> internal::Result<PostForm> {
+        let region_code = self.resolve_region(None)?;
+        let url = self.bucket_endpoint(&region_code).to_url("")?;
+        let signed_at = now();
+        let scope =
+            CredentialScope::v4(DateStamp::from(&signed_at), &region_code, &ServiceCode::S3);
+        let form = policy.sign(url, self.bucket, self.credentials, &scope, &signed_at)?;
+        Ok(form)
+    }
+
+    /// Locates the object without signing, like the `Location` of the uploaded object.
+    pub fn object_url(
+        &self,
+        object_key: &str,
+        region: Option<&RegionCode>,
+    ) -> internal::Result<Url> {
+        let region_code = self.resolve_region(region)?;
+        Ok(self.bucket_endpoint(&region_code).to_url(object_key)?)
+    }
+
+    fn resolve_region(&self, requested: Option<&RegionCode>) -> internal::Result<RegionCode> {
+        let cached_region = self.region_cache.get(self.bucket);
+        let region_code = requested
+            .or(cached_region.as_ref())
+            .or(self.default_region.as_ref())
+            .ok_or(RegionNotSpecified)?;
+
+        Ok(region_code.clone())
+    }
+
+    fn bucket_endpoint<'b>(&'b self, region: &'b RegionCode) -> BucketEndpoint<'b> {
+        BucketEndpoint {
+            endpoint: &self.client_config.endpoint,
+            variants: self.client_config.endpoint_variants,
+            addressing_style: self.client_config.addressing_style,
+            bucket: self.bucket,
+            region,
+        }
+    }
+}
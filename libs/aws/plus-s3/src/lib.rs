@@ -7,6 +7,9 @@ extern crate async_trait;
 #[macro_use]
 extern crate failure;
 
+#[macro_use]
+extern crate serde_derive;
+
 extern crate proc_macro;
 
 pub mod client;
@@ -16,3 +19,4 @@ mod error;
 pub use error::Error;
 
 pub mod internal;
+pub mod transfer;
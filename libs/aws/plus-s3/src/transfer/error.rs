@@ -0,0 +1,64 @@
+use crate::actions::get_object::OutfileError;
+use crate::internal::impl_async::S3ErrorResponse;
+use crate::{actions, internal};
+
+pub type Result<T> = ::std::result::Result<T, Error>;
+
+#[derive(Fail, Debug)]
+pub enum Error {
+    #[fail(display = "actions::Error > {}", 0)]
+    ActionsError(actions::Error),
+
+    #[fail(display = "internal::Error > {}", 0)]
+    InternalError(internal::Error),
+
+    #[fail(display = "ContentLengthNotFound > object_key: {}", 0)]
+    ContentLengthNotFound(String),
+
+    #[fail(display = "get_object::OutfileError > {}", 0)]
+    OutfileError(OutfileError),
+
+    #[fail(display = "invalid part size: {}", 0)]
+    InvalidPartSize(u64),
+
+    #[fail(display = "std::io::Error > {}", 0)]
+    StdIoError(std::io::Error),
+
+    #[fail(display = "too large to upload: {} bytes", 0)]
+    TooLarge(u64),
+}
+
+impl Error {
+    /// Returns the response when S3 has rejected the request.
+    pub fn s3_error(&self) -> Option<&S3ErrorResponse> {
+        match self {
+            Error::ActionsError(e) => e.s3_error(),
+            Error::InternalError(e) => e.s3_error(),
+            _ => None,
+        }
+    }
+}
+
+impl From<actions::Error> for Error {
+    fn from(e: actions::Error) -> Self {
+        Error::ActionsError(e)
+    }
+}
+
+impl From<internal::Error> for Error {
+    fn from(e: internal::Error) -> Self {
+        Error::InternalError(e)
+    }
+}
+
+impl From<OutfileError> for Error {
+    fn from(e: OutfileError) -> Self {
+        Error::OutfileError(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::StdIoError(e)
+    }
+}
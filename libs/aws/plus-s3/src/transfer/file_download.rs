@@ -0,0 +1,27 @@
+use plus_aws::index::RegionCode;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub struct FileDownload {
+    pub object_key: String,
+    /// Left untouched unless all the ranges have been downloaded.
+    pub file_path: PathBuf,
+    pub region_code: Option<RegionCode>,
+}
+
+impl FileDownload {
+    pub fn new<A: Into<String>, B: Into<PathBuf>>(object_key: A, file_path: B) -> Self {
+        FileDownload {
+            object_key: object_key.into(),
+            file_path: file_path.into(),
+            region_code: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DownloadOutput {
+    /// All the ranges have been downloaded from the object of this ETag.
+    pub e_tag: String,
+    pub size: u64,
+}
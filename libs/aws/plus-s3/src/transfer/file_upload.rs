@@ -0,0 +1,32 @@
+use crate::core::checksum::ChecksumAlgorithm;
+use plus_aws::http::request::header::ContentType;
+use plus_aws::index::RegionCode;
+
+#[derive(Debug)]
+pub struct FileUpload {
+    pub file_path: String,
+    pub object_key: String,
+    pub content_type: Option<ContentType>,
+    pub region_code: Option<RegionCode>,
+    /// Applied to each part in the multipart upload.
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+}
+
+impl FileUpload {
+    pub fn new<A: Into<String>, B: Into<String>>(file_path: A, object_key: B) -> Self {
+        FileUpload {
+            file_path: file_path.into(),
+            object_key: object_key.into(),
+            content_type: None,
+            region_code: None,
+            checksum_algorithm: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UploadOutput {
+    /// Ends with `-N` for the object uploaded in N parts.
+    pub e_tag: String,
+    pub location: String,
+}
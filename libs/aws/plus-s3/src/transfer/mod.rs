@@ -0,0 +1,18 @@
+mod error;
+pub use error::Error;
+pub use error::Result;
+
+mod file_download;
+pub use file_download::{DownloadOutput, FileDownload};
+
+mod file_upload;
+pub use file_upload::{FileUpload, UploadOutput};
+
+mod part_layout;
+pub use part_layout::{Part, PartLayout};
+
+mod transfer_config;
+pub use transfer_config::TransferConfig;
+
+mod transfer_manager;
+pub use transfer_manager::TransferManager;
@@ -0,0 +1,107 @@
+use crate::transfer;
+use crate::transfer::Error::{InvalidPartSize, TooLarge};
+
+const MIB: u64 = 1024 * 1024;
+
+/// rf.
+/// [Amazon S3 multipart upload limits](https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html)
+const MIN_PART_SIZE: u64 = 5 * MIB;
+const MAX_PART_SIZE: u64 = 5 * 1024 * MIB;
+const MAX_PARTS: u64 = 10_000;
+const MAX_OBJECT_SIZE: u64 = 5 * 1024 * 1024 * MIB;
+
+/// Splits the object into the parts of the same size, except for the last one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PartLayout {
+    pub total_size: u64,
+    pub part_size: u64,
+}
+
+impl PartLayout {
+    /// Uses the smallest part size, rounded up to MiB, which needs no more than 10,000 parts.
+    pub fn new(total_size: u64, min_part_size: u64) -> transfer::Result<PartLayout> {
+        if total_size > MAX_OBJECT_SIZE {
+            return Err(TooLarge(total_size));
+        }
+        if min_part_size > MAX_PART_SIZE {
+            return Err(InvalidPartSize(min_part_size));
+        }
+        let required = total_size.div_ceil(MAX_PARTS);
+        let part_size = required.div_ceil(MIB) * MIB;
+        Ok(PartLayout {
+            total_size,
+            part_size: part_size.max(min_part_size).max(MIN_PART_SIZE),
+        })
+    }
+
+    pub fn part_count(&self) -> u32 {
+        self.total_size.div_ceil(self.part_size).max(1) as u32
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = Part> {
+        let layout = *self;
+        (1..=layout.part_count()).map(move |part_number| {
+            let offset = u64::from(part_number - 1) * layout.part_size;
+            Part {
+                part_number,
+                offset,
+                length: layout.part_size.min(layout.total_size - offset),
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Part {
+    pub part_number: u32,
+    pub offset: u64,
+    pub length: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Part, PartLayout, MAX_OBJECT_SIZE, MAX_PARTS, MIB};
+    use crate::transfer;
+    use crate::transfer::Error::{InvalidPartSize, TooLarge};
+
+    #[test]
+    fn it_uses_min_part_size_for_small_objects() -> transfer::Result<()> {
+        let layout = PartLayout::new(20 * MIB + 1, 8 * MIB)?;
+        assert_eq!(layout.part_size, 8 * MIB);
+        assert_eq!(layout.part_count(), 3);
+
+        let parts: Vec<Part> = layout.parts().collect();
+        assert_eq!(parts[0].offset, 0);
+        assert_eq!(parts[2].part_number, 3);
+        assert_eq!(parts[2].offset, 16 * MIB);
+        assert_eq!(parts[2].length, 4 * MIB + 1);
+        Ok(())
+    }
+
+    #[test]
+    fn it_keeps_parts_within_limit() -> transfer::Result<()> {
+        let layout = PartLayout::new(100_000 * MIB, 8 * MIB)?;
+        assert_eq!(layout.part_size, 10 * MIB);
+        assert_eq!(layout.part_count(), 10_000);
+
+        let layout = PartLayout::new(MAX_OBJECT_SIZE, 8 * MIB)?;
+        assert_eq!(layout.part_size % MIB, 0);
+        assert!(u64::from(layout.part_count()) <= MAX_PARTS);
+
+        let sum: u64 = layout.parts().map(|part| part.length).sum();
+        assert_eq!(sum, MAX_OBJECT_SIZE);
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_invalid_sizes() {
+        match PartLayout::new(MAX_OBJECT_SIZE + 1, 8 * MIB) {
+            Err(TooLarge(_)) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        match PartLayout::new(MIB, 6 * 1024 * MIB) {
+            Err(InvalidPartSize(_)) => (),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
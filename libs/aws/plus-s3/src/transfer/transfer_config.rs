@@ -0,0 +1,32 @@
+const MIB: u64 = 1024 * 1024;
+
+#[derive(Clone, Debug)]
+pub struct TransferConfig {
+    /// Files of this size or larger are uploaded by the multipart upload.
+    pub multipart_threshold: u64,
+
+    /// Raised automatically to keep the number of the parts within 10,000.
+    pub min_part_size: u64,
+
+    /// The number of the parts or the ranges transferred at the same time.
+    pub max_concurrency: usize,
+
+    /// The size of each `Range` request to download the object.
+    pub range_size: u64,
+
+    /// Includes the first attempt of each range whose body has failed,
+    /// in addition to the retries of the client.
+    pub range_attempts: u32,
+}
+
+impl Default for TransferConfig {
+    fn default() -> Self {
+        TransferConfig {
+            multipart_threshold: 16 * MIB,
+            min_part_size: 8 * MIB,
+            max_concurrency: 4,
+            range_size: 8 * MIB,
+            range_attempts: 3,
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use crate::actions;
+use crate::actions::get_object::Outfile;
+use crate::actions::{
+    abort_multipart_upload, complete_multipart_upload, create_multipart_upload, get_object,
+    head_object, put_object, upload_part,
+};
+use crate::client::S3Client;
+use crate::core::multipart::{CompleteMultipartUpload, CompletedPart};
+use crate::internal;
+use crate::transfer;
+use crate::transfer::Error::{ContentLengthNotFound, InvalidPartSize};
+use crate::transfer::{
+    DownloadOutput, FileDownload, FileUpload, Part, PartLayout, TransferConfig, UploadOutput,
+};
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use std::future::Future;
+use std::path::Path;
+use tempfile::NamedTempFile;
+use tokio::time::delay_for;
+
+/// Uploads the files in a single request or in the multipart upload by their sizes,
+/// and downloads the objects by the concurrent `Range` requests.
+#[derive(Clone, Debug)]
+pub struct TransferManager {
+    client: S3Client,
+    config: TransferConfig,
+}
+
+impl TransferManager {
+    pub fn new(client: S3Client, config: TransferConfig) -> TransferManager {
+        TransferManager { client, config }
+    }
+
+    pub async fn upload_file(&self, upload: &FileUpload) -> transfer::Result<UploadOutput> {
+        let size = tokio::fs::metadata(&upload.file_path).await?.len();
+        if size < self.config.multipart_threshold {
+            self.put_file(upload).await
+        } else {
+            let layout = PartLayout::new(size, self.config.min_part_size)?;
+            self.upload_parts(upload, layout).await
+        }
+    }
+
+    /// Pins all the ranges to the ETag told by `HeadObject` by `If-Match`,
+    /// so that the file cannot be mixed with the object overwritten meanwhile.
+    pub async fn download_file(&self, download: &FileDownload) -> transfer::Result<DownloadOutput> {
+        if self.config.range_size == 0 {
+            return Err(InvalidPartSize(0));
+        }
+        let outfile = Outfile::create(download.file_path.clone())?;
+        let request = head_object::ObjectRequest {
+            object_key: download.object_key.clone(),
+            region_code: download.region_code.clone(),
+        };
+        let headers = self.client.head_object(request).await?.headers;
+        let size = match headers.content_length {
+            Some(length) => length.value(),
+            None => return Err(ContentLengthNotFound(download.object_key.clone())),
+        };
+        let e_tag = headers.e_tag.into_string();
+
+        // Persisted like `Outfile::write` only after all the ranges have been written.
+        let file = NamedTempFile::new_in(outfile.directory())?;
+        file.as_file().set_len(size)?;
+
+        let layout = PartLayout {
+            total_size: size,
+            part_size: self.config.range_size,
+        };
+        let ranges = layout.parts().filter(|range| range.length > 0);
+        stream::iter(ranges.map(Ok))
+            .try_for_each_concurrent(self.config.max_concurrency.max(1), |range| {
+                self.download_range(download, file.path(), &e_tag, range)
+            })
+            .await?;
+
+        file.persist(outfile.as_ref())
+            .map_err(std::io::Error::from)?;
+
+        Ok(DownloadOutput { e_tag, size })
+    }
+
+    async fn put_file(&self, upload: &FileUpload) -> transfer::Result<UploadOutput> {
+        let request = put_object::FileRequest {
+            file_path: upload.file_path.clone(),
+            object_key: upload.object_key.clone(),
+            content_type: upload.content_type.clone(),
+            region_code: upload.region_code.clone(),
+            checksum_algorithm: upload.checksum_algorithm,
+        };
+        let response = self.client.put_object(request).await?;
+        Ok(UploadOutput {
+            e_tag: response.headers.e_tag.into_string(),
+            location: self.client.object_url(&upload.object_key)?.to_string(),
+        })
+    }
+
+    /// Aborts the upload on failure not to leave the parts charged.
+    async fn upload_parts(
+        &self,
+        upload: &FileUpload,
+        layout: PartLayout,
+    ) -> transfer::Result<UploadOutput> {
+        let request = create_multipart_upload::ObjectRequest {
+            object_key: upload.object_key.clone(),
+            content_type: upload.content_type.clone(),
+            region_code: upload.region_code.clone(),
+            checksum_algorithm: upload.checksum_algorithm,
+        };
+        let upload_id = self
+            .client
+            .create_multipart_upload(request)
+            .await?
+            .upload_id;
+        let result = self.complete_parts(upload, layout, &upload_id).await;
+        if result.is_err() {
+            let mut request = abort_multipart_upload::UploadRequest::new(
+                upload.object_key.as_str(),
+                upload_id.as_str(),
+            );
+            request.region_code = upload.region_code.clone();
+
+            // The original error is more useful than the one of the abort.
+            let _ = self.client.abort_multipart_upload(request).await;
+        }
+        result
+    }
+
+    async fn complete_parts(
+        &self,
+        upload: &FileUpload,
+        layout: PartLayout,
+        upload_id: &str,
+    ) -> transfer::Result<UploadOutput> {
+        let parts: Vec<CompletedPart> = stream::iter(layout.parts())
+            .map(|part| self.upload_part(upload, upload_id, part))
+            .buffer_unordered(self.config.max_concurrency.max(1))
+            .try_collect()
+            .await?;
+
+        let mut request = complete_multipart_upload::CompletionRequest::new(
+            upload.object_key.as_str(),
+            upload_id,
+            CompleteMultipartUpload::new(parts),
+        );
+        request.region_code = upload.region_code.clone();
+
+        let response = self.client.complete_multipart_upload(request).await?;
+        let location = match response.location {
+            Some(location) => location,
+            None => self.client.object_url(&upload.object_key)?.to_string(),
+        };
+        Ok(UploadOutput {
+            e_tag: response.e_tag,
+            location,
+        })
+    }
+
+    async fn upload_part(
+        &self,
+        upload: &FileUpload,
+        upload_id: &str,
+        part: Part,
+    ) -> transfer::Result<CompletedPart> {
+        // retried only by the client, which sees all the failures of the part.
+        let request = upload_part::FileRequest {
+            file_path: upload.file_path.clone(),
+            offset: part.offset,
+            length: part.length,
+            object_key: upload.object_key.clone(),
+            upload_id: upload_id.to_string(),
+            part_number: part.part_number,
+            region_code: upload.region_code.clone(),
+            checksum_algorithm: upload.checksum_algorithm,
+        };
+        let response = self.client.upload_part(request).await?;
+
+        let e_tag = response.e_tag.into_string();
+        Ok(CompletedPart::new(
+            part.part_number,
+            e_tag,
+            response.checksum,
+        ))
+    }
+
+    async fn download_range(
+        &self,
+        download: &FileDownload,
+        temp_path: &Path,
+        e_tag: &str,
+        range: Part,
+    ) -> transfer::Result<()> {
+        self.with_retries(|| {
+            let request = get_object::RangeRequest::new(
+                download.object_key.as_str(),
+                temp_path.to_path_buf(),
+                range.offset,
+                range.length,
+            );
+            let request = match &download.region_code {
+                Some(region_code) => request.with_region(region_code.clone()),
+                None => request,
+            };
+            self.client.get_object(request.with_if_match(e_tag))
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Retries in addition to the client, since the body can fail after the response has arrived.
+    async fn with_retries<F, R, A>(&self, request: F) -> transfer::Result<A>
+    where
+        F: Fn() -> R,
+        R: Future<Output = actions::Result<A>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match request().await {
+                Ok(response) => return Ok(response),
+                Err(e) if attempt < self.config.range_attempts && is_retryable(&e) => {
+                    let retry_config = &self.client.retry_config;
+                    let delay = retry_config.backoff(attempt, &mut rand::thread_rng());
+                    delay_for(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+/// Only the failures of the body are retried,
+/// since the client has already retried the others as far as `max_attempts`.
+///
+/// The idle timeout of the body cannot be told apart from the one waiting for the headers,
+/// so the latter can also be retried here.
+fn is_retryable(e: &actions::Error) -> bool {
+    matches!(
+        e,
+        actions::Error::GetObjectError(
+            get_object::Error::ReqwestError(_)
+                | get_object::Error::RangeLengthMismatch { .. }
+                | get_object::Error::InternalError(internal::Error::ReadTimeout(_))
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransferManager;
+    use crate::client::test_server::{create_client_at, request_lines, response, serve_by};
+    use crate::core::retry::RetryConfig;
+    use crate::transfer::{FileUpload, TransferConfig};
+    use plus_aws::index::RegionCode;
+    use std::io::Write;
+    use std::time::Duration;
+    use tempfile::NamedTempFile;
+    use tokio::runtime::Builder;
+
+    #[test]
+    fn it_aborts_upload_when_part_fails() {
+        let (url, received) = serve_by(|request| {
+            let method = request.request_line.split(' ').next().unwrap_or_default();
+            match method {
+                "POST" => response("200 OK", &[], INITIATED),
+                "PUT" => response("500 Internal Server Error", &[], INTERNAL_ERROR),
+                _ => response("204 No Content", &[], ""),
+            }
+        });
+        let retry_config = RetryConfig {
+            max_attempts: 2,
+            base_delay: Duration::from_millis(1),
+            ..RetryConfig::default()
+        };
+        let client = create_client_at(url, Some(RegionCode::any("us-east-1")))
+            .with_retry_config(retry_config);
+        let config = TransferConfig {
+            multipart_threshold: 0,
+            ..TransferConfig::default()
+        };
+        let manager = TransferManager::new(client, config);
+
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"Hello, World!").unwrap();
+        let upload = FileUpload::new(file.path().to_string_lossy(), "a.txt");
+
+        let mut runtime = Builder::new()
+            .basic_scheduler()
+            .enable_all()
+            .build()
+            .unwrap();
+        assert!(runtime.block_on(manager.upload_file(&upload)).is_err());
+        assert_eq!(
+            request_lines(&received),
+            vec![
+                "POST /example/a.txt?uploads= HTTP/1.1",
+                "PUT /example/a.txt?partNumber=1&uploadId=VXBsb2FkIElE HTTP/1.1",
+                "PUT /example/a.txt?partNumber=1&uploadId=VXBsb2FkIElE HTTP/1.1",
+                "DELETE /example/a.txt?uploadId=VXBsb2FkIElE HTTP/1.1",
+            ]
+        );
+    }
+
+    const INITIATED: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+        <InitiateMultipartUploadResult>\
+        <Bucket>example</Bucket><Key>a.txt</Key><UploadId>VXBsb2FkIElE</UploadId>\
+        </InitiateMultipartUploadResult>";
+
+    const INTERNAL_ERROR: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+        <Error><Code>InternalError</Code><Message>We encountered an internal error.</Message></Error>";
+}